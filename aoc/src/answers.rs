@@ -0,0 +1,143 @@
+//! Database of the expected puzzle answers (`answers.toml`)
+
+use std::collections::BTreeMap;
+
+use miette::{Diagnostic, NamedSource, Result, SourceSpan};
+use serde::Deserialize;
+use thiserror::Error;
+use utils::input;
+use utils::params::Params;
+use utils::solution::Solution;
+
+/// Default location of the answers file (relative to the workspace root)
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// Error while parsing the answers file
+#[derive(Error, Diagnostic, Debug)]
+#[error("Invalid answers file")]
+#[diagnostic(code(aoc::answers::parse))]
+pub struct AnswersParseError {
+    #[source_code]
+    src: NamedSource<String>,
+    #[label("{message}")]
+    span: Option<SourceSpan>,
+    message: String,
+}
+
+/// Expected answer of one part of one day for one input
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedAnswer {
+    /// The expected result of `process`
+    pub expected: String,
+    /// Puzzle parameters for this input (replacing the defaults of the solution)
+    #[serde(default)]
+    pub params: BTreeMap<String, toml::Value>,
+}
+
+impl ExpectedAnswer {
+    /// The puzzle parameters for this input
+    pub fn params(&self) -> Params {
+        let mut params = Params::default();
+        for (name, value) in &self.params {
+            // strings without their quotes, other values (numbers, booleans) as written in TOML
+            let value = value
+                .as_str()
+                .map_or_else(|| value.to_string(), str::to_string);
+            params.set(name, &value);
+        }
+        params
+    }
+}
+
+/// All expected answers, keyed by day (`day07`), part (`part1`) and input (`input1`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, ExpectedAnswer>>>);
+
+impl Answers {
+    /// Loads the answers file (works in root folder and in dayXY)
+    pub fn load(file_name: &str) -> Result<Self> {
+        let content = input::read_input_file(file_name)?;
+        Self::parse(file_name, &content)
+    }
+
+    /// Parses the content of an answers file
+    pub fn parse(file_name: &str, content: &str) -> Result<Self> {
+        toml::from_str(content).map_err(|err| {
+            AnswersParseError {
+                span: err.span().map(SourceSpan::from),
+                message: err.message().to_string(),
+                src: NamedSource::new(file_name, content.to_string()),
+            }
+            .into()
+        })
+    }
+
+    /// Expected answers of the solution, keyed by input name
+    pub fn for_solution(&self, solution: &dyn Solution) -> Vec<(&str, &ExpectedAnswer)> {
+        self.0
+            .get(&solution.crate_name())
+            .and_then(|parts| parts.get(&format!("part{}", solution.part())))
+            .map(|inputs| {
+                inputs
+                    .iter()
+                    .map(|(input, answer)| (input.as_str(), answer))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let content = r#"
+[day17.part1.input1]
+expected = "3,5,0,1,5,1,5,1,0"
+
+[day17.part1.example]
+expected = "4,6,3,5,6,3,5,2,1,0"
+"#;
+        let answers = Answers::parse("answers.toml", content)?;
+        let day17_part1 = crate::find_solutions(Some(17), Some(1))?[0];
+        let expected: Vec<_> = answers
+            .for_solution(day17_part1)
+            .into_iter()
+            .map(|(input, answer)| (input, answer.expected.as_str()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("example", "4,6,3,5,6,3,5,2,1,0"),
+                ("input1", "3,5,0,1,5,1,5,1,0")
+            ],
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_params() -> miette::Result<()> {
+        let content = r#"
+[day14.part1.example]
+expected = "12"
+params = { width = 11, name = "example", debug = true }
+"#;
+        let answers = Answers::parse("answers.toml", content)?;
+        let params = answers.0["day14"]["part1"]["example"].params();
+        assert_eq!(
+            vec![("debug", "true"), ("name", "example"), ("width", "11")],
+            params.iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let content = "[day17.part1.input1]\nexpect = \"1\"\n";
+        assert!(Answers::parse("answers.toml", content).is_err());
+    }
+}
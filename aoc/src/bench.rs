@@ -0,0 +1,457 @@
+//! Benchmarks of all solutions (timing and allocations), compared against a stored baseline
+//!
+//! The results are stored as TOML keyed by day and part (like `answers.toml`), so a run can be
+//! kept as baseline and later runs show the changes, e.g. after optimizing the `grid` crate.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use itertools::Itertools;
+use miette::{Context, Diagnostic, IntoDiagnostic, NamedSource, Result, SourceSpan};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utils::input;
+use utils::params::Params;
+use utils::solution::{PhaseTimes, Solution};
+
+/// Default location of the results of the latest run (relative to the workspace root)
+pub const RESULTS_FILE_NAME: &str = "benchmarks/results.toml";
+
+/// Default location of the markdown summary of the latest run
+pub const SUMMARY_FILE_NAME: &str = "benchmarks/summary.md";
+
+/// Default location of the baseline the results are compared with
+pub const BASELINE_FILE_NAME: &str = "benchmarks/baseline.toml";
+
+/// Default location of the baseline for the generated inputs of the given size and seed
+/// (their timings can't be compared with the real inputs)
+pub fn generated_baseline_file_name(size: usize, seed: u64) -> String {
+    format!("benchmarks/baseline_generated_{size}_{seed}.toml")
+}
+
+/// Default slowdown of the median time (in percent) which counts as regression
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Default number of timed runs per solution
+pub const DEFAULT_SAMPLES: usize = 10;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator which counts the allocations (install it with `#[global_allocator]`)
+///
+/// Without it the allocation counts of all measurements are 0.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+/// Counts of the [`CountingAllocator`] since the start of the program
+fn allocation_counts() -> (usize, usize) {
+    (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED_BYTES.load(Ordering::Relaxed),
+    )
+}
+
+/// Benchmark result of one part of one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Measurement {
+    /// Number of timed runs
+    pub samples: usize,
+    /// Median time of parsing and solving (in nanoseconds)
+    pub median_ns: u64,
+    /// Median time of parsing (in nanoseconds, 0 in results of older versions)
+    #[serde(default)]
+    pub parse_ns: u64,
+    /// Median time of solving the parsed input (in nanoseconds, 0 in results of older versions)
+    #[serde(default)]
+    pub solve_ns: u64,
+    /// Fastest run (in nanoseconds)
+    pub min_ns: u64,
+    /// Slowest run (in nanoseconds)
+    pub max_ns: u64,
+    /// Number of allocations (including reallocations) of one run
+    pub allocations: usize,
+    /// Bytes allocated by one run
+    pub allocated_bytes: usize,
+}
+
+impl Measurement {
+    /// Median time of parsing and solving
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    /// Fastest run
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    /// Median time of parsing
+    pub fn parse(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns)
+    }
+
+    /// Median time of solving the parsed input
+    pub fn solve(&self) -> Duration {
+        Duration::from_nanos(self.solve_ns)
+    }
+}
+
+/// Runs the solution with the given parameters once to count the allocations,
+/// then `samples` times to measure the time (of parsing and solving separately)
+pub fn bench_solution(
+    solution: &dyn Solution,
+    input: &str,
+    params: &Params,
+    samples: usize,
+) -> Result<Measurement> {
+    let process = || {
+        solution
+            .process_timed(input, params)
+            .with_context(|| format!("process {} part {}", solution.crate_name(), solution.part()))
+    };
+
+    let (start_allocations, start_bytes) = allocation_counts();
+    process()?;
+    let (end_allocations, end_bytes) = allocation_counts();
+
+    let times = (0..samples.max(1))
+        .map(|_| process().map(|(_result, times)| times))
+        .collect::<Result<Vec<_>>>()?;
+    let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+    let sorted = |phase: fn(&PhaseTimes) -> Duration| {
+        let mut durations = times.iter().map(phase).collect_vec();
+        durations.sort_unstable();
+        durations
+    };
+    let durations = sorted(PhaseTimes::total);
+    let median = |durations: &[Duration]| nanos(durations[durations.len() / 2]);
+    Ok(Measurement {
+        samples: durations.len(),
+        median_ns: median(&durations),
+        parse_ns: median(&sorted(|times| times.parse)),
+        solve_ns: median(&sorted(|times| times.solve)),
+        min_ns: nanos(durations[0]),
+        max_ns: nanos(durations[durations.len() - 1]),
+        allocations: end_allocations - start_allocations,
+        allocated_bytes: end_bytes - start_bytes,
+    })
+}
+
+/// Error while parsing a benchmark results file
+#[derive(Error, Diagnostic, Debug)]
+#[error("Invalid benchmark results file")]
+#[diagnostic(code(aoc::bench::parse))]
+pub struct BenchResultsParseError {
+    #[source_code]
+    src: NamedSource<String>,
+    #[label("{message}")]
+    span: Option<SourceSpan>,
+    message: String,
+}
+
+/// Benchmark results, keyed by day (`day07`) and part (`part1`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BenchResults(BTreeMap<String, BTreeMap<String, Measurement>>);
+
+impl BenchResults {
+    /// Loads a results file (works in root folder and in dayXY)
+    pub fn load(file_name: &str) -> Result<Self> {
+        let content = input::read_input_file(file_name)?;
+        Self::parse(file_name, &content)
+    }
+
+    /// Parses the content of a results file
+    pub fn parse(file_name: &str, content: &str) -> Result<Self> {
+        toml::from_str(content).map_err(|err| {
+            BenchResultsParseError {
+                span: err.span().map(SourceSpan::from),
+                message: err.message().to_string(),
+                src: NamedSource::new(file_name, content.to_string()),
+            }
+            .into()
+        })
+    }
+
+    /// Writes the results as TOML, creating the folder if needed
+    pub fn save(&self, file_name: &str) -> Result<()> {
+        let content = toml::to_string(self).into_diagnostic()?;
+        write_file(file_name, &content)
+    }
+
+    /// Stores the measurement of the solution (replacing an older one)
+    pub fn insert(&mut self, solution: &dyn Solution, measurement: Measurement) {
+        self.insert_part(&solution.crate_name(), solution.part(), measurement);
+    }
+
+    fn insert_part(&mut self, crate_name: &str, part: u8, measurement: Measurement) {
+        self.0
+            .entry(crate_name.to_string())
+            .or_default()
+            .insert(format!("part{part}"), measurement);
+    }
+
+    /// Measurement of the given day crate and part
+    pub fn get(&self, crate_name: &str, part: u8) -> Option<&Measurement> {
+        self.0.get(crate_name)?.get(&format!("part{part}"))
+    }
+
+    /// Stores all measurements of `other` (replacing older ones)
+    pub fn merge(&mut self, other: &BenchResults) {
+        for (crate_name, parts) in &other.0 {
+            self.0.entry(crate_name.clone()).or_default().extend(
+                parts
+                    .iter()
+                    .map(|(part, measurement)| (part.clone(), measurement.clone())),
+            );
+        }
+    }
+
+    /// Iterates over day crate name, part and measurement (ordered by day and part)
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u8, &Measurement)> {
+        self.0.iter().flat_map(|(crate_name, parts)| {
+            parts.iter().filter_map(move |(part, measurement)| {
+                let part = part.strip_prefix("part")?.parse().ok()?;
+                Some((crate_name.as_str(), part, measurement))
+            })
+        })
+    }
+}
+
+/// Writes the file, creating its folder if needed
+pub fn write_file(file_name: &str, content: &str) -> Result<()> {
+    let path = Path::new(file_name);
+    if let Some(folder) = path
+        .parent()
+        .filter(|folder| !folder.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(folder)
+            .into_diagnostic()
+            .with_context(|| format!("create folder {}", folder.display()))?;
+    }
+    std::fs::write(path, content)
+        .into_diagnostic()
+        .with_context(|| format!("write {file_name}"))
+}
+
+/// A measurement together with the baseline measurement of the same day and part
+#[derive(Debug, Clone)]
+pub struct Comparison<'a> {
+    /// Name of the day crate
+    pub crate_name: &'a str,
+    /// Part of the puzzle
+    pub part: u8,
+    /// Measurement of this run
+    pub measurement: &'a Measurement,
+    /// Measurement of the baseline (if the baseline contains the day and part)
+    pub baseline: Option<&'a Measurement>,
+}
+
+impl Comparison<'_> {
+    /// Change of the median time relative to the baseline in percent (positive is slower)
+    #[allow(clippy::cast_precision_loss)]
+    pub fn change_percent(&self) -> Option<f64> {
+        let baseline = self.baseline?;
+        (baseline.median_ns > 0).then(|| {
+            (self.measurement.median_ns as f64 - baseline.median_ns as f64) * 100.0
+                / baseline.median_ns as f64
+        })
+    }
+
+    /// Whether the median time got slower by more than `threshold_percent`
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.change_percent()
+            .is_some_and(|change| change > threshold_percent)
+    }
+}
+
+/// Pairs every measurement of `results` with the one of `baseline`
+pub fn compare<'a>(results: &'a BenchResults, baseline: &'a BenchResults) -> Vec<Comparison<'a>> {
+    results
+        .iter()
+        .map(|(crate_name, part, measurement)| Comparison {
+            crate_name,
+            part,
+            measurement,
+            baseline: baseline.get(crate_name, part),
+        })
+        .collect()
+}
+
+/// Renders the comparisons as markdown table followed by the number of regressions
+pub fn markdown_summary(comparisons: &[Comparison], threshold_percent: f64) -> String {
+    let mut lines = vec![
+        "| Day | Part | Median | Parse | Solve | Min | Allocations | Allocated | Baseline | Change |"
+            .to_string(),
+        "|-----|-----:|-------:|------:|------:|----:|------------:|----------:|---------:|-------:|"
+            .to_string(),
+    ];
+    lines.extend(comparisons.iter().map(|comparison| {
+        let measurement = comparison.measurement;
+        let change = match comparison.change_percent() {
+            Some(change) if comparison.is_regression(threshold_percent) => {
+                format!("**{change:+.1}%** regression")
+            }
+            Some(change) => format!("{change:+.1}%"),
+            None => "new".to_string(),
+        };
+        format!(
+            "| {} | {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} | {} | {} | {} | {change} |",
+            comparison.crate_name,
+            comparison.part,
+            measurement.median(),
+            measurement.parse(),
+            measurement.solve(),
+            measurement.min(),
+            measurement.allocations,
+            format_bytes(measurement.allocated_bytes),
+            comparison
+                .baseline
+                .map_or(String::new(), |baseline| format!(
+                    "{:.2?}",
+                    baseline.median()
+                )),
+        )
+    }));
+
+    let total: Duration = comparisons
+        .iter()
+        .map(|comparison| comparison.measurement.median())
+        .sum();
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold_percent))
+        .map(|comparison| format!("{} part {}", comparison.crate_name, comparison.part))
+        .collect_vec();
+    lines.push(String::new());
+    lines.push(format!("Total median time: {total:.2?}"));
+    lines.push(if regressions.is_empty() {
+        format!("No regressions above {threshold_percent}%")
+    } else {
+        format!(
+            "{} regression(s) above {threshold_percent}%: {}",
+            regressions.len(),
+            regressions.join(", ")
+        )
+    });
+    lines.join("\n")
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.5 KiB`
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(median_ns: u64) -> Measurement {
+        Measurement {
+            samples: 3,
+            median_ns,
+            parse_ns: median_ns / 4,
+            solve_ns: median_ns - median_ns / 4,
+            min_ns: median_ns - 100_000,
+            max_ns: median_ns + 100_000,
+            allocations: 42,
+            allocated_bytes: 1536,
+        }
+    }
+
+    #[test]
+    fn test_results_round_trip() -> miette::Result<()> {
+        let mut results = BenchResults::default();
+        results.insert_part("day07", 2, measurement(2_000_000));
+        results.insert_part("day07", 1, measurement(1_000_000));
+        let content = toml::to_string(&results).into_diagnostic()?;
+        assert!(content.contains("[day07.part1]"));
+        assert_eq!(results, BenchResults::parse("results.toml", &content)?);
+        assert!(BenchResults::parse("results.toml", "[day07.part1]\nmedian = 1\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = BenchResults::default();
+        baseline.insert_part("day01", 1, measurement(1_000_000));
+        baseline.insert_part("day01", 2, measurement(1_000_000));
+        let mut results = BenchResults::default();
+        results.insert_part("day01", 1, measurement(1_050_000));
+        results.insert_part("day01", 2, measurement(1_200_000));
+        results.insert_part("day02", 1, measurement(500_000));
+
+        let comparisons = compare(&results, &baseline);
+        let regressions = comparisons
+            .iter()
+            .map(|comparison| comparison.is_regression(10.0))
+            .collect_vec();
+        assert_eq!(vec![false, true, false], regressions);
+        assert_eq!(None, comparisons[2].change_percent());
+
+        let summary = markdown_summary(&comparisons, 10.0);
+        assert!(summary.contains(
+            "| day01 | 1 | 1.05ms | 262.50µs | 787.50µs | 950.00µs | 42 | 1.5 KiB | 1.00ms | +5.0% |"
+        ));
+        assert!(summary.contains(
+            "| day01 | 2 | 1.20ms | 300.00µs | 900.00µs | 1.10ms | 42 | 1.5 KiB | 1.00ms | **+20.0%** regression |"
+        ));
+        assert!(summary.contains(
+            "| day02 | 1 | 500.00µs | 125.00µs | 375.00µs | 400.00µs | 42 | 1.5 KiB |  | new |"
+        ));
+        assert!(summary.ends_with("1 regression(s) above 10%: day01 part 2"));
+    }
+
+    #[test]
+    fn test_bench_solution() -> miette::Result<()> {
+        let day01_part1 = crate::find_solutions(Some(1), Some(1))?[0];
+        let params = day01_part1.default_params();
+        let measurement = bench_solution(day01_part1, "3   4\n4   3\n2   5\n", &params, 3)?;
+        assert_eq!(3, measurement.samples);
+        assert!(measurement.min_ns <= measurement.median_ns);
+        assert!(measurement.median_ns <= measurement.max_ns);
+        assert!(measurement.parse_ns + measurement.solve_ns > 0);
+        assert!(bench_solution(day01_part1, "3   x\n", &params, 3).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+//! Runner for all daily puzzle solutions
+
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+#![deny(missing_docs)]
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::doc_markdown)]
+
+use std::time::{Duration, Instant};
+
+use miette::{miette, Context, Result};
+use utils::input;
+use utils::params::Params;
+use utils::solution::Solution;
+
+pub mod answers;
+pub mod bench;
+pub mod verify;
+
+/// All registered solutions, ordered by day and part
+pub fn all_solutions() -> impl Iterator<Item = &'static dyn Solution> {
+    [
+        day01::SOLUTIONS,
+        day02::SOLUTIONS,
+        day03::SOLUTIONS,
+        day04::SOLUTIONS,
+        day05::SOLUTIONS,
+        day06::SOLUTIONS,
+        day07::SOLUTIONS,
+        day08::SOLUTIONS,
+        day09::SOLUTIONS,
+        day10::SOLUTIONS,
+        day11::SOLUTIONS,
+        day12::SOLUTIONS,
+        day13::SOLUTIONS,
+        day14::SOLUTIONS,
+        day15::SOLUTIONS,
+        day16::SOLUTIONS,
+        day17::SOLUTIONS,
+        day18::SOLUTIONS,
+        day19::SOLUTIONS,
+        day20::SOLUTIONS,
+        day21::SOLUTIONS,
+        day22::SOLUTIONS,
+        day23::SOLUTIONS,
+        day24::SOLUTIONS,
+        day25::SOLUTIONS,
+    ]
+    .into_iter()
+    .flatten()
+    .copied()
+}
+
+/// Returns the solutions for the given day and part (`None` matches all)
+pub fn find_solutions(day: Option<u8>, part: Option<u8>) -> Result<Vec<&'static dyn Solution>> {
+    let solutions: Vec<_> = all_solutions()
+        .filter(|solution| day.is_none_or(|day| solution.day() == day))
+        .filter(|solution| part.is_none_or(|part| solution.part() == part))
+        .collect();
+    if solutions.is_empty() {
+        return Err(miette!(
+            "No solution registered for day {} part {}",
+            day.map_or("*".to_string(), |day| day.to_string()),
+            part.map_or("*".to_string(), |part| part.to_string())
+        ));
+    }
+    Ok(solutions)
+}
+
+/// Default input file of the solution, e.g. `day07/input2.txt`
+pub fn default_input_file(solution: &dyn Solution) -> String {
+    format!("{}/{}", solution.crate_name(), solution.input_file_name())
+}
+
+/// Input file with the given name (without `.txt`) in the day folder of the solution
+pub fn input_file(solution: &dyn Solution, input_name: &str) -> String {
+    format!("{}/{input_name}.txt", solution.crate_name())
+}
+
+/// Runs the solution with its default parameters replaced by `param_overrides`
+/// and returns the result with the elapsed time
+pub fn time_solution(
+    solution: &dyn Solution,
+    input: &str,
+    param_overrides: &Params,
+) -> Result<(String, Duration)> {
+    let params = solution
+        .default_params()
+        .with_overrides(param_overrides)
+        .with_context(|| format!("parameters of {} part {}", solution.crate_name(), solution.part()))?;
+
+    let start = Instant::now();
+    let result = solution
+        .process(input, &params)
+        .with_context(|| format!("process {} part {}", solution.crate_name(), solution.part()))?;
+    Ok((result, start.elapsed()))
+}
+
+/// Loads the input of the solution, runs it and returns the result with the elapsed time.
+///
+/// `input_arg` (a file path or `-` for stdin), else the file named by `AOC_INPUT`, replaces the
+/// default input file of the solution.
+pub fn run_solution(
+    solution: &dyn Solution,
+    input_arg: Option<&str>,
+    param_overrides: &Params,
+) -> Result<(String, Duration)> {
+    let default_input_file = default_input_file(solution);
+    let input = input::load_input(input_arg, &default_input_file)?;
+    time_solution(solution, &input, param_overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_days_registered() {
+        let day_parts: Vec<_> = all_solutions()
+            .map(|solution| (solution.day(), solution.part()))
+            .collect();
+        assert_eq!(49, day_parts.len());
+        assert_eq!(Some(&(1, 1)), day_parts.first());
+        assert_eq!(Some(&(25, 1)), day_parts.last());
+        assert!(day_parts.is_sorted());
+    }
+
+    #[test]
+    fn test_find_solutions() -> miette::Result<()> {
+        assert_eq!(2, find_solutions(Some(17), None)?.len());
+        assert_eq!(25, find_solutions(None, Some(1))?.len());
+        assert!(find_solutions(Some(25), Some(2)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_solution_with_params() -> miette::Result<()> {
+        let day14_part1 = find_solutions(Some(14), Some(1))?[0];
+        let example = include_str!("../../day14/example1.txt");
+        let params = Params::from([("width", 11), ("height", 7)]);
+        assert_eq!("12", time_solution(day14_part1, example, &params)?.0);
+        assert!(time_solution(day14_part1, example, &Params::from([("cycles", 6)])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solved() -> miette::Result<()> {
+        for solution in all_solutions() {
+            for seed in 0..3 {
+                let generated = solution.generate(seed, 12);
+                assert_eq!(generated, solution.generate(seed, 12));
+                solution
+                    .process(&generated.input, &generated.params)
+                    .with_context(|| {
+                        format!(
+                            "{} part {} with seed {seed}:\n{}",
+                            solution.crate_name(),
+                            solution.part(),
+                            generated.input
+                        )
+                    })?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use aoc::answers::{Answers, ANSWERS_FILE_NAME};
+use aoc::bench::{
+    bench_solution, compare, generated_baseline_file_name, markdown_summary, write_file,
+    BenchResults, CountingAllocator, BASELINE_FILE_NAME, DEFAULT_SAMPLES,
+    DEFAULT_THRESHOLD_PERCENT, RESULTS_FILE_NAME, SUMMARY_FILE_NAME,
+};
+use aoc::verify::{report, verify};
+use aoc::{default_input_file, find_solutions, run_solution};
+use miette::{miette, IntoDiagnostic, Result};
+use utils::generate::{DEFAULT_SEED, DEFAULT_SIZE};
+use utils::input;
+use utils::params::Params;
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "Usage:
+  aoc run <day|all> [part] [input file|-] [-p name=value]...
+  aoc verify <day|all> [part] [answers file]
+  aoc bench <day|all> [part] [--samples n] [--baseline file] [--threshold percent] [--save-baseline]
+            [--generate size] [--seed n]
+  aoc generate <day> [part] [--size n] [--seed n]";
+
+/// Separates the `-p name=value` (or `--param name=value`) options from the other arguments
+fn split_params(args: &[String]) -> Result<(Vec<String>, Params)> {
+    let mut positional = Vec::new();
+    let mut assignments = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "-p" || arg == "--param" {
+            let assignment = args
+                .next()
+                .ok_or_else(|| miette!("Missing name=value after {arg}\n{USAGE}"))?;
+            assignments.push(assignment.as_str());
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+    Ok((positional, Params::parse_assignments(assignments)?))
+}
+
+fn parse_day_and_part(args: &[String]) -> Result<(Option<u8>, Option<u8>)> {
+    let day = match args.first().map(String::as_str) {
+        None => return Err(miette!("Missing day\n{USAGE}")),
+        Some("all") => None,
+        Some(day) => Some(day.parse().into_diagnostic()?),
+    };
+    let part = match args.get(1) {
+        None => None,
+        Some(part) => Some(part.parse().into_diagnostic()?),
+    };
+    Ok((day, part))
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let (args, param_overrides) = split_params(args)?;
+    let (day, part) = parse_day_and_part(&args)?;
+    let input_arg = args.get(2).map(String::as_str);
+    let input_env = std::env::var_os(input::INPUT_ENV_VAR).is_some();
+    if (input_arg.is_some() || input_env || !param_overrides.is_empty())
+        && (day.is_none() || part.is_none())
+    {
+        return Err(miette!(
+            "An input ({} included) or parameters can only be given for a single day and part\n{USAGE}",
+            input::INPUT_ENV_VAR
+        ));
+    }
+
+    let mut failed = 0;
+    for solution in find_solutions(day, part)? {
+        match run_solution(solution, input_arg, &param_overrides) {
+            Ok((result, duration)) => println!(
+                "{} part {}: {result} ({duration:?})",
+                solution.crate_name(),
+                solution.part()
+            ),
+            Err(err) => {
+                eprintln!("{err:?}");
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(miette!("{failed} solution(s) failed"));
+    }
+    Ok(())
+}
+
+fn verify_answers(args: &[String]) -> Result<()> {
+    let (day, part) = parse_day_and_part(args)?;
+    let answers_file = args.get(2).map_or(ANSWERS_FILE_NAME, String::as_str);
+    let answers = Answers::load(answers_file)?;
+
+    let verifications = verify(&find_solutions(day, part)?, &answers);
+    println!("{}", report(&verifications));
+
+    let failed = verifications
+        .iter()
+        .filter(|verification| verification.is_failure())
+        .count();
+    if failed > 0 {
+        return Err(miette!("{failed} verification(s) failed"));
+    }
+    Ok(())
+}
+
+/// Options of `aoc bench`
+struct BenchOptions {
+    samples: usize,
+    baseline_file: Option<String>,
+    threshold_percent: f64,
+    save_baseline: bool,
+    /// Size of the generated inputs which replace the input files
+    generate: Option<usize>,
+    seed: u64,
+}
+
+/// Separates the `aoc bench` options from the other arguments
+fn split_bench_options(args: &[String]) -> Result<(Vec<String>, BenchOptions)> {
+    let mut positional = Vec::new();
+    let mut options = BenchOptions {
+        samples: DEFAULT_SAMPLES,
+        baseline_file: None,
+        threshold_percent: DEFAULT_THRESHOLD_PERCENT,
+        save_baseline: false,
+        generate: None,
+        seed: DEFAULT_SEED,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| miette!("Missing value after {arg}\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--samples" => options.samples = value()?.parse().into_diagnostic()?,
+            "--baseline" => options.baseline_file = Some(value()?.clone()),
+            "--threshold" => options.threshold_percent = value()?.parse().into_diagnostic()?,
+            "--save-baseline" => options.save_baseline = true,
+            "--generate" => options.generate = Some(value()?.parse().into_diagnostic()?),
+            "--seed" => options.seed = value()?.parse().into_diagnostic()?,
+            _ => positional.push(arg.clone()),
+        }
+    }
+    Ok((positional, options))
+}
+
+fn bench(args: &[String]) -> Result<()> {
+    let (args, options) = split_bench_options(args)?;
+    let (day, part) = parse_day_and_part(&args)?;
+
+    let mut results = BenchResults::default();
+    let mut failed = 0;
+    for solution in find_solutions(day, part)? {
+        let measurement = match options.generate {
+            Some(size) => {
+                let generated = solution.generate(options.seed, size);
+                bench_solution(
+                    solution,
+                    &generated.input,
+                    &generated.params,
+                    options.samples,
+                )
+            }
+            None => input::read_input_file(default_input_file(solution))
+                .map_err(miette::Report::from)
+                .and_then(|input| {
+                    bench_solution(
+                        solution,
+                        &input,
+                        &solution.default_params(),
+                        options.samples,
+                    )
+                }),
+        };
+        match measurement {
+            Ok(measurement) => {
+                eprintln!(
+                    "{} part {}: {:.2?}",
+                    solution.crate_name(),
+                    solution.part(),
+                    measurement.median()
+                );
+                results.insert(solution, measurement);
+            }
+            Err(err) => {
+                eprintln!("{err:?}");
+                failed += 1;
+            }
+        }
+    }
+    results.save(RESULTS_FILE_NAME)?;
+
+    let baseline_file = options
+        .baseline_file
+        .unwrap_or_else(|| match options.generate {
+            Some(size) => generated_baseline_file_name(size, options.seed),
+            None => BASELINE_FILE_NAME.to_string(),
+        });
+    // like loading, the existence check works in root folder and in dayXY
+    let mut baseline = if utils::correct_folder(&baseline_file).exists() {
+        BenchResults::load(&baseline_file)?
+    } else {
+        BenchResults::default()
+    };
+    let comparisons = compare(&results, &baseline);
+    let summary = markdown_summary(&comparisons, options.threshold_percent);
+    write_file(SUMMARY_FILE_NAME, &format!("{summary}\n"))?;
+    println!("{summary}");
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(options.threshold_percent))
+        .count();
+
+    if options.save_baseline {
+        baseline.merge(&results);
+        baseline.save(&baseline_file)?;
+    }
+    if failed > 0 {
+        return Err(miette!("{failed} solution(s) failed"));
+    }
+    if regressions > 0 && !options.save_baseline {
+        return Err(miette!(
+            "{regressions} regression(s) above {}%",
+            options.threshold_percent
+        ));
+    }
+    Ok(())
+}
+
+/// Separates the `aoc generate` options (size and seed) from the other arguments
+fn split_generate_options(args: &[String]) -> Result<(Vec<String>, usize, u64)> {
+    let mut positional = Vec::new();
+    let (mut size, mut seed) = (DEFAULT_SIZE, DEFAULT_SEED);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| miette!("Missing value after {arg}\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--size" => size = value()?.parse().into_diagnostic()?,
+            "--seed" => seed = value()?.parse().into_diagnostic()?,
+            _ => positional.push(arg.clone()),
+        }
+    }
+    Ok((positional, size, seed))
+}
+
+/// Prints a generated input, its parameters are printed to stderr (as options of `aoc run`)
+fn generate(args: &[String]) -> Result<()> {
+    let (args, size, seed) = split_generate_options(args)?;
+    let (day, part) = parse_day_and_part(&args)?;
+    if day.is_none() {
+        return Err(miette!(
+            "An input can only be generated for a single day\n{USAGE}"
+        ));
+    }
+
+    let solutions = find_solutions(day, part)?;
+    for solution in &solutions {
+        let params = solution.generate(seed, size).params;
+        eprintln!(
+            "{} part {}:{}",
+            solution.crate_name(),
+            solution.part(),
+            params
+                .iter()
+                .map(|(name, value)| format!(" -p {name}={value}"))
+                .collect::<String>()
+        );
+    }
+    // without trailing newline, like the input files
+    print!("{}", solutions[0].generate(seed, size).input);
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify_answers(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        _ => Err(miette!("{USAGE}")),
+    }
+}
@@ -0,0 +1,197 @@
+//! Verification of the solutions against the expected answers
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use itertools::Itertools;
+use utils::input;
+use utils::solution::Solution;
+
+use crate::answers::{Answers, ExpectedAnswer};
+use crate::{input_file, time_solution};
+
+/// Outcome of verifying one solution for one input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The result equals the expected answer
+    Pass,
+    /// The result differs from the expected answer
+    Fail {
+        /// Expected answer
+        expected: String,
+        /// Actual result
+        actual: String,
+    },
+    /// The input could not be loaded or `process` returned an error
+    Error(String),
+    /// There is no expected answer for the solution
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Error(_) => "ERROR",
+            Status::Missing => "MISSING",
+        })
+    }
+}
+
+/// Result of verifying one solution for one input
+#[derive(Debug, Clone)]
+pub struct Verification {
+    /// Name of the day crate
+    pub crate_name: String,
+    /// Part of the puzzle
+    pub part: u8,
+    /// Name of the input (file name without `.txt`)
+    pub input: String,
+    /// Outcome
+    pub status: Status,
+    /// Time needed by `process`
+    pub duration: Option<Duration>,
+}
+
+impl Verification {
+    /// Whether the verification failed (a missing answer is no failure)
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+/// Runs every solution for every input which has an expected answer
+pub fn verify(solutions: &[&dyn Solution], answers: &Answers) -> Vec<Verification> {
+    solutions
+        .iter()
+        .flat_map(|&solution| {
+            let expected_answers = answers.for_solution(solution);
+            if expected_answers.is_empty() {
+                let input = solution.input_file_name().trim_end_matches(".txt").to_string();
+                return vec![Verification {
+                    crate_name: solution.crate_name(),
+                    part: solution.part(),
+                    input,
+                    status: Status::Missing,
+                    duration: None,
+                }];
+            }
+            expected_answers
+                .into_iter()
+                .map(|(input, answer)| verify_input(solution, input, answer))
+                .collect()
+        })
+        .collect()
+}
+
+fn verify_input(solution: &dyn Solution, input: &str, answer: &ExpectedAnswer) -> Verification {
+    let expected = &answer.expected;
+    let result = input::read_input_file(input_file(solution, input))
+        .map_err(miette::Report::from)
+        .and_then(|input| time_solution(solution, &input, &answer.params()));
+    let (status, duration) = match result {
+        Ok((actual, duration)) if &actual == expected => (Status::Pass, Some(duration)),
+        Ok((actual, duration)) => (
+            Status::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+            Some(duration),
+        ),
+        Err(err) => (Status::Error(format!("{err:?}")), None),
+    };
+    Verification {
+        crate_name: solution.crate_name(),
+        part: solution.part(),
+        input: input.to_string(),
+        status,
+        duration,
+    }
+}
+
+/// Shows expected and actual answer below each other, marking the first difference
+pub fn answer_diff(expected: &str, actual: &str) -> String {
+    let first_difference = expected
+        .chars()
+        .zip(actual.chars())
+        .position(|(expected_char, actual_char)| expected_char != actual_char)
+        .unwrap_or_else(|| expected.chars().count().min(actual.chars().count()));
+    format!(
+        "  expected: {expected}\n  actual:   {actual}\n            {}^",
+        " ".repeat(first_difference)
+    )
+}
+
+/// Renders the verifications as table followed by the details of all failures
+pub fn report(verifications: &[Verification]) -> String {
+    let mut lines = vec![format!(
+        "{:<6} {:<4} {:<10} {:<8} {:>12}",
+        "Day", "Part", "Input", "Status", "Time"
+    )];
+    lines.extend(verifications.iter().map(|verification| {
+        format!(
+            "{:<6} {:<4} {:<10} {:<8} {:>12}",
+            verification.crate_name,
+            verification.part,
+            verification.input,
+            verification.status.to_string(),
+            verification
+                .duration
+                .map_or(String::new(), |duration| format!("{duration:.2?}")),
+        )
+    }));
+
+    for verification in verifications.iter().filter(|verification| verification.is_failure()) {
+        lines.push(String::new());
+        lines.push(format!(
+            "{} part {} ({}):",
+            verification.crate_name, verification.part, verification.input
+        ));
+        match &verification.status {
+            Status::Fail { expected, actual } => lines.push(answer_diff(expected, actual)),
+            Status::Error(err) => lines.push(err.clone()),
+            Status::Pass | Status::Missing => {}
+        }
+    }
+
+    let counts = verifications
+        .iter()
+        .counts_by(|verification| verification.status.to_string());
+    lines.push(String::new());
+    lines.push(
+        ["PASS", "FAIL", "ERROR", "MISSING"]
+            .iter()
+            .map(|status| format!("{status}: {}", counts.get(*status).unwrap_or(&0)))
+            .join(", "),
+    );
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_diff() {
+        assert_eq!(
+            "  expected: 1,2,3\n  actual:   1,4,3\n              ^",
+            answer_diff("1,2,3", "1,4,3")
+        );
+        assert_eq!(
+            "  expected: 12\n  actual:   123\n              ^",
+            answer_diff("12", "123")
+        );
+    }
+
+    #[test]
+    fn test_verify() -> miette::Result<()> {
+        let answers = Answers::parse("answers.toml", "[day01.part1.input1]\nexpected = \"1\"\n")?;
+        let solutions = crate::find_solutions(Some(1), None)?;
+        let verifications = verify(&solutions, &answers);
+        assert_eq!(2, verifications.len());
+        assert!(verifications[0].is_failure());
+        assert_eq!(Status::Missing, verifications[1].status);
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use {{crate_name}}::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,13 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn {{crate_name}}_part1() {
-    {{crate_name}}_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")))
-    .unwrap();
+fn {{crate_name}}_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("{{crate_name}}/input1.txt").unwrap();
+    bencher.bench(|| {{crate_name | capitalize}}::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn {{crate_name}}_part2() {
-    {{crate_name}}_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")))
-    .unwrap();
-}
\ No newline at end of file
+fn {{crate_name}}_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("{{crate_name}}/input1.txt").unwrap();
+    let input = {{crate_name | capitalize}}::parse(&input).unwrap();
+    let params = {{crate_name | capitalize}}::default_params(1);
+    bencher.bench(|| {{crate_name | capitalize}}::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn {{crate_name}}_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("{{crate_name}}/input2.txt").unwrap();
+    let input = {{crate_name | capitalize}}::parse(&input).unwrap();
+    let params = {{crate_name | capitalize}}::default_params(2);
+    bencher.bench(|| {{crate_name | capitalize}}::part2(divan::black_box(&input), &params).unwrap());
+}
@@ -13,7 +13,7 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt").replace('\r', "");
+    let file = utils::input::load_input_from_args("{{crate_name}}/input1.txt")?;
     let result = process(&file).context("process part 1")?;
     println!("{result}");
     Ok(())
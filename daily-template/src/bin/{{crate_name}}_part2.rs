@@ -13,7 +13,7 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt").replace('\r', "");
+    let file = utils::input::load_input_from_args("{{crate_name}}/input2.txt")?;
     let result = process(&file).context("process part 2")?;
     println!("{result}");
     Ok(())
@@ -15,5 +15,40 @@
 )]
 
 
+pub mod custom_error;
+pub mod {{crate_name}}_common;
+pub mod {{crate_name}}_generate;
 pub mod {{crate_name}}_part1;
 pub mod {{crate_name}}_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day {{crate_name | remove: "day" | plus: 0}}
+pub struct {{crate_name | capitalize}};
+
+impl Day for {{crate_name | capitalize}} {
+    const DAY: u8 = {{crate_name | remove: "day" | plus: 0}};
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok({{crate_name}}_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        {{crate_name}}_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        {{crate_name}}_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        {{crate_name}}_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<{{crate_name | capitalize}}>::PART1, &DayPart::<{{crate_name | capitalize}}>::PART2];
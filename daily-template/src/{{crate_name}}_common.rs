@@ -0,0 +1,5 @@
+use crate::custom_error::AocError;
+
+pub fn parse(input: &str) -> Result<String, AocError> {
+    Ok(input.to_string())
+}
@@ -0,0 +1,15 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// `size` lines of random numbers
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let lines = (0..size.max(1))
+        .map(|_| {
+            (0..rng.gen_range(1..10))
+                .map(|_| rng.gen_range(0..1000))
+                .join(" ")
+        })
+        .collect_vec();
+    GeneratedInput::new(lines.join("\n"))
+}
@@ -4,8 +4,14 @@ use rayon::prelude::*;
 
 use miette::{miette, Error, Result};
 
+use crate::{{crate_name}}_common::parse;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
+    solve(&parse(input)?)
+}
+
+pub fn solve(input: &str) -> Result<String> {
     Ok(input.to_string())
 }
 
@@ -1,9 +1,14 @@
 use miette::Diagnostic;
 use thiserror::Error;
+use utils::parse_error::ParseError;
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] ParseError),
 }
@@ -0,0 +1,28 @@
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// The left and the right list of location IDs
+pub type LocationLists = (Vec<i64>, Vec<i64>);
+
+pub fn parse(input: &str) -> Result<LocationLists, AocError> {
+    let context = ParseContext::new(input);
+    let mut array_left = Vec::new();
+    let mut array_right = Vec::new();
+
+    for line in input.lines() {
+        let mut numbers = line.split_whitespace();
+        let left = numbers
+            .next()
+            .ok_or_else(|| context.error_after(line, "a number"))?;
+        let right = numbers
+            .next()
+            .ok_or_else(|| context.error_after(left, "a second number"))?;
+        if let Some(extra) = numbers.next() {
+            return Err(context.error(extra, "end of line").into());
+        }
+        array_left.push(context.parse(left, "a number")?);
+        array_right.push(context.parse(right, "a number")?);
+    }
+    Ok((array_left, array_right))
+}
@@ -0,0 +1,23 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Two lists of `size` location ids; some ids of the right list are taken from the left list
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let left_ids = (0..size.max(1))
+        .map(|_| rng.gen_range(10_000..100_000))
+        .collect_vec();
+    let lines = left_ids
+        .iter()
+        .map(|left_id| {
+            let right_id = if rng.gen_bool(0.3) {
+                *left_ids.choose(rng).unwrap()
+            } else {
+                rng.gen_range(10_000..100_000)
+            };
+            format!("{left_id}   {right_id}")
+        })
+        .collect_vec();
+    GeneratedInput::new(lines.join("\n"))
+}
@@ -1,25 +1,16 @@
 use itertools::Itertools;
 
-
 use std::iter::zip;
 
+use crate::day01_common::{parse, LocationLists};
+
 
 pub fn process(input: &str) -> miette::Result<String> {
-    let mut array_left: Vec<i64> = Vec::new();
-    let mut array_right: Vec<i64> = Vec::new();
-
-    for line in input.lines() {
-        let numbers: Vec<i64> = line
-            .split_whitespace()
-            .filter_map(|s| s.parse().ok())
-            .collect();
-
-        assert_eq!(numbers.len(), 2);
-        array_left.push(numbers[0]);
-        array_right.push(numbers[1]);
-    }
+    solve(&parse(input)?)
+}
 
-    let output: i64 = zip(array_left.into_iter().sorted(), array_right.into_iter().sorted())
+pub fn solve((array_left, array_right): &LocationLists) -> miette::Result<String> {
+    let output: i64 = zip(array_left.iter().sorted(), array_right.iter().sorted())
         //.inspect(|(l, r)| print!("{l}+{r}=", ))
         .map(|(l, r)| (l - r).abs())
         //.inspect(|res| println!("{res}", ))
@@ -51,4 +42,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3   4\n4   x\n2").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 5: expected a number",
+            err.to_string()
+        );
+        let err = parse("3   4\n4   3\n2").unwrap_err();
+        assert_eq!(
+            "Parse error at line 3, column 2: expected a second number",
+            err.to_string()
+        );
+    }
+
 }
@@ -1,26 +1,17 @@
+use crate::day01_common::{parse, LocationLists};
 
 use counter::Counter;
 
 pub fn process(input: &str) -> miette::Result<String> {
-    let mut array_left: Vec<i32> = Vec::new();
-    let mut array_right: Vec<i32> = Vec::new();
-
-    for line in input.lines() {
-        let numbers: Vec<i32> = line
-            .split_whitespace()
-            .filter_map(|s| s.parse().ok())
-            .collect();
-
-        assert_eq!(numbers.len(), 2);
-        array_left.push(numbers[0]);
-        array_right.push(numbers[1]);
-    }
+    solve(&parse(input)?)
+}
 
-    let array_right_counts = array_right.into_iter().collect::<Counter<_>>();
+pub fn solve((array_left, array_right): &LocationLists) -> miette::Result<String> {
+    let array_right_counts = array_right.iter().copied().collect::<Counter<_>>();
 
     let output: usize = array_left
-        .into_iter()
-        .map(|num_left| usize::try_from(num_left).unwrap() * array_right_counts[&num_left])
+        .iter()
+        .map(|&num_left| usize::try_from(num_left).unwrap() * array_right_counts[&num_left])
         .sum();
 
     Ok(output.to_string())
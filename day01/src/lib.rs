@@ -12,5 +12,40 @@
     clippy::doc_markdown,
 )]
 
+pub mod custom_error;
+pub mod day01_common;
+pub mod day01_generate;
 pub mod day01_part1;
-pub mod day01_part2;
\ No newline at end of file
+pub mod day01_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 1: both parts compare the left and the right list of location IDs
+pub struct Day01;
+
+impl Day for Day01 {
+    const DAY: u8 = 1;
+    type Input = day01_common::LocationLists;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day01_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day01_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day01_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day01_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day01>::PART1, &DayPart::<Day01>::PART2];
@@ -0,0 +1,19 @@
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// The reports, each a list of levels
+pub type Reports = Vec<Vec<i32>>;
+
+pub fn parse(input: &str) -> Result<Reports, AocError> {
+    let context = ParseContext::new(input);
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|level| context.parse(level, "a number"))
+                .collect()
+        })
+        .collect::<Result<_, _>>()
+        .map_err(AocError::from)
+}
@@ -0,0 +1,26 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// `size` reports of 5 to 8 levels; most reports change steadily, some have a bad level
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let reports = (0..size.max(1))
+        .map(|_| {
+            let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level: i32 = rng.gen_range(20..80);
+            (0..rng.gen_range(5..=8))
+                .map(|_| {
+                    let current_level = level;
+                    let step = if rng.gen_bool(0.1) {
+                        rng.gen_range(-4..=4)
+                    } else {
+                        direction * rng.gen_range(1..=3)
+                    };
+                    level = (level + step).max(1);
+                    current_level
+                })
+                .join(" ")
+        })
+        .collect_vec();
+    GeneratedInput::new(reports.join("\n"))
+}
@@ -4,19 +4,16 @@ use num_traits::ToPrimitive;
 use rayon::prelude::*;
 use utils::split_by_newline;
 
+use crate::day02_common::{parse, Reports};
+
 
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let input: Vec<Vec<i32>> = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .filter_map(|s| s.parse().ok())
-                .collect()
-        })
-        .collect();
+    solve(&parse(input)?)
+}
 
+pub fn solve(input: &Reports) -> miette::Result<String> {
     let result = input
         .iter()
         // .inspect(|report| {
@@ -15,5 +15,40 @@
 )]
 
 
+pub mod custom_error;
+pub mod day02_common;
+pub mod day02_generate;
 pub mod day02_part1;
 pub mod day02_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 2: both parts check the safety of the parsed reports
+pub struct Day02;
+
+impl Day for Day02 {
+    const DAY: u8 = 2;
+    type Input = day02_common::Reports;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day02_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day02_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day02_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day02_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day02>::PART1, &DayPart::<Day02>::PART2];
@@ -13,8 +13,8 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let file = utils::input::load_input_from_args("day03/input1.txt")?;
+    let result = process(&file).context("process part 1")?;
     println!("{}", result);
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Noise between the instructions, including incomplete `mul` instructions
+const NOISE: &[&str] = &[
+    "what()",
+    "who()",
+    "from()",
+    "select()",
+    "where()",
+    "how()",
+    "#",
+    "%",
+    "&",
+    "*",
+    "+",
+    "-",
+    ":",
+    ";",
+    "<",
+    ">",
+    "?",
+    "@",
+    "[",
+    "]",
+    "^",
+    "{",
+    "}",
+    "~",
+    "'",
+    " ",
+    ",",
+    "mul(",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "mul(4*",
+    "mul(6,9!",
+    "?(12,34)",
+    "do(",
+    "don't",
+];
+
+/// Corrupted memory with `size` instructions (mostly `mul(x,y)`, some `do()` and `don't()`)
+/// surrounded by noise, in lines of up to 20 instructions
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let instructions = (0..size.max(1))
+        .map(|_| {
+            let noise = (0..rng.gen_range(0..4))
+                .map(|_| *NOISE.choose(rng).unwrap())
+                .collect::<String>();
+            let instruction = match rng.gen_range(0..10) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                _ => format!("mul({},{})", rng.gen_range(1..1000), rng.gen_range(1..1000)),
+            };
+            noise + &instruction
+        })
+        .collect_vec();
+    let lines = instructions
+        .chunks(20)
+        .map(|line_instructions| line_instructions.concat())
+        .collect_vec();
+    GeneratedInput::new(lines.join("\n"))
+}
@@ -15,5 +15,39 @@
 )]
 
 
+pub mod custom_error;
+pub mod day03_generate;
 pub mod day03_part1;
 pub mod day03_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 3: both parts scan the corrupted memory themselves, so parsing just keeps the text
+pub struct Day03;
+
+impl Day for Day03 {
+    const DAY: u8 = 3;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day03_part1::process(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day03_part2::process(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day03_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day03>::PART1, &DayPart::<Day03>::PART2];
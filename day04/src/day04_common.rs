@@ -0,0 +1,9 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Neighborhood, Topology},
+};
+
+/// Parses the word search (every character is a letter of it)
+pub fn parse(input: &str) -> GridArray<char> {
+    GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Square, input)
+}
@@ -0,0 +1,12 @@
+use utils::generate::{grid_to_string, random_grid, GeneratedInput, StdRng};
+
+/// A `size` x `size` word search of the letters `X`, `M`, `A` and `S`
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let size = size.max(1);
+    GeneratedInput::new(grid_to_string(&random_grid(
+        rng,
+        size,
+        size,
+        &['X', 'M', 'A', 'S'],
+    )))
+}
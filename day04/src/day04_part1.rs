@@ -5,53 +5,42 @@ use grid::{
     grid_array::GridArray,
     grid_iteration::adjacent_cell,
     grid_types::{Coor2DMut, Direction, Neighborhood, Topology},
+    pattern::Symmetries,
 };
 use itertools::{EitherOrBoth, Itertools};
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
+use crate::day04_common::parse;
+
 
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let grid =
-        GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Square, input);
+    solve(&parse(input))
+}
 
-    let search = "XMAS";
+pub fn solve(grid: &GridArray<char>) -> miette::Result<String> {
+    // the rotations of both patterns cover all 8 directions
+    let straight =
+        GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Square, "XMAS\n");
+    let diagonal = GridArray::from_newline_separated_string(
+        Topology::Bounded,
+        Neighborhood::Square,
+        "X...\n.M..\n..A.\n...S\n",
+    );
 
-    let counter = grid
-        .all_indexes()
-        .map(|coor| {
-            grid.all_adjacent_directions()
-                .filter(|direction| check(search, &coor, &grid, *direction))
-                .count()
+    let counter = [straight, diagonal]
+        .iter()
+        .map(|pattern| {
+            grid.find_pattern(pattern, Some(&'.'), Symmetries::Rotations)
+                .len()
         })
         .sum::<usize>();
 
     Ok(counter.to_string())
 }
 
-fn check(
-    search: &str,
-    coor: &Coor2DMut<usize>,
-    grid: &GridArray<char>,
-    direction: Direction,
-) -> bool {
-    let coor_sequence = (1..=search.len()).scan(Some(coor.clone()), |cur_coor_opt, index| {
-        let last_corr_opt = cur_coor_opt.as_ref().cloned();
-        *cur_coor_opt = match (&cur_coor_opt, index) {
-            (None, _) => None,
-            (Some(cur_coor), _) => grid.adjacent_cell(cur_coor.x, cur_coor.y, direction),
-        };
-
-        last_corr_opt
-    });
-    let char_sequence = coor_sequence
-        .flat_map(|coor| grid.get(coor.x, coor.y))
-        .collect::<String>();
-    char_sequence == search
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
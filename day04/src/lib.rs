@@ -15,5 +15,41 @@
 )]
 
 
+pub mod custom_error;
+pub mod day04_common;
+pub mod day04_generate;
 pub mod day04_part1;
 pub mod day04_part2;
+
+use grid::grid_array::GridArray;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 4: both parts search the same letter grid
+pub struct Day04;
+
+impl Day for Day04 {
+    const DAY: u8 = 4;
+    type Input = GridArray<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day04_common::parse(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day04_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day04_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day04_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day04>::PART1, &DayPart::<Day04>::PART2];
@@ -13,8 +13,8 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let file = utils::input::load_input_from_args("day05/input2.txt")?;
+    let result = process(&file).context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
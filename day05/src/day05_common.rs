@@ -0,0 +1,51 @@
+use fxhash::FxHashMap;
+use smallvec::SmallVec;
+use utils::parse_error::{ParseContext, ParseError};
+
+use crate::custom_error::AocError;
+
+pub type Page = u8;
+pub type PageVec = SmallVec<[Page; 20]>;
+/// For every page the pages which must be printed before it
+pub type Rules = FxHashMap<Page, PageVec>;
+/// The page ordering rules and the updates
+pub type PrintQueue = (Rules, Vec<PageVec>);
+
+fn parse_page(context: &ParseContext, page_str: &str) -> Result<Page, ParseError> {
+    context.parse(page_str, "a page number")
+}
+
+fn parse_rule(context: &ParseContext, rule: &str) -> Result<(Page, Page), ParseError> {
+    let (rule_0_str, rule_1_str) = context.split_once(rule, "|")?;
+    Ok((
+        parse_page(context, rule_0_str)?,
+        parse_page(context, rule_1_str)?,
+    ))
+}
+
+fn parse_page_vec(context: &ParseContext, update: &str) -> Result<PageVec, ParseError> {
+    update
+        .split(',')
+        .map(|page_str| parse_page(context, page_str))
+        .collect()
+}
+
+fn parse_rules(context: &ParseContext, rules_str: &str) -> Result<Rules, ParseError> {
+    let mut rules = Rules::default();
+    for rule in rules_str.lines() {
+        let (rule_0, rule_1) = parse_rule(context, rule)?;
+        rules.entry(rule_1).or_default().push(rule_0);
+    }
+    Ok(rules)
+}
+
+pub fn parse(input: &str) -> Result<PrintQueue, AocError> {
+    let context = ParseContext::new(input);
+    let (rules_str, updates_str) = context.split_once(input, "\n\n")?;
+    let rules = parse_rules(&context, rules_str)?;
+    let updates = updates_str
+        .lines()
+        .map(|update| parse_page_vec(&context, update))
+        .collect::<Result<_, _>>()?;
+    Ok((rules, updates))
+}
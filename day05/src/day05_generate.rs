@@ -0,0 +1,29 @@
+use itertools::Itertools;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Ordering rules for every pair of up to `size` pages (5 to 90) and `size` updates of an odd
+/// number of pages; about half of the updates are in the right order
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let mut pages = (10..10 + size.clamp(5, 90)).collect_vec();
+    pages.shuffle(rng);
+    let rules = pages
+        .iter()
+        .tuple_combinations()
+        .map(|(before, after)| format!("{before}|{after}"))
+        .collect_vec();
+    let updates = (0..size.max(1))
+        .map(|_| {
+            let max_len = (pages.len().min(23) - 1) / 2;
+            let len = 2 * rng.gen_range(1..=max_len) + 1;
+            // indexes into the ordered pages, so sorting them sorts the update
+            let mut indexes = index::sample(rng, pages.len(), len).into_vec();
+            if rng.gen_bool(0.5) {
+                indexes.sort_unstable();
+            }
+            indexes.iter().map(|&index| pages[index]).join(",")
+        })
+        .collect_vec();
+    GeneratedInput::new(format!("{}\n\n{}", rules.join("\n"), updates.join("\n")))
+}
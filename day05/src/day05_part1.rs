@@ -2,51 +2,27 @@ use fxhash::FxHashMap;
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
-use smallvec::SmallVec;
 
+use crate::day05_common::{parse, Page, PrintQueue, Rules};
 
 
-type Page = u8;
-type PageVec = SmallVec<[Page; 20]>;
-
-fn parse_page(rule_0_str: &str) -> Page {
-    rule_0_str.parse::<Page>().unwrap()
-}
-
-fn parse_rule(rule: &str) -> (u8, u8) {
-    let (rule_0_str, rule_1_str) = rule.split_once('|').unwrap();
-    (parse_page(rule_0_str), parse_page(rule_1_str))
-}
-
-fn parse_page_vec(update: &str) -> PageVec {
-    update.split(',').map(parse_page).collect::<PageVec>()
-}
-
-fn parse_rules(rules_str: &str) -> FxHashMap<Page, PageVec> {
-    let mut rules: FxHashMap<Page, PageVec> = FxHashMap::default();
-    for rule in rules_str.lines() {
-        let (rule_0, rule_1) = parse_rule(rule);
-        rules.entry(rule_1).or_default().push(rule_0);
-    }
-    rules
-}
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let input = input.replace("\r\n", "\n");
-    let (rules_str, updates_str) = input.split_once("\n\n").unwrap();
-    let rules = parse_rules(rules_str);
-    let updates = updates_str.lines().map(parse_page_vec);
+    solve(&parse(input)?)
+}
 
+pub fn solve((rules, updates): &PrintQueue) -> miette::Result<String> {
     let result: usize = updates
-        .filter(|update| is_sorted_by_rules(update, &rules))
+        .iter()
+        .filter(|update| is_sorted_by_rules(update, rules))
         .map(|sorted_update| sorted_update[sorted_update.len() / 2] as usize)
         .sum();
 
     Ok(result.to_string())
 }
 
-fn is_sorted_by_rules(update: &[Page], ordering_rules: &FxHashMap<Page, PageVec>) -> bool {
+fn is_sorted_by_rules(update: &[Page], ordering_rules: &Rules) -> bool {
     update.windows(2).all(|window| {
         let a = &window[0];
         let b = &window[1];
@@ -54,20 +30,20 @@ fn is_sorted_by_rules(update: &[Page], ordering_rules: &FxHashMap<Page, PageVec>
         let b_should_be_greater_than_a = || {
             ordering_rules
                 .get(b)
-                .map_or(true, |b_rules| b_rules.contains(a))
+                .is_none_or(|b_rules| b_rules.contains(a))
         };
 
         let a_should_not_be_greater_than_b = || {
             ordering_rules
                 .get(a)
-                .map_or(true, |a_rules| !a_rules.contains(b))
+                .is_none_or(|a_rules| !a_rules.contains(b))
         };
 
         b_should_be_greater_than_a() && a_should_not_be_greater_than_b()
     })
 }
 
-fn is_sorted_by_rules_2(update: &[Page], ordering_rules: &FxHashMap<Page, PageVec>) -> bool {
+fn is_sorted_by_rules_2(update: &[Page], ordering_rules: &Rules) -> bool {
     // Iterate over pairs of adjacent elements
     for window in update.windows(2) {
         let a = window[0];
@@ -91,6 +67,8 @@ fn is_sorted_by_rules_2(update: &[Page], ordering_rules: &FxHashMap<Page, PageVe
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -133,4 +111,30 @@ mod tests {
         assert_eq!("5588", process(input)?);
         Ok(())
     }
+
+    /// Random ordering rules and updates over a few pages, so that updates often follow the rules
+    fn print_queue() -> impl Strategy<Value = (Rules, Vec<Page>)> {
+        let page = 10..16_u8;
+        (
+            prop::collection::vec((page.clone(), page.clone()), 0..30),
+            prop::collection::vec(page, 1..6),
+        )
+            .prop_map(|(rule_pairs, update)| {
+                let mut rules = Rules::default();
+                for (before, after) in rule_pairs {
+                    rules.entry(after).or_default().push(before);
+                }
+                (rules, update)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_is_sorted_by_rules_implementations_agree((rules, update) in print_queue()) {
+            prop_assert_eq!(
+                is_sorted_by_rules(&update, &rules),
+                is_sorted_by_rules_2(&update, &rules)
+            );
+        }
+    }
 }
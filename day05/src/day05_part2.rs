@@ -2,20 +2,20 @@ use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
-
-
-use std::collections::HashMap;
+use crate::day05_common::{parse, Page, PrintQueue, Rules};
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (rules, updates) = parse_input(input);
+    solve(&parse(input)?)
+}
 
-    let result: i32 = updates
-        .into_par_iter()
+pub fn solve((rules, updates): &PrintQueue) -> miette::Result<String> {
+    let result: usize = updates
+        .par_iter()
         .filter_map(|update| {
-            let sorted_update = sorted_by_rules(&update, &rules);
-            if update != sorted_update {
-                Some(sorted_update[sorted_update.len() / 2])
+            let sorted_update = sorted_by_rules(update, rules);
+            if update.as_slice() != sorted_update.as_slice() {
+                Some(usize::from(sorted_update[sorted_update.len() / 2]))
             } else {
                 None
             }
@@ -25,12 +25,12 @@ pub fn process(input: &str) -> miette::Result<String> {
     Ok(result.to_string())
 }
 
-fn sorted_by_rules(update: &[i32], rules: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
+fn sorted_by_rules(update: &[Page], rules: &Rules) -> Vec<Page> {
     let mut sorted_update = update.to_vec();
     sorted_update.sort_unstable_by(|&a, &b| {
-        if rules.get(&b).map_or(false, |v| v.contains(&a)) {
+        if rules.get(&b).is_some_and(|v| v.contains(&a)) {
             std::cmp::Ordering::Less
-        } else if rules.get(&a).map_or(false, |v| v.contains(&b)) {
+        } else if rules.get(&a).is_some_and(|v| v.contains(&b)) {
             std::cmp::Ordering::Greater
         } else {
             a.cmp(&b)
@@ -39,30 +39,6 @@ fn sorted_by_rules(update: &[i32], rules: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
     sorted_update
 }
 
-fn parse_input(input: &str) -> (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>) {
-    let input = input.replace("\r\n", "\n");
-    let (rules_str, updates_str) = input.split_once("\n\n").unwrap();
-
-    let mut rules: HashMap<i32, Vec<i32>> = HashMap::default();
-    for rule in rules_str.lines() {
-        let (rule_0_str, rule_1_str) = rule.split_once('|').unwrap();
-        let rule_0 = rule_0_str.parse::<i32>().unwrap();
-        let rule_1 = rule_1_str.parse::<i32>().unwrap();
-        rules.entry(rule_1).or_default().push(rule_0);
-    }
-
-    let updates = updates_str
-        .lines()
-        .map(|update| {
-            update
-                .split(',')
-                .map(|page_str| page_str.parse::<i32>().unwrap())
-                .collect_vec()
-        })
-        .collect_vec();
-    (rules, updates)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +83,18 @@ mod tests {
         assert_eq!("5331", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("47|53\n97-13\n\n75,47").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 6: expected '|'",
+            err.to_string()
+        );
+        let err = parse("47|53\n\n75,x7,61").unwrap_err();
+        assert_eq!(
+            "Parse error at line 3, column 4: expected a page number",
+            err.to_string()
+        );
+    }
 }
@@ -14,5 +14,40 @@
     clippy::bool_to_int_with_if
 )]
 
+pub mod custom_error;
+pub mod day05_common;
+pub mod day05_generate;
 pub mod day05_part1;
 pub mod day05_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 5: both parts check the updates against the same page ordering rules
+pub struct Day05;
+
+impl Day for Day05 {
+    const DAY: u8 = 5;
+    type Input = day05_common::PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day05_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day05_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day05_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day05_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day05>::PART1, &DayPart::<Day05>::PART2];
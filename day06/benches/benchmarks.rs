@@ -1,4 +1,5 @@
 use day06::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,11 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day06_part1() {
-    day06_part1::process(divan::black_box(include_str!("../input1.txt",))).unwrap();
+fn day06_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day06/input1.txt").unwrap();
+    bencher.bench(|| Day06::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day06_part2() {
-    day06_part2::process(divan::black_box(include_str!("../input2.txt",))).unwrap();
+fn day06_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day06/input1.txt").unwrap();
+    let input = Day06::parse(&input).unwrap();
+    let params = Day06::default_params(1);
+    bencher.bench(|| Day06::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day06_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day06/input2.txt").unwrap();
+    let input = Day06::parse(&input).unwrap();
+    let params = Day06::default_params(2);
+    bencher.bench(|| Day06::part2(divan::black_box(&input), &params).unwrap());
 }
@@ -0,0 +1,55 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Direction, Neighborhood, Topology, UCoor2D},
+};
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// The map of the lab (with the guard replaced by '.') and where the guard starts
+#[derive(Debug, Clone)]
+pub struct Lab {
+    pub grid: GridArray<char>,
+    pub start_pos: UCoor2D,
+    pub start_direction: Direction,
+}
+
+pub fn parse(input: &str) -> Result<Lab, AocError> {
+    let mut grid = GridArray::from_newline_separated_string(
+        Topology::Bounded,
+        Neighborhood::Orthogonal,
+        input,
+    );
+    let (start_pos, start_direction) = get_start_pos_and_direction(input, &mut grid)?;
+    Ok(Lab {
+        grid,
+        start_pos,
+        start_direction,
+    })
+}
+
+fn get_start_pos_and_direction(
+    input: &str,
+    grid: &mut GridArray<char>,
+) -> Result<(UCoor2D, Direction), AocError> {
+    let context = ParseContext::new(input);
+    let (start_pos, direction_ch) = grid
+        .all_cells()
+        .find(|(_coor, ch)| ch != &&'.' && ch != &&'#')
+        .ok_or_else(|| context.error_after(input, "a guard ('^', '>', 'v' or '<')"))?;
+    let start_direction = Direction::from_arrow(*direction_ch).ok_or_else(|| {
+        context.cell_error(
+            start_pos.x,
+            start_pos.y,
+            "'.', '#' or a guard ('^', '>', 'v' or '<')",
+        )
+    })?;
+    grid.set(start_pos.x, start_pos.y, '.');
+    if let Some((coor, _ch)) = grid
+        .all_cells()
+        .find(|(_coor, ch)| ch != &&'.' && ch != &&'#')
+    {
+        return Err(context.cell_error(coor.x, coor.y, "'.' or '#'").into());
+    }
+    Ok((start_pos, start_direction))
+}
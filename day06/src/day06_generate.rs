@@ -0,0 +1,16 @@
+use rand::Rng;
+use utils::generate::{grid_to_string, GeneratedInput, StdRng};
+
+/// A `size` x `size` lab with about one obstruction in ten cells and the guard facing north
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let size = size.max(2);
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|_y| {
+            (0..size)
+                .map(|_x| if rng.gen_bool(0.1) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    rows[rng.gen_range(0..size)][rng.gen_range(0..size)] = '^';
+    GeneratedInput::new(grid_to_string(&rows))
+}
@@ -1,39 +1,26 @@
 use std::collections::HashMap;
 
 
-use grid::{grid_array::GridArray, grid_iteration::adjacent_cell, grid_types::UCoor2D};
+use grid::{grid_array::GridArray, grid_types::UCoor2D};
 
 use grid::grid_types::Direction;
 
-use grid::grid_types::{Neighborhood, Topology};
+use crate::day06_common::{parse, Lab};
 
 pub fn process(input: &str) -> miette::Result<String> {
-    let mut grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-    );
-    let (start_pos, start_direction) = get_start_pos_and_direction(&mut grid);
-    let (is_loop, all_possible_coors_count) =
-        get_all_possible_coors(&grid, &start_pos, start_direction);
-    println!("is_loop: {is_loop}");
-
-    Ok(all_possible_coors_count.to_string())
+    solve(&parse(input)?)
 }
 
-fn get_start_pos_and_direction(grid: &mut GridArray<char>) -> (UCoor2D, Direction) {
-    let (start_pos, direction_ch) = grid
-        .all_cells()
-        .find(|(_coor, ch)| ch != &&'.' && ch != &&'#')
-        .unwrap();
-    let start_direction = match direction_ch {
-        '>' => Direction::East,
-        '^' => Direction::North,
-        '<' => Direction::West,
-        _ => panic!("Invalid direction {direction_ch}"),
-    };
-    grid.set(start_pos.x, start_pos.y, '.');
-    (start_pos, start_direction)
+pub fn solve(lab: &Lab) -> miette::Result<String> {
+    let Lab {
+        grid,
+        start_pos,
+        start_direction,
+    } = lab;
+    let start_direction = *start_direction;
+    let (_, all_possible_coors_count) = get_all_possible_coors(grid, start_pos, start_direction);
+
+    Ok(all_possible_coors_count.to_string())
 }
 
 fn get_all_possible_coors(
@@ -41,35 +28,26 @@ fn get_all_possible_coors(
     start_pos: &UCoor2D,
     start_direction: Direction,
 ) -> (bool, usize) {
-    let mut pos = start_pos.clone();
+    let mut pos = *start_pos;
     let mut direction = start_direction;
     let mut all_positions = HashMap::new();
-    all_positions.insert(pos.clone(), 1);
+    all_positions.insert(pos, 1);
     loop {
-        match adjacent_cell(
-            grid.get_topology(),
-            grid.width(),
-            grid.height(),
-            pos.clone(),
-            direction,
-        )
-        .map(|next_pos| (grid.get_unchecked(next_pos.x, next_pos.y), next_pos))
-        {
-            Some(('.', next_pos)) => {
-                println!("pos/dir: {pos}{direction:?} => next_pos: {next_pos}");
-                let count = all_positions.entry(next_pos.clone()).or_insert(0);
-                *count += 1;
-                if *count >= 3 {
-                    return (true, all_positions.len());
-                }
-                pos = next_pos;
-            }
-            Some(('#', _)) => {
-                direction = direction.rotate(90);
+        let mut ray = grid
+            .ray(pos, direction.diff_coor())
+            .blocked_by(|&cell| cell == '#');
+        for (next_pos, _cell) in ray.by_ref() {
+            let count = all_positions.entry(next_pos).or_insert(0);
+            *count += 1;
+            if *count >= 3 {
+                return (true, all_positions.len());
             }
-            Some((ch, _)) => panic!("Invalid character {ch}"),
-            None => return (false, all_positions.len()),
+            pos = next_pos;
         }
+        if ray.obstacle().is_none() {
+            return (false, all_positions.len());
+        }
+        direction = direction.turn_right();
     }
 }
 
@@ -99,4 +77,13 @@ mod tests {
         assert_eq!(4977.to_string(), process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("..#\n.x.\n.^.").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 2: expected '.', '#' or a guard ('^', '>', 'v' or '<')",
+            err.to_string()
+        );
+    }
 }
@@ -3,25 +3,34 @@ use std::collections::HashSet;
 
 use grid::{grid_array::GridArray, grid_types::UCoor2D};
 
-use grid::grid_types::Direction;
-
-use grid::grid_types::{Neighborhood, Topology};
+use grid::grid_types::{CardinalDirection, Direction};
+use grid::jump_table::JumpTable;
 
 use itertools::Itertools;
 use rayon::prelude::*;
 
+use crate::day06_common::{parse, Lab};
+
 pub fn process(input: &str) -> miette::Result<String> {
-    let mut grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-    );
-    let (start_pos, start_direction) = get_start_pos_and_direction(&mut grid);
+    solve(&parse(input)?)
+}
+
+pub fn solve(lab: &Lab) -> miette::Result<String> {
+    let Lab {
+        grid,
+        start_pos,
+        start_direction,
+    } = lab;
+    let start_direction = *start_direction;
 
     let (has_loop, all_possible_positions) =
-        get_all_possible_coors(&grid, None, &start_pos, start_direction);
+        get_all_possible_coors(grid, start_pos, start_direction);
     assert!(!has_loop);
 
+    let jumps = JumpTable::new(grid, |&cell| cell == '#');
+    let start_direction = CardinalDirection::try_from(start_direction)
+        .expect("the guard faces north, east, south or west");
+
     let unique = all_possible_positions
         .into_iter()
         .map(|(coor, _dir)| coor)
@@ -29,50 +38,30 @@ pub fn process(input: &str) -> miette::Result<String> {
     let result = unique
         .collect_vec()
         .into_par_iter()
-        .filter(|possible_coor| *possible_coor != start_pos)
-        .filter(|possible_coor| {
-            let (has_loop, _) =
-                get_all_possible_coors(&grid, Some(possible_coor), &start_pos, start_direction);
-            has_loop
-        })
+        .filter(|possible_coor| possible_coor != start_pos)
+        .filter(|possible_coor| jumps.has_loop(*start_pos, start_direction, Some(*possible_coor)))
         .count();
 
     Ok(result.to_string())
 }
 
-fn get_start_pos_and_direction(grid: &mut GridArray<char>) -> (UCoor2D, Direction) {
-    let (start_pos, direction_ch) = grid
-        .all_cells()
-        .find(|(_coor, ch)| ch != &&'.' && ch != &&'#')
-        .unwrap();
-    let start_direction = match direction_ch {
-        '>' => Direction::East,
-        '^' => Direction::North,
-        '<' => Direction::West,
-        _ => panic!("Invalid direction {direction_ch}"),
-    };
-    grid.set(start_pos.x, start_pos.y, '.');
-    (start_pos, start_direction)
-}
-
 fn get_all_possible_coors(
     grid: &GridArray<char>,
-    additional_obstacle_pos: Option<&UCoor2D>,
     start_pos: &UCoor2D,
     start_direction: Direction,
 ) -> (bool, HashSet<(UCoor2D, Direction)>) {
-    let mut pos = start_pos.clone();
+    let mut pos = *start_pos;
     let mut direction = start_direction;
     let mut all_positions = HashSet::new();
-    all_positions.insert((pos.clone(), direction));
+    all_positions.insert((pos, direction));
     loop {
         match grid
             .adjacent_cell(pos.x, pos.y, direction)
             .map(|next_pos| (grid.get_unchecked(next_pos.x, next_pos.y), next_pos))
         {
-            Some(('.', next_pos)) if Some(&next_pos) != additional_obstacle_pos => {
+            Some(('.', next_pos)) => {
                 //println!("pos/dir: {pos}{direction:?} => next_pos: {next_pos}");
-                let new_pos_dir_tuple = (next_pos.clone(), direction);
+                let new_pos_dir_tuple = (next_pos, direction);
                 if all_positions.contains(&new_pos_dir_tuple) {
                     return (true, all_positions);
                 }
@@ -80,10 +69,7 @@ fn get_all_possible_coors(
                 pos = next_pos;
             }
             Some(('#', _)) => {
-                direction = direction.rotate(90);
-            }
-            Some(('.', next_pos)) if Some(&next_pos) == additional_obstacle_pos => {
-                direction = direction.rotate(90);
+                direction = direction.turn_right();
             }
             Some((ch, _)) => panic!("Invalid character {ch}"),
             None => return (false, all_positions),
@@ -13,5 +13,40 @@
     clippy::module_name_repetitions
 )]
 
+pub mod custom_error;
+pub mod day06_common;
+pub mod day06_generate;
 pub mod day06_part1;
 pub mod day06_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 6: both parts follow the guard through the same lab
+pub struct Day06;
+
+impl Day for Day06 {
+    const DAY: u8 = 6;
+    type Input = day06_common::Lab;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day06_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day06_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day06_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day06_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day06>::PART1, &DayPart::<Day06>::PART2];
@@ -0,0 +1,11 @@
+use utils::parse::{lines_of, list_of, parse_all, separated, unsigned};
+
+use crate::custom_error::AocError;
+
+/// The calibration equations, each a test value with its numbers
+pub type Equations = Vec<(usize, Vec<usize>)>;
+
+pub fn parse(input: &str) -> Result<Equations, AocError> {
+    let equation = separated(unsigned, ": ", list_of(" ", unsigned));
+    Ok(parse_all(input, lines_of(equation))?)
+}
@@ -0,0 +1,42 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Results are kept below this, so that the solutions can't overflow
+const MAX_RESULT: usize = 1_000_000_000_000_000;
+
+/// `size` equations of 2 to 9 numbers; about half of them have a result calculated with random
+/// operators (`+`, `*` and `||`)
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let equations = (0..size.max(1))
+        .map(|_| {
+            let numbers = (0..rng.gen_range(2..=9))
+                .map(|_| {
+                    if rng.gen_bool(0.5) {
+                        rng.gen_range(1..10)
+                    } else {
+                        rng.gen_range(1..1000)
+                    }
+                })
+                .collect_vec();
+            let result = if rng.gen_bool(0.5) {
+                numbers[1..]
+                    .iter()
+                    .fold(numbers[0], |result: usize, &number| {
+                        let calculated = match rng.gen_range(0..3) {
+                            0 => Some(result + number),
+                            1 => result.checked_mul(number),
+                            _ => format!("{result}{number}").parse().ok(),
+                        };
+                        calculated
+                            .filter(|&calculated| calculated < MAX_RESULT)
+                            .unwrap_or(result + number)
+                    })
+            } else {
+                rng.gen_range(1..1_000_000)
+            };
+            format!("{result}: {}", numbers.iter().join(" "))
+        })
+        .collect_vec();
+    GeneratedInput::new(equations.join("\n"))
+}
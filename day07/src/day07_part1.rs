@@ -1,17 +1,12 @@
-
+use crate::day07_common::{parse, Equations};
 
 pub fn process(input: &str) -> miette::Result<String> {
-    let input = input.lines().map(|line| {
-        let (result, args_str) = line.split_once(": ").unwrap();
-
-        let args: Vec<usize> = args_str
-            .split(' ')
-            .map(|arg_str| arg_str.parse().unwrap())
-            .collect();
-        (result.parse::<usize>().unwrap(), args)
-    });
+    solve(&parse(input)?)
+}
 
+pub fn solve(input: &Equations) -> miette::Result<String> {
     let result: usize = input
+        .iter()
         .filter(|(result, args)| is_solvable(*result, args))
         .map(|(result, _)| result)
         .sum();
@@ -58,6 +53,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = parse("190: 10 19\n3267: 81 x 27").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 10: expected a number",
+            err.to_string()
+        );
+    }
+
     #[test]
     fn test_part1() -> miette::Result<()> {
         let input = include_str!("../input1.txt");
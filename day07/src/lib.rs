@@ -13,6 +13,40 @@
     clippy::module_name_repetitions,
 )]
 
+pub mod custom_error;
+pub mod day07_common;
+pub mod day07_generate;
 pub mod day07_part1;
 pub mod day07_part2;
 
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 7: both parts check which calibration equations can be solved
+pub struct Day07;
+
+impl Day for Day07 {
+    const DAY: u8 = 7;
+    type Input = day07_common::Equations;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day07_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day07_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day07_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day07_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day07>::PART1, &DayPart::<Day07>::PART2];
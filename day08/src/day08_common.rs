@@ -0,0 +1,9 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Neighborhood, Topology},
+};
+
+/// Parses the map of the antennas ('.' is empty, every other character is an antenna frequency)
+pub fn parse(input: &str) -> GridArray<char> {
+    GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Square, input)
+}
@@ -0,0 +1,17 @@
+use rand::Rng;
+use utils::generate::{grid_to_string, GeneratedInput, StdRng};
+
+/// Frequencies of the antennas
+const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size` x `size` map with 2 to 4 antennas for each of about `size / 4` frequencies
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let size = size.max(2);
+    let mut rows = vec![vec!['.'; size]; size];
+    for frequency in FREQUENCIES.chars().take((size / 4).max(1)) {
+        for _antenna in 0..rng.gen_range(2..=4) {
+            rows[rng.gen_range(0..size)][rng.gen_range(0..size)] = frequency;
+        }
+    }
+    GeneratedInput::new(grid_to_string(&rows))
+}
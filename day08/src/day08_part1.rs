@@ -1,16 +1,13 @@
-use grid::{
-    grid_array::GridArray,
-    grid_types::{Neighborhood, Topology},
-};
+use grid::{grid_array::GridArray, grid_types::ICoor2D};
 use itertools::Itertools;
 
+use crate::day08_common::parse;
 
-
-#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
 pub fn process(input: &str) -> miette::Result<String> {
-    let grid =
-        GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Square, input);
+    solve(&parse(input))
+}
 
+pub fn solve(grid: &GridArray<char>) -> miette::Result<String> {
     let antenna_types = grid
         .all_cells()
         .map(|(_coor, ch)| ch)
@@ -19,10 +16,7 @@ pub fn process(input: &str) -> miette::Result<String> {
     let antennas = antenna_types.map(|antenna_type| {
         grid.all_cells()
             .filter(|(_coor, ch)| *ch == antenna_type)
-            .map(|(coor, _ch)| {
-                let (x, y) = coor.to_tuple();
-                (x as isize, y as isize)
-            })
+            .map(|(coor, _ch)| coor)
             .collect_vec()
     });
 
@@ -30,13 +24,9 @@ pub fn process(input: &str) -> miette::Result<String> {
         .flat_map(|antenna_coors| {
             Itertools::permutations(antenna_coors.iter(), 2)
                 .filter_map(|pair| {
-                    let (point0_x, point0_y) = (pair[0].0, pair[0].1);
-                    let (point1_x, point1_y) = (pair[1].0, pair[1].1);
-                    let (new_coor_x, new_coor_y) =
-                        (2 * point0_x - point1_x, 2 * point0_y - point1_y);
-                    let on_grid = (0 <= new_coor_x && (new_coor_x as usize) < grid.width())
-                        && (new_coor_y >= 0 && (new_coor_y as usize) < grid.height());
-                    on_grid.then_some((new_coor_x, new_coor_y))
+                    let diff_coor =
+                        ICoor2D::try_from(*pair[0]).unwrap() - ICoor2D::try_from(*pair[1]).unwrap();
+                    grid.ray(*pair[0], diff_coor).next().map(|(coor, _ch)| coor)
                 })
                 .collect_vec()
         })
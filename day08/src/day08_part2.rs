@@ -1,18 +1,15 @@
 use std::collections::HashSet;
 
-use grid::{
-    grid_array::GridArray,
-    grid_types::{ICoor2D, Neighborhood, Topology},
-};
+use grid::{grid_array::GridArray, grid_types::ICoor2D};
 use itertools::Itertools;
 
+use crate::day08_common::parse;
 
-
-#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
 pub fn process(input: &str) -> miette::Result<String> {
-    let grid =
-        GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Square, input);
+    solve(&parse(input))
+}
 
+pub fn solve(grid: &GridArray<char>) -> miette::Result<String> {
     let antenna_types = grid
         .all_cells()
         .map(|(_coor, ch)| ch)
@@ -21,26 +18,18 @@ pub fn process(input: &str) -> miette::Result<String> {
     let antennas = antenna_types.map(|antenna_type| {
         grid.all_cells()
             .filter(|(_coor, ch)| *ch == antenna_type)
-            .map(|(coor, _ch)| ICoor2D {
-                x: coor.x as isize,
-                y: coor.y as isize,
-            })
+            .map(|(coor, _ch)| coor)
             .collect_vec()
     });
 
     let mut antinodes = HashSet::new();
     for antenna_coors in antennas {
         for pair in Itertools::permutations(antenna_coors.iter(), 2) {
-            let point0 = pair[0];
-            let point1 = pair[1];
-            let diff_coor = point0 - point1;
-            let mut new_coor = point0.clone();
-            while (0 <= new_coor.x && (new_coor.x as usize) < grid.width())
-                && (0 <= new_coor.y && (new_coor.y as usize) < grid.height())
-            {
-                antinodes.insert(new_coor.clone());
-                new_coor = &new_coor + &diff_coor;
-            }
+            let point0 = *pair[0];
+            let point1 = *pair[1];
+            let diff_coor = ICoor2D::try_from(point0).unwrap() - ICoor2D::try_from(point1).unwrap();
+            antinodes.insert(point0);
+            antinodes.extend(grid.ray(point0, diff_coor).map(|(coor, _ch)| coor));
         }
     }
 
@@ -13,5 +13,41 @@
     clippy::module_name_repetitions
 )]
 
+pub mod custom_error;
+pub mod day08_common;
+pub mod day08_generate;
 pub mod day08_part1;
 pub mod day08_part2;
+
+use grid::grid_array::GridArray;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 8: both parts look for antinodes of the antennas on the same map
+pub struct Day08;
+
+impl Day for Day08 {
+    const DAY: u8 = 8;
+    type Input = GridArray<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day08_common::parse(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day08_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day08_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day08_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day08>::PART1, &DayPart::<Day08>::PART2];
@@ -0,0 +1,41 @@
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// A file (with `id`) followed by free space
+#[derive(Debug, Clone)]
+pub struct Space {
+    pub id: Option<usize>,
+    pub occupied: usize,
+    pub free: usize,
+}
+
+/// The files and free spaces of the disk
+pub type DiskMap = Vec<Space>;
+
+pub fn parse(input: &str) -> Result<DiskMap, AocError> {
+    let context = ParseContext::new(input);
+    input
+        .char_indices()
+        .enumerate()
+        .map(|(i, (index, ch))| {
+            let length = ch
+                .to_digit(10)
+                .ok_or_else(|| context.char_error(input, index, "a digit"))?
+                as usize;
+            Ok(if i % 2 == 0 {
+                Space {
+                    id: Some(i / 2),
+                    occupied: length,
+                    free: 0,
+                }
+            } else {
+                Space {
+                    id: None,
+                    occupied: 0,
+                    free: length,
+                }
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,14 @@
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// A disk map of `size` files (1 to 9 blocks) with free space (0 to 9 blocks) between them
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let mut disk_map = String::new();
+    for file in 0..size.max(1) {
+        if file > 0 {
+            disk_map.push(char::from(b'0' + rng.gen_range(0..10)));
+        }
+        disk_map.push(char::from(b'0' + rng.gen_range(1..10)));
+    }
+    GeneratedInput::new(disk_map)
+}
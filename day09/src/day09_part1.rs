@@ -1,37 +1,13 @@
 // use itertools::Itertools;
 
+use crate::day09_common::{parse, DiskMap, Space};
 
-
-struct Space {
-    id: Option<usize>,
-    occupied: usize,
-    free: usize,
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
 }
 
-pub fn process(input: &str) -> miette::Result<String> {
-    let mut space_list = vec![];
-    let mut free_space = false;
-    let mut id = 0;
-    for ch in input.chars() {
-        let length = ch.to_digit(10).unwrap() as usize;
-        space_list.push(if free_space {
-            Space {
-                id: None,
-                occupied: 0,
-                free: length,
-            }
-        } else {
-            Space {
-                id: Some(id),
-                occupied: length,
-                free: 0,
-            }
-        });
-        free_space = !free_space;
-        if !free_space {
-            id += 1;
-        }
-    }
+pub fn solve(disk_map: &DiskMap) -> miette::Result<String> {
+    let mut space_list = disk_map.clone();
 
     //print_space_list(&space_list);
 
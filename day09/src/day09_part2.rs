@@ -1,15 +1,13 @@
 //use itertools::Itertools;
 
+use crate::day09_common::{parse, DiskMap, Space};
 
-
-struct Space {
-    id: Option<usize>,
-    occupied: usize,
-    free: usize,
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
 }
 
-pub fn process(input: &str) -> miette::Result<String> {
-    let mut space_list = parse_input(input);
+pub fn solve(disk_map: &DiskMap) -> miette::Result<String> {
+    let mut space_list = disk_map.clone();
     let highest_id = space_list.iter().filter_map(|s| s.id).max().unwrap_or(0);
 
     for current_id in (0..=highest_id).rev() {
@@ -79,29 +77,6 @@ fn calculate_checksum(space_list: &[Space]) -> usize {
     checksum
 }
 
-fn parse_input(input: &str) -> Vec<Space> {
-    input
-        .chars()
-        .enumerate()
-        .map(|(i, ch)| {
-            let length = ch.to_digit(10).unwrap() as usize;
-            if i % 2 == 0 {
-                Space {
-                    id: Some(i / 2),
-                    occupied: length,
-                    free: 0,
-                }
-            } else {
-                Space {
-                    id: None,
-                    occupied: 0,
-                    free: length,
-                }
-            }
-        })
-        .collect()
-}
-
 #[allow(dead_code)]
 fn print_space_list(space_list: &Vec<Space>) {
     for space in space_list {
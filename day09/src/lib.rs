@@ -13,5 +13,40 @@
     clippy::module_name_repetitions
 )]
 
+pub mod custom_error;
+pub mod day09_common;
+pub mod day09_generate;
 pub mod day09_part1;
 pub mod day09_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 9: both parts compact the same disk map
+pub struct Day09;
+
+impl Day for Day09 {
+    const DAY: u8 = 9;
+    type Input = day09_common::DiskMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day09_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day09_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day09_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day09_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day09>::PART1, &DayPart::<Day09>::PART2];
@@ -0,0 +1,18 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Neighborhood, Topology},
+};
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// Parses the topographic map of heights
+pub fn parse(input: &str) -> Result<GridArray<usize>, AocError> {
+    ParseContext::new(input).check_chars(input, |ch| ch.is_ascii_digit(), "a height (0-9)")?;
+    Ok(GridArray::from_newline_separated_string_into(
+        Topology::Bounded,
+        Neighborhood::Orthogonal,
+        input,
+        |ch| ch.to_digit(10).unwrap() as usize,
+    ))
+}
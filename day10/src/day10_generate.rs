@@ -0,0 +1,24 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// A `size` x `size` topographic map; most heights are one higher than their left and upper
+/// neighbors, so that there are many hiking trails
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let size = size.max(1);
+    let rows = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    let noise = if rng.gen_bool(0.75) {
+                        0
+                    } else {
+                        rng.gen_range(1..10)
+                    };
+                    char::from(b'0' + u8::try_from((x + y + noise) % 10).unwrap())
+                })
+                .collect::<String>()
+        })
+        .collect_vec();
+    GeneratedInput::new(rows.join("\n"))
+}
@@ -1,7 +1,8 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 use fxhash::FxHashMap;
 use grid::{
+    distance_field::{weighted_distance_field, FieldDirection},
     grid_array::GridArray,
     grid_types::{Neighborhood, Topology, UCoor2D},
 };
@@ -9,72 +10,51 @@ use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
+use crate::day10_common::parse;
+
 
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let grid = GridArray::from_newline_separated_string_into(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-        |ch| ch.to_digit(10).unwrap() as usize,
-    );
+    solve(&parse(input)?)
+}
+
+pub fn solve(grid: &GridArray<usize>) -> miette::Result<String> {
     let trail_head_positions = grid.all_cells().filter(|(_coor, height)| *height == &0);
 
     let result: usize = trail_head_positions
-        .map(|(trail_head_pos, trail_head_height)| {
-            let mut hiking_trails_ends_set = HashSet::new();
-            hiking_trails_ends(
-                &grid,
-                trail_head_pos,
-                *trail_head_height,
-                &mut hiking_trails_ends_set,
+        .map(|(trail_head_pos, _trail_head_height)| {
+            // hiking trails go up by exactly one step
+            let trails = weighted_distance_field(
+                grid,
+                [trail_head_pos],
+                FieldDirection::Forward,
+                |&height, &neighbor_height| (neighbor_height == height + 1).then_some(1),
             );
-            hiking_trails_ends_set.len()
+            grid.all_cells()
+                .filter(|(coor, height)| {
+                    **height == 9 && trails.get_unchecked(coor.x, coor.y).is_some()
+                })
+                .count()
         })
         .sum();
 
     Ok(result.to_string())
 }
 
-fn hiking_trails_ends(
-    grid: &GridArray<usize>,
-    pos: UCoor2D,
-    height: usize,
-    hiking_trails_ends_set: &mut HashSet<UCoor2D>,
-) {
-    if height == 9 {
-        hiking_trails_ends_set.insert(pos);
-        return;
-    }
-
-    for (neighbor_coor, neighbor_height) in grid.neighborhood_cells(pos.x, pos.y) {
-        if *neighbor_height == height + 1 {
-            hiking_trails_ends(
-                grid,
-                neighbor_coor,
-                *neighbor_height,
-                hiking_trails_ends_set,
-            );
-        }
-    }
+pub fn process2(input: &str) -> miette::Result<String> {
+    solve2(&parse(input)?)
 }
 
-pub fn process2(input: &str) -> miette::Result<String> {
-    let grid = GridArray::from_newline_separated_string_into(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-        |ch| ch.to_digit(10).unwrap() as usize,
-    );
+pub fn solve2(grid: &GridArray<usize>) -> miette::Result<String> {
     let summit_positions = grid.all_cells().filter(|(_coor, height)| *height == &9).collect_vec();
     let trail_head_positions = grid.all_cells().filter(|(_coor, height)| *height == &0);
 
-    let mut cache: FxHashMap<UCoor2D, bool> = FxHashMap::default();
+    let mut cache: FxHashMap<(UCoor2D, UCoor2D), bool> = FxHashMap::default();
     let result: usize = trail_head_positions
         .map(|(trail_head_pos, _trail_head_height)| {
             summit_positions.iter().filter(|(summit_pos, _)| {
-                hiking_trail_to_zero_exists(&grid, &trail_head_pos, summit_pos, &9,  &mut cache)
+                hiking_trail_to_zero_exists(grid, &trail_head_pos, summit_pos, &9,  &mut cache)
             }).count()
         })
         .sum();
@@ -87,13 +67,14 @@ fn hiking_trail_to_zero_exists(
     goal: &UCoor2D,
     pos: &UCoor2D,
     height: &usize,
-    cache: &mut FxHashMap<UCoor2D, bool>
+    cache: &mut FxHashMap<(UCoor2D, UCoor2D), bool>
 ) -> bool {
     if height == &0 {
         return goal == pos ;
     }
 
-    if let Some(&count) = cache.get(pos) {
+    let key = (*goal, *pos);
+    if let Some(&count) = cache.get(&key) {
         return count;
     }
     let exists = grid.neighborhood_cells(pos.x, pos.y)
@@ -101,12 +82,14 @@ fn hiking_trail_to_zero_exists(
         .any(|(neighbor_coor, neighbor_height)| {
             hiking_trail_to_zero_exists(grid, goal, &neighbor_coor, neighbor_height, cache)
         });
-    cache.insert(pos.clone(), exists);
+    cache.insert(key, exists);
     exists
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -123,10 +106,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_solve2_example() -> miette::Result<()> {
+        let input = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+        assert_eq!("36", process2(input)?);
+        Ok(())
+    }
+
     #[test]
     fn test_part1() -> miette::Result<()> {
         let input = include_str!("../input1.txt");
         assert_eq!("468", process(input)?);
         Ok(())
     }
+
+    /// Random topographic maps; most cells are one higher than their left and upper
+    /// neighbors, so that complete hiking trails are common
+    fn topographic_map() -> impl Strategy<Value = GridArray<usize>> {
+        (1..12_usize, 1..12_usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop_oneof![3 => Just(0_usize), 1 => 1..10_usize],
+                width * height,
+            )
+            .prop_map(move |noise| {
+                let heights = noise
+                    .iter()
+                    .enumerate()
+                    .map(|(index, noise)| (index % width + index / width + noise) % 10)
+                    .collect();
+                GridArray::from_1d_vec(Topology::Bounded, Neighborhood::Orthogonal, width, heights)
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve2_agrees_with_solve(grid in topographic_map()) {
+            prop_assert_eq!(solve(&grid).ok(), solve2(&grid).ok());
+        }
+    }
 }
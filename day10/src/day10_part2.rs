@@ -9,22 +9,22 @@ use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
+use crate::day10_common::parse;
+
 
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let grid = GridArray::from_newline_separated_string_into(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-        |ch| ch.to_digit(10).unwrap() as usize,
-    );
+    solve(&parse(input)?)
+}
+
+pub fn solve(grid: &GridArray<usize>) -> miette::Result<String> {
     let trail_head_positions = grid.all_cells().filter(|(_coor, height)| *height == &0);
 
     let mut cache: FxHashMap<UCoor2D, usize> = FxHashMap::default();
     let result: usize = trail_head_positions
         .map(|(trail_head_pos, trail_head_height)| {
-            hiking_trails_count(&grid, trail_head_pos, *trail_head_height, &mut cache)
+            hiking_trails_count(grid, trail_head_pos, *trail_head_height, &mut cache)
         })
         .sum();
 
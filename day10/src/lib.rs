@@ -15,5 +15,41 @@
 )]
 
 
+pub mod custom_error;
+pub mod day10_common;
+pub mod day10_generate;
 pub mod day10_part1;
 pub mod day10_part2;
+
+use grid::grid_array::GridArray;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 10: both parts follow the hiking trails of the same topographic map
+pub struct Day10;
+
+impl Day for Day10 {
+    const DAY: u8 = 10;
+    type Input = GridArray<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day10_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day10_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day10_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day10_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day10>::PART1, &DayPart::<Day10>::PART2];
@@ -1,4 +1,5 @@
 use day11::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,17 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day11_part1() {
-    day11_part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn day11_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day11/input1.txt").unwrap();
+    bencher.bench(|| Day11::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day11_part2() {
-    day11_part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn day11_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day11/input1.txt").unwrap();
+    let input = Day11::parse(&input).unwrap();
+    let params = Day11::default_params(1);
+    bencher.bench(|| Day11::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day11_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day11/input2.txt").unwrap();
+    let input = Day11::parse(&input).unwrap();
+    let params = Day11::default_params(2);
+    bencher.bench(|| Day11::part2(divan::black_box(&input), &params).unwrap());
+}
@@ -0,0 +1,15 @@
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// The numbers engraved on the stones
+pub type Stones = Vec<u64>;
+
+pub fn parse(input: &str) -> Result<Stones, AocError> {
+    let context = ParseContext::new(input);
+    input
+        .split(' ')
+        .map(|num_str| context.parse(num_str, "a number"))
+        .collect::<Result<_, _>>()
+        .map_err(AocError::from)
+}
@@ -0,0 +1,18 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// `size` stones with numbers of 1 to 7 digits (some of them 0)
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let stones = (0..size.max(1))
+        .map(|_| {
+            if rng.gen_bool(0.1) {
+                0
+            } else {
+                let digits = rng.gen_range(1..=7);
+                rng.gen_range(1..10_u64.pow(digits))
+            }
+        })
+        .join(" ");
+    GeneratedInput::new(stones)
+}
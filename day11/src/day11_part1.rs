@@ -1,11 +1,20 @@
+use crate::day11_common::{parse, Stones};
 
 
+
+/// Number of blinks of the real puzzle
+pub const CYCLES: usize = 25;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let input = input
-        .split(' ')
-        .map(|num_str| num_str.parse::<u128>().unwrap());
-    let result: u128 = input.map(|num| evolve(num, 25)).sum();
+    solve(&parse(input)?, CYCLES)
+}
+
+pub fn solve(stones: &Stones, cycles: usize) -> miette::Result<String> {
+    let result: u128 = stones
+        .iter()
+        .map(|&num| evolve(u128::from(num), cycles))
+        .sum();
     Ok(result.to_string())
 }
 
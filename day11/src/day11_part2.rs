@@ -2,13 +2,18 @@
 use cached::proc_macro::cached;
 use rayon::prelude::*;
 
+use crate::day11_common::{parse, Stones};
+
+/// Number of blinks of the real puzzle
+pub const CYCLES: u64 = 75;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let input = input
-        .split(' ')
-        .map(|num_str| num_str.parse::<u64>().unwrap());
-    let cycles = 75;
-    let result: u64 = input.par_bridge().map(|num| evolve(num, cycles)).sum();
+    solve(&parse(input)?, CYCLES)
+}
+
+pub fn solve(stones: &Stones, cycles: u64) -> miette::Result<String> {
+    let result: u64 = stones.par_iter().map(|&num| evolve(num, cycles)).sum();
     Ok(result.to_string())
 }
 
@@ -23,7 +28,7 @@ fn evolve(num: u64, cycles_left: u64) -> u64 {
     }
 
     let digit_count = digit_count(num);
-    if digit_count % 2 != 0 {
+    if !digit_count.is_multiple_of(2) {
         return evolve(num * 2024, cycles_left - 1);
     }
 
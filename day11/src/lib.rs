@@ -14,5 +14,47 @@
     clippy::bool_to_int_with_if
 )]
 
+pub mod custom_error;
+pub mod day11_common;
+pub mod day11_generate;
 pub mod day11_part1;
 pub mod day11_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 11: both parts blink at the same stones, just a different number of times
+pub struct Day11;
+
+impl Day for Day11 {
+    const DAY: u8 = 11;
+    type Input = day11_common::Stones;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day11_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<String> {
+        day11_part1::solve(input, params.get("cycles")?)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<String> {
+        day11_part2::solve(input, params.get("cycles")?)
+    }
+
+    fn default_params(part: u8) -> Params {
+        match part {
+            1 => Params::from([("cycles", day11_part1::CYCLES)]),
+            _ => Params::from([("cycles", day11_part2::CYCLES)]),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day11_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day11>::PART1, &DayPart::<Day11>::PART2];
@@ -0,0 +1,9 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Neighborhood, Topology},
+};
+
+/// Parses the garden map (every character is the plant type of a plot)
+pub fn parse(input: &str) -> GridArray<char> {
+    GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Orthogonal, input)
+}
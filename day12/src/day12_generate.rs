@@ -0,0 +1,26 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// A `size` x `size` garden; most plots continue the region of their left or upper neighbor
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let size = size.max(1);
+    let mut rows: Vec<Vec<char>> = Vec::with_capacity(size);
+    for y in 0..size {
+        let mut row = Vec::with_capacity(size);
+        for x in 0..size {
+            let plant = match rng.gen_range(0..10) {
+                0..=3 if x > 0 => row[x - 1],
+                4..=7 if y > 0 => rows[y - 1][x],
+                _ => char::from(b'A' + rng.gen_range(0..26)),
+            };
+            row.push(plant);
+        }
+        rows.push(row);
+    }
+    GeneratedInput::new(
+        rows.iter()
+            .map(|row| row.iter().collect::<String>())
+            .join("\n"),
+    )
+}
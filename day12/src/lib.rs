@@ -15,5 +15,41 @@
 )]
 
 
+pub mod custom_error;
+pub mod day12_common;
+pub mod day12_generate;
 pub mod day12_part1;
 pub mod day12_part2;
+
+use grid::grid_array::GridArray;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 12: both parts price the fences of the regions of the same garden
+pub struct Day12;
+
+impl Day for Day12 {
+    const DAY: u8 = 12;
+    type Input = GridArray<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day12_common::parse(input))
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day12_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day12_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day12_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day12>::PART1, &DayPart::<Day12>::PART2];
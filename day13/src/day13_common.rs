@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use utils::parse_error::{ParseContext, ParseError};
+
+use crate::custom_error::AocError;
+
+/// Movement of button A, movement of button B and the prize location, each as (x, y)
+pub type Machine = ((u32, u32), (u32, u32), (u32, u32));
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, AocError> {
+    let context = ParseContext::new(input);
+    input
+        .split("\n\n")
+        .map(|block| -> Result<Machine, AocError> {
+            /* block is eg
+            Button A: X+94, Y+34
+            Button B: X+22, Y+67
+            Prize: X=8400, Y=5400
+            */
+            let mut lines = block.lines();
+            let mut next_line = || {
+                lines
+                    .next()
+                    .ok_or_else(|| context.error_after(block, "another line"))
+            };
+            let a = parse_xy(&context, next_line()?, "Button A: ", '+')?;
+            let b = parse_xy(&context, next_line()?, "Button B: ", '+')?;
+            let prize = parse_xy(&context, next_line()?, "Prize: ", '=')?;
+            Ok((a, b, prize))
+        })
+        .collect()
+}
+
+/// Parses e.g. `Button A: X+94, Y+34` (`label` is `Button A: `, `sign` is `+`)
+fn parse_xy<'a, T: FromStr>(
+    context: &ParseContext<'a>,
+    line: &'a str,
+    label: &str,
+    sign: char,
+) -> Result<(T, T), ParseError> {
+    let coordinates = context.strip_prefix(line, label)?;
+    let (x, y) = context.split_once(coordinates, ", ")?;
+    let x = context.strip_prefix(x, &format!("X{sign}"))?;
+    let y = context.strip_prefix(y, &format!("Y{sign}"))?;
+    Ok((context.parse(x, "a number")?, context.parse(y, "a number")?))
+}
@@ -0,0 +1,69 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Offset of the prize positions in part 2
+const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+/// `size` claw machines; about a third of the prizes can be reached (part 1), another third
+/// can be reached after moving the prize by the offset of part 2
+///
+/// The movements of the buttons are never (nearly) parallel, as in the real puzzle input.
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let machines = (0..size.max(1))
+        .map(|_| {
+            let (a, b) = loop {
+                let a: (u32, u32) = (rng.gen_range(10..100), rng.gen_range(10..100));
+                let b: (u32, u32) = (rng.gen_range(10..100), rng.gen_range(10..100));
+                let determinant = i64::from(a.0 * b.1) - i64::from(a.1 * b.0);
+                if determinant.abs() >= 100 {
+                    break (a, b);
+                }
+            };
+            let prize = match rng.gen_range(0..3) {
+                0 => {
+                    let (presses_a, presses_b) = (rng.gen_range(0..100), rng.gen_range(0..100));
+                    (
+                        presses_a * a.0 + presses_b * b.0,
+                        presses_a * a.1 + presses_b * b.1,
+                    )
+                }
+                1 => reachable_with_offset(a, b, rng),
+                _ => (rng.gen_range(1000..20_000), rng.gen_range(1000..20_000)),
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect_vec();
+    GeneratedInput::new(machines.join("\n\n"))
+}
+
+/// A prize which the buttons can reach after moving it by [`PRIZE_OFFSET`]
+/// (or a random prize if the buttons can't move diagonally together)
+fn reachable_with_offset(a: (u32, u32), b: (u32, u32), rng: &mut StdRng) -> (u32, u32) {
+    let (a_x, a_y, b_x, b_y) = (
+        i64::from(a.0),
+        i64::from(a.1),
+        i64::from(b.0),
+        i64::from(b.1),
+    );
+    let target_x = PRIZE_OFFSET + rng.gen_range(1000..20_000);
+    let target_y = PRIZE_OFFSET + rng.gen_range(1000..20_000);
+    // the (rounded) button presses which reach the target, the prize is where they end
+    let determinant = a_x * b_y - a_y * b_x;
+    let presses_a = (target_x * b_y - target_y * b_x) / determinant;
+    let presses_b = (target_y * a_x - target_x * a_y) / determinant;
+    let prize = |a_move: i64, b_move: i64| {
+        u32::try_from(presses_a * a_move + presses_b * b_move - PRIZE_OFFSET).ok()
+    };
+    match (
+        presses_a > 0 && presses_b > 0,
+        prize(a_x, b_x),
+        prize(a_y, b_y),
+    ) {
+        (true, Some(prize_x), Some(prize_y)) => (prize_x, prize_y),
+        _ => (rng.gen_range(1000..20_000), rng.gen_range(1000..20_000)),
+    }
+}
@@ -1,9 +1,7 @@
-use itertools::Itertools;
-
-
-
 use nalgebra::{Matrix2, Vector2};
 
+use crate::day13_common::{parse, Machine};
+
 
 #[allow(clippy::cast_sign_loss)]
 fn solve_system(
@@ -39,43 +37,12 @@ fn solve_system(
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let input = input.replace('\r', "");
-    let input = input.split("\n\n").map(|block| {
-        /* block is eg
-        Button A: X+94, Y+34
-        Button B: X+22, Y+67
-        Prize: X=8400, Y=5400
-        */
-        let mut lines = block.lines();
-        let a = lines.next().unwrap();
-        let b = lines.next().unwrap();
-        let prize = lines.next().unwrap();
-
-        let a = a.split(": ").nth(1).unwrap();
-        let b = b.split(": ").nth(1).unwrap();
-        let prize = prize.split(": ").nth(1).unwrap();
-
-        let (a_x, a_y) = a
-            .split(", ")
-            .map(|x| x.split('+').nth(1).unwrap().parse::<u32>().unwrap())
-            .collect_tuple()
-            .unwrap();
-        let (b_x, b_y) = b
-            .split(", ")
-            .map(|x| x.split('+').nth(1).unwrap().parse::<u32>().unwrap())
-            .collect_tuple()
-            .unwrap();
-        let (prize_x, prize_y) = prize
-            .split(", ")
-            .map(|x| x.split('=').nth(1).unwrap().parse::<u32>().unwrap())
-            .collect_tuple()
-            .unwrap();
-
-        ((a_x, a_y), (b_x, b_y), (prize_x, prize_y))
-    });
+    solve(&parse(input)?)
+}
 
+pub fn solve(machines: &[Machine]) -> miette::Result<String> {
     let mut result = 0;
-    for ((a_x, a_y), (b_x, b_y), (prize_x, prize_y)) in input {
+    for &((a_x, a_y), (b_x, b_y), (prize_x, prize_y)) in machines {
         if let Some((button_a, button_b)) =
             solve_system(prize_x, prize_y, a_x, a_y, b_x, b_y)
         {
@@ -125,4 +92,19 @@ Prize: X=18641, Y=10279";
         assert_eq!("29187", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22 Y+67\nPrize: X=8400, Y=5400";
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 20: expected ', '",
+            err.to_string()
+        );
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 21: expected another line",
+            err.to_string()
+        );
+    }
 }
@@ -1,6 +1,7 @@
-use itertools::Itertools;
 use nalgebra::{Matrix2, Vector2};
 
+use crate::day13_common::{parse, Machine};
+
 #[allow(clippy::cast_sign_loss)]
 #[allow(clippy::cast_precision_loss)]
 fn solve_system(prize_vector: Vector2<u64>, coeff_matrix: Matrix2<u64>) -> Option<Vector2<u64>> {
@@ -32,16 +33,24 @@ fn solve_system(prize_vector: Vector2<u64>, coeff_matrix: Matrix2<u64>) -> Optio
         })
 }
 
+//#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
+}
+
 #[allow(
     clippy::cast_sign_loss,
     clippy::cast_precision_loss,
     clippy::let_and_return
 )]
-//#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
+pub fn solve(machines: &[Machine]) -> miette::Result<String> {
     let additional_price_vec = Vector2::new(10_000_000_000_000, 10_000_000_000_000);
-    let result = parse_input(input)
-        .map(|(prize_vec, coeff_matrix)| {
+    let result = machines
+        .iter()
+        .map(|&((a_x, a_y), (b_x, b_y), (prize_x, prize_y))| {
+            // the button movements as columns
+            let coeff_matrix = Matrix2::new(a_x, b_x, a_y, b_y).map(u64::from);
+            let prize_vec = Vector2::new(prize_x, prize_y).map(u64::from);
             if let Some(button_vec) = solve_system(prize_vec + additional_price_vec, coeff_matrix) {
                 let costs = button_vec[0] * 3 + button_vec[1];
                 //println!("A/B: {button_vec} => {costs}");
@@ -56,48 +65,6 @@ pub fn process(input: &str) -> miette::Result<String> {
     Ok(result.to_string())
 }
 
-#[allow(clippy::cast_sign_loss)]
-#[allow(clippy::cast_precision_loss)]
-fn parse_input(input: &str) -> impl Iterator<Item = (Vector2<u64>, Matrix2<u64>)> + '_ {
-    let input = input.split("\n\n").map(|block| {
-        /* block is for example
-        Button A: X+94, Y+34
-        Button B: X+22, Y+67
-        Prize: X=8400, Y=5400
-        */
-        let mut lines = block.lines();
-        let a = lines.next().unwrap();
-        let b = lines.next().unwrap();
-        let prize = lines.next().unwrap();
-
-        let a = a.split(": ").nth(1).unwrap();
-        let b = b.split(": ").nth(1).unwrap();
-        let prize = prize.split(": ").nth(1).unwrap();
-
-        let (a_x, a_y) = a
-            .split(", ")
-            .map(|x| x.split('+').nth(1).unwrap().parse::<u64>().unwrap())
-            .collect_tuple()
-            .unwrap();
-        let (b_x, b_y) = b
-            .split(", ")
-            .map(|x| x.split('+').nth(1).unwrap().parse::<u64>().unwrap())
-            .collect_tuple()
-            .unwrap();
-        let (prize_x, prize_y) = prize
-            .split(", ")
-            .map(|x| x.split('=').nth(1).unwrap().parse::<u64>().unwrap())
-            .collect_tuple()
-            .unwrap();
-
-        let coeff_matrix = Matrix2::new(a_x, b_x, a_y, b_y);
-        let prize_vec = Vector2::new(prize_x, prize_y);
-
-        (prize_vec, coeff_matrix)
-    });
-    input
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
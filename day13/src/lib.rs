@@ -15,5 +15,40 @@
 )]
 
 
+pub mod custom_error;
+pub mod day13_common;
+pub mod day13_generate;
 pub mod day13_part1;
 pub mod day13_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 13: both parts solve the same claw machines (part 2 moves the prizes)
+pub struct Day13;
+
+impl Day for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<day13_common::Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day13_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day13_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day13_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day13_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day13>::PART1, &DayPart::<Day13>::PART2];
@@ -1,4 +1,5 @@
 use day14::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,17 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day14_part1() {
-    day14_part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn day14_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day14/input1.txt").unwrap();
+    bencher.bench(|| Day14::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day14_part2() {
-    day14_part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn day14_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day14/input1.txt").unwrap();
+    let input = Day14::parse(&input).unwrap();
+    let params = Day14::default_params(1);
+    bencher.bench(|| Day14::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day14_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day14/input2.txt").unwrap();
+    let input = Day14::parse(&input).unwrap();
+    let params = Day14::default_params(2);
+    bencher.bench(|| Day14::part2(divan::black_box(&input), &params).unwrap());
+}
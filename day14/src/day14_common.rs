@@ -0,0 +1,29 @@
+use grid::grid_types::{ICoor2D, UCoor2D};
+use utils::parse::{key_pair, lines_of, parse_all, separated, PResult};
+
+use crate::custom_error::AocError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Robot {
+    pub pos: UCoor2D,
+    pub vel: ICoor2D,
+}
+
+impl Robot {
+    /// Parses a line like `p=0,4 v=3,-3`
+    fn parse(line: &str) -> PResult<'_, Self> {
+        let (remaining, ((pos_x, pos_y), (vel_x, vel_y))) =
+            separated(key_pair("p"), " ", key_pair("v"))(line)?;
+        Ok((
+            remaining,
+            Robot {
+                pos: UCoor2D { x: pos_x, y: pos_y },
+                vel: ICoor2D { x: vel_x, y: vel_y },
+            },
+        ))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, AocError> {
+    Ok(parse_all(input, lines_of(Robot::parse))?)
+}
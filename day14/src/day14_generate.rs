@@ -0,0 +1,39 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Number of robots which line up to the christmas tree
+const TREE_WIDTH: usize = 10;
+
+/// `size` robots (at least 10) in an area of `size` x `size + 2` tiles (at least 11 wide)
+///
+/// At a random second 10 of the robots line up horizontally, which part 2 recognizes as
+/// the christmas tree.
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let width = size.max(TREE_WIDTH + 1);
+    let height = width + 2;
+    let tree_second = rng.gen_range(1..width * height);
+    let tree_x = rng.gen_range(1..=width - TREE_WIDTH);
+    let tree_y = rng.gen_range(0..height);
+
+    let robots = (0..size.max(TREE_WIDTH))
+        .map(|robot| {
+            let (x, y) = if robot < TREE_WIDTH {
+                (tree_x + robot, tree_y)
+            } else {
+                (rng.gen_range(0..width), rng.gen_range(0..height))
+            };
+            let (vel_x, vel_y) = (
+                rng.gen_range(-(width as isize)..width as isize),
+                rng.gen_range(-(height as isize)..height as isize),
+            );
+            // move back from the position at the second of the christmas tree
+            let start_x = (x as isize - vel_x * tree_second as isize).rem_euclid(width as isize);
+            let start_y = (y as isize - vel_y * tree_second as isize).rem_euclid(height as isize);
+            format!("p={start_x},{start_y} v={vel_x},{vel_y}")
+        })
+        .collect_vec();
+    GeneratedInput::new(robots.join("\n"))
+        .with_param("width", &width)
+        .with_param("height", &height)
+}
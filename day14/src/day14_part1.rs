@@ -8,39 +8,24 @@ use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
+use crate::day14_common::{parse, Robot};
+
+/// Size of the area of the real puzzle (the example uses 11x7)
+pub const WIDTH: usize = 101;
+pub const HEIGHT: usize = 103;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    process2(input, 101, 103)
-    //process2(input, 11, 7)
+    solve(&parse(input)?, WIDTH, HEIGHT)
 }
 
-struct Robot {
-    pos: UCoor2D,
-    vel: ICoor2D,
-}
+pub fn solve(robots: &[Robot], width: usize, height: usize) -> miette::Result<String> {
+    let mut robots = robots.to_vec();
 
-pub fn process2(input: &str, width: usize, height: usize) -> miette::Result<String> {
-    let mut robots = input
-        .lines()
-        .map(|line| {
-            let (position_str, velocity_str) = line.split_once(' ').unwrap();
-            let (pos_x_str, pos_y_str) = position_str["p=".len()..].split_once(',').unwrap();
-            let (pos_x, pos_y) = (pos_x_str.parse().unwrap(), pos_y_str.parse().unwrap());
-            let (vel_x_str, vel_y_str) = velocity_str["v=".len()..].split_once(',').unwrap();
-            let (vel_x, vel_y) = (vel_x_str.parse().unwrap(), vel_y_str.parse().unwrap());
-
-            Robot {
-                pos: UCoor2D { x: pos_x, y: pos_y },
-                vel: ICoor2D { x: vel_x, y: vel_y },
-            }
-        })
-        .collect_vec();
-
-    println!("robots: {}", robots.len());
     //print_robots(&robots, width, height);
     for _iteration in 0..100 {
         for robot in &mut robots {
-            let mut new_pos = &robot.pos.to_icoor2d().unwrap() + &robot.vel;
+            let mut new_pos = robot.pos.to_icoor2d().unwrap() + robot.vel;
             while new_pos.x >= width as isize {
                 new_pos.x -= width as isize;
             }
@@ -120,7 +105,7 @@ p=9,3 v=2,3
 p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3";
-        assert_eq!("12", process2(&input.replace('\r', ""), 11, 7)?);
+        assert_eq!("12", solve(&parse(&input.replace('\r', ""))?, 11, 7)?);
         Ok(())
     }
 
@@ -130,4 +115,18 @@ p=9,5 v=-3,-3";
         assert_eq!("219150360", process(&input.replace('\r', ""))?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("p=0,4 v=3,-3\np=6,3 v=-1;-3").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 11: expected ','",
+            err.to_string()
+        );
+        let err = parse("p=0,4 v=3,-3\np=6,-3 v=-1,-3").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 5: expected a number",
+            err.to_string()
+        );
+    }
 }
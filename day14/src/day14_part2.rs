@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use fxhash::FxHashMap;
 use grid::{
     grid_array::{GridArray, GridArrayBuilder},
@@ -7,22 +5,16 @@ use grid::{
     grid_types::{ICoor2D, Neighborhood, Topology, UCoor2D},
 };
 use itertools::Itertools;
-use miette::Error;
+use miette::miette;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
-
+use crate::day14_common::{parse, Robot};
 
 //#[tracing::instrument]
-#[derive(Clone, Debug, PartialEq)]
-struct Robot {
-    pos: UCoor2D,
-    vel: ICoor2D,
-}
-
 impl Robot {
     fn move_robot(&mut self, width: usize, height: usize) {
-        let mut new_pos = &self.pos.to_icoor2d().unwrap() + &self.vel;
+        let mut new_pos = self.pos.to_icoor2d().unwrap() + self.vel;
         while new_pos.x >= width as isize {
             new_pos.x -= width as isize;
         }
@@ -42,23 +34,6 @@ impl Robot {
     }
 }
 
-impl FromStr for Robot {
-    type Err = Error;
-
-    fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let (position_str, velocity_str) = line.split_once(' ').unwrap();
-        let (pos_x_str, pos_y_str) = position_str["p=".len()..].split_once(',').unwrap();
-        let (pos_x, pos_y) = (pos_x_str.parse().unwrap(), pos_y_str.parse().unwrap());
-        let (vel_x_str, vel_y_str) = velocity_str["v=".len()..].split_once(',').unwrap();
-        let (vel_x, vel_y) = (vel_x_str.parse().unwrap(), vel_y_str.parse().unwrap());
-
-        Ok(Robot {
-            pos: UCoor2D { x: pos_x, y: pos_y },
-            vel: ICoor2D { x: vel_x, y: vel_y },
-        })
-    }
-}
-
 fn get_index_pos(pos: &UCoor2D, height: usize) -> usize {
     get_index(pos.x, pos.y, height)
 }
@@ -67,20 +42,30 @@ fn get_index(x: usize, y: usize, height: usize) -> usize {
     x * height + y
 }
 
+/// Size of the area of the real puzzle
+pub const WIDTH: usize = 101;
+pub const HEIGHT: usize = 103;
+
 pub fn process(input: &str) -> miette::Result<String> {
-    let (width, height) = (101, 103);
+    solve(&parse(input)?, WIDTH, HEIGHT)
+}
+
+pub fn solve(robots: &[Robot], width: usize, height: usize) -> miette::Result<String> {
     let mut position_count_vec = vec![0; width * height];
-    let mut robots = input
-        .lines()
-        .map(|line| {
-            let robot = Robot::from_str(line).unwrap();
-            position_count_vec[get_index_pos(&robot.pos, height)] += 1;
-            robot
-        })
-        .collect_vec();
+    let mut robots = robots.to_vec();
+    for robot in &robots {
+        position_count_vec[get_index_pos(&robot.pos, height)] += 1;
+    }
 
+    // the robots return to their start positions after width * height seconds
     let mut iteration = 0;
     while !has_christmas_tree(&position_count_vec, width, height) {
+        if iteration + 1 >= width * height {
+            return Err(miette!(
+                "No christmas tree within {} seconds",
+                width * height
+            ));
+        }
         for robot in &mut robots {
             position_count_vec[get_index_pos(&robot.pos, height)] -= 1;
             robot.move_robot(width, height);
@@ -131,6 +116,8 @@ fn print_robots(position_count_vec: &[usize], width: usize, height: usize) {
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -139,4 +126,57 @@ mod tests {
         assert_eq!("8053", process(&input.replace('\r', ""))?);
         Ok(())
     }
+
+    #[test]
+    fn test_no_christmas_tree() -> miette::Result<()> {
+        let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+        let err = solve(&parse(input)?, 11, 7).unwrap_err();
+        assert_eq!("No christmas tree within 77 seconds", err.to_string());
+        Ok(())
+    }
+
+    /// Random robots inside a random area
+    fn robot_in_area() -> impl Strategy<Value = (Robot, usize, usize)> {
+        (1..120_usize, 1..120_usize).prop_flat_map(|(width, height)| {
+            (0..width, 0..height, -200..200_isize, -200..200_isize).prop_map(
+                move |(x, y, vel_x, vel_y)| {
+                    let robot = Robot {
+                        pos: UCoor2D::new(x, y),
+                        vel: ICoor2D::new(vel_x, vel_y),
+                    };
+                    (robot, width, height)
+                },
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_move_robot_agrees_with_closed_form(
+            (robot, width, height) in robot_in_area(),
+            steps in 0..200_isize,
+        ) {
+            let mut moved_robot = robot.clone();
+            for _step in 0..steps {
+                moved_robot.move_robot(width, height);
+            }
+            let expected_x = (robot.pos.x as isize + steps * robot.vel.x).rem_euclid(width as isize);
+            let expected_y = (robot.pos.y as isize + steps * robot.vel.y).rem_euclid(height as isize);
+            prop_assert_eq!(
+                UCoor2D::new(expected_x as usize, expected_y as usize),
+                moved_robot.pos
+            );
+        }
+    }
 }
@@ -15,5 +15,53 @@
 )]
 
 
+pub mod custom_error;
+pub mod day14_common;
+pub mod day14_generate;
 pub mod day14_part1;
 pub mod day14_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 14: both parts move the same robots around the area
+pub struct Day14;
+
+impl Day for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<day14_common::Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day14_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<String> {
+        day14_part1::solve(input, params.get("width")?, params.get("height")?)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<String> {
+        day14_part2::solve(input, params.get("width")?, params.get("height")?)
+    }
+
+    fn default_params(part: u8) -> Params {
+        match part {
+            1 => Params::from([
+                ("width", day14_part1::WIDTH),
+                ("height", day14_part1::HEIGHT),
+            ]),
+            _ => Params::from([
+                ("width", day14_part2::WIDTH),
+                ("height", day14_part2::HEIGHT),
+            ]),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day14_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day14>::PART1, &DayPart::<Day14>::PART2];
@@ -1,4 +1,5 @@
 use day15::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,17 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day15_part1() {
-    day15_part1::process(divan::black_box(
-        &include_str!("../input1.txt",).replace('\r', ""),
-    ))
-    .unwrap();
+fn day15_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day15/input1.txt").unwrap();
+    bencher.bench(|| Day15::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day15_part2() {
-    day15_part2::process(divan::black_box(
-        &include_str!("../input2.txt").replace('\r', ""),
-    ))
-    .unwrap();
+fn day15_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day15/input1.txt").unwrap();
+    let input = Day15::parse(&input).unwrap();
+    let params = Day15::default_params(1);
+    bencher.bench(|| Day15::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day15_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day15/input2.txt").unwrap();
+    let input = Day15::parse(&input).unwrap();
+    let params = Day15::default_params(2);
+    bencher.bench(|| Day15::part2(divan::black_box(&input), &params).unwrap());
 }
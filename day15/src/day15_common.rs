@@ -0,0 +1,36 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Direction, Neighborhood, Topology},
+};
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// The map of the warehouse and the movements of the robot
+#[derive(Clone, Debug)]
+pub struct Warehouse {
+    pub grid: GridArray<char>,
+    pub movements: Vec<Direction>,
+}
+
+pub fn parse(input: &str) -> Result<Warehouse, AocError> {
+    let context = ParseContext::new(input);
+    let (map_str, movements_str) = context.split_once(input, "\n\n")?;
+    context.check_chars(map_str, |ch| "#.O@".contains(ch), "'#', '.', 'O' or '@'")?;
+    context.find_unique_char(map_str, '@')?;
+    let grid = GridArray::from_newline_separated_string(
+        Topology::Bounded,
+        Neighborhood::Orthogonal,
+        map_str,
+    );
+
+    let movements = movements_str
+        .char_indices()
+        .filter(|(_index, movement)| *movement != '\n')
+        .map(|(index, movement)| {
+            Direction::from_arrow(movement)
+                .ok_or_else(|| context.char_error(movements_str, index, "'>', '<', '^' or 'v'"))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Warehouse { grid, movements })
+}
@@ -0,0 +1,33 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use utils::generate::{grid_to_string, GeneratedInput, StdRng};
+
+/// A `size` x `size` warehouse (surrounded by walls) with boxes and walls and `10 * size`
+/// movements of the robot in lines of 70
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let size = size.max(3);
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+                        '#'
+                    } else {
+                        *['.', '.', '.', 'O', 'O', '#'].choose(rng).unwrap()
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows[rng.gen_range(1..size - 1)][rng.gen_range(1..size - 1)] = '@';
+
+    let movements = (0..10 * size)
+        .map(|_| *['^', 'v', '<', '>'].choose(rng).unwrap())
+        .collect_vec();
+    let movements = movements
+        .chunks(70)
+        .map(|line| line.iter().collect::<String>())
+        .join("\n");
+    GeneratedInput::new(format!("{}\n\n{movements}", grid_to_string(&rows)))
+}
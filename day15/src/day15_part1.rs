@@ -7,36 +7,25 @@ use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
+use crate::day15_common::{parse, Warehouse};
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (map_str, movements_str) = input.split_once("\n\n").unwrap();
-    let mut grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        map_str,
-    );
+    solve(&parse(input)?)
+}
+
+pub fn solve(warehouse: &Warehouse) -> miette::Result<String> {
+    let mut grid = warehouse.grid.clone();
     let mut robot_coor = grid
         .all_cells()
         .filter(|(_coor, ch)| **ch == '@')
         .map(|(coor, _h)| coor)
         .next()
         .unwrap();
-    let movements_str = movements_str.replace('\n', "");
-    let movements = movements_str.chars();
 
-    for movement in movements {
-        let movement_dir = match movement {
-            '>' => Direction::East,
-            '<' => Direction::West,
-            '^' => Direction::North,
-            'v' => Direction::South,
-            _ => panic!(),
-        };
+    for &movement_dir in &warehouse.movements {
         if check_if_movement_works(&mut grid, &robot_coor, movement_dir) {
-            let new_coor = (robot_coor.to_icoor2d().unwrap() + movement_dir.diff_coor())
-                .to_ucoor2d()
-                .unwrap();
-            robot_coor = new_coor;
+            robot_coor = robot_coor.step(movement_dir, 1).unwrap();
         }
         //grid.println(false);
     }
@@ -55,9 +44,7 @@ fn check_if_movement_works(
     coor: &UCoor2D,
     movement_dir: Direction,
 ) -> bool {
-    let neighbor_coor = (coor.to_icoor2d().unwrap() + movement_dir.diff_coor())
-        .to_ucoor2d()
-        .unwrap();
+    let neighbor_coor = coor.step(movement_dir, 1).unwrap();
     let neighbor = grid.get(neighbor_coor.x, neighbor_coor.y).unwrap_or(&'#');
     let is_possible = match neighbor {
         '.' => true,
@@ -127,4 +114,18 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
         assert_eq!("1526673", process(&input.replace('\r', ""))?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let err = process("#####\n#.@O#\n#####\n\n<<x>").unwrap_err();
+        assert_eq!(
+            "Parse error at line 5, column 3: expected '>', '<', '^' or 'v'",
+            err.to_string()
+        );
+        let err = process("#####\n#..O#\n#####\n\n<<>").unwrap_err();
+        assert_eq!(
+            "Parse error at line 3, column 6: expected '@'",
+            err.to_string()
+        );
+    }
 }
@@ -11,13 +11,19 @@ use rayon::prelude::*;
 
 use gif::{Encoder, Frame, Repeat};
 
+use crate::day15_common::{parse, Warehouse};
+
 fn count_chars(grid: &GridArray<char>, ch: char) -> usize {
     grid.all_cells().filter(|(_coor, &cell)| cell == ch).count()
 }
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (mut grid, movements) = parse(input);
+    solve(&parse(input)?)
+}
+
+pub fn solve(warehouse: &Warehouse) -> miette::Result<String> {
+    let mut grid = double_grid(&warehouse.grid);
     let mut robot_coor = get_robot_pos(&grid);
     let mut moves = FxHashMap::default();
 
@@ -35,7 +41,7 @@ pub fn process(input: &str) -> miette::Result<String> {
     if cfg!(debug_assertions) {
         grid.println(false);
     }
-    for movement in movements {
+    for &movement in &warehouse.movements {
         if cfg!(debug_assertions) {
             println!("{movement:?} {}", robot_coor);
             grid.println(false);
@@ -90,38 +96,24 @@ fn get_robot_pos(grid: &GridArray<char>) -> UCoor2D {
         .unwrap()
 }
 
-fn parse(input: &str) -> (GridArray<char>, Vec<Direction>) {
-    fn double_grid(map_str: &str) -> String {
-        let map_str = map_str
-            .chars()
-            .map(|ch| match ch {
-                '#' => "##",
-                'O' => "[]",
-                '.' => "..",
-                '@' => "@.",
-                '\n' => "\n",
-                _ => panic!("Unsupported char '{ch}'"),
-            })
-            .collect::<String>();
-        map_str
-    }
-
-    let (map_str, movements_str) = input.split_once("\n\n").unwrap();
-    let map_str = double_grid(map_str);
-    let grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        &map_str,
-    );
-    let movements_str = movements_str.replace('\n', "");
-    let movements = movements_str.chars().map(|movement| match movement {
-        '>' => Direction::East,
-        '<' => Direction::West,
-        '^' => Direction::North,
-        'v' => Direction::South,
-        _ => panic!("Unsupported movement char '{movement}'"),
-    });
-    (grid, movements.collect_vec())
+/// Makes everything except the robot twice as wide
+fn double_grid(grid: &GridArray<char>) -> GridArray<char> {
+    let data = grid
+        .iter()
+        .flat_map(|ch| match ch {
+            '#' => ['#', '#'],
+            'O' => ['[', ']'],
+            '.' => ['.', '.'],
+            '@' => ['@', '.'],
+            _ => unreachable!("checked by parse"),
+        })
+        .collect();
+    GridArray::from_1d_vec(
+        grid.get_topology(),
+        grid.get_neighborhood(),
+        grid.width() * 2,
+        data,
+    )
 }
 
 fn check_if_robot_movement_works(
@@ -297,7 +289,7 @@ fn check_if_box_movement_works_horizontal(
 #[inline]
 fn add_move(moves: &mut FxHashMap<UCoor2D, char>, coor: &UCoor2D, cell: char) {
     if cell != '.' || !moves.contains_key(coor) {
-        moves.insert(coor.clone(), cell);
+        moves.insert(*coor, cell);
     }
 }
 
@@ -319,9 +311,7 @@ fn look_in_dir(grid: &GridArray<char>, coor: &UCoor2D, dir: Direction) -> (UCoor
 }
 
 fn add_direction(coor: &UCoor2D, dir: Direction) -> UCoor2D {
-    (coor.to_icoor2d().unwrap() + dir.diff_coor())
-        .to_ucoor2d()
-        .unwrap()
+    coor.step(dir, 1).unwrap()
 }
 
 #[cfg(test)]
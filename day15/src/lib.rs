@@ -15,5 +15,40 @@
 )]
 
 
+pub mod custom_error;
+pub mod day15_common;
+pub mod day15_generate;
 pub mod day15_part1;
 pub mod day15_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 15: both parts push boxes around the same warehouse (part 2 makes it twice as wide)
+pub struct Day15;
+
+impl Day for Day15 {
+    const DAY: u8 = 15;
+    type Input = day15_common::Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day15_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day15_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day15_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day15_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day15>::PART1, &DayPart::<Day15>::PART2];
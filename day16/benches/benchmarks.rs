@@ -1,4 +1,5 @@
 use day16::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,13 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day16_part1() {
-    day16_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")))
-    .unwrap();
+fn day16_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day16/input1.txt").unwrap();
+    bencher.bench(|| Day16::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day16_part2() {
-    day16_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")))
-    .unwrap();
-}
\ No newline at end of file
+fn day16_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day16/input1.txt").unwrap();
+    let input = Day16::parse(&input).unwrap();
+    let params = Day16::default_params(1);
+    bencher.bench(|| Day16::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day16_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day16/input2.txt").unwrap();
+    let input = Day16::parse(&input).unwrap();
+    let params = Day16::default_params(2);
+    bencher.bench(|| Day16::part2(divan::black_box(&input), &params).unwrap());
+}
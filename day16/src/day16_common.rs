@@ -0,0 +1,20 @@
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Neighborhood, Topology},
+};
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// Parses the maze, which must contain exactly one start `S` and one end `E`
+pub fn parse(input: &str) -> Result<GridArray<char>, AocError> {
+    let context = ParseContext::new(input);
+    context.check_chars(input, |ch| "#.SE".contains(ch), "'#', '.', 'S' or 'E'")?;
+    context.find_unique_char(input, 'S')?;
+    context.find_unique_char(input, 'E')?;
+    Ok(GridArray::from_newline_separated_string(
+        Topology::Bounded,
+        Neighborhood::Orthogonal,
+        input,
+    ))
+}
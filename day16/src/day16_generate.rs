@@ -0,0 +1,21 @@
+use rand::Rng;
+use utils::generate::{grid_to_string, maze, GeneratedInput, StdRng};
+
+/// A `size` x `size` maze (`size` rounded up to an odd number) with the start in the lower
+/// left and the end in the upper right corner; some walls are removed, so that there are
+/// several paths
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let mut rows = maze(rng, size, size);
+    let size = rows.len();
+    for (y, row) in rows.iter_mut().enumerate().take(size - 1).skip(1) {
+        for (x, cell) in row.iter_mut().enumerate().take(size - 1).skip(1) {
+            // walls between two open cells of the maze
+            if (x + y) % 2 == 1 && rng.gen_bool(0.1) {
+                *cell = '.';
+            }
+        }
+    }
+    rows[size - 2][1] = 'S';
+    rows[1][size - 2] = 'E';
+    GeneratedInput::new(grid_to_string(&rows))
+}
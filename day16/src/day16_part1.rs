@@ -1,9 +1,12 @@
 use grid::{
     grid_array::GridArray,
-    grid_types::{Direction, Neighborhood, Topology, UCoor2D},
+    grid_types::{Direction, UCoor2D},
+    search::cheapest_paths,
 };
 use itertools::Itertools;
-use pathfinding::prelude::dijkstra;
+use miette::miette;
+
+use crate::day16_common::parse;
 
 //#[tracing::instrument]
 
@@ -18,10 +21,10 @@ impl Eq for Node {}
 fn costs(direction1: Direction, direction2: Direction) -> usize {
     if direction1 == direction2 {
         1
-    } else if (direction1 == direction2.rotate(90)) || (direction1 == direction2.rotate(-90)) {
+    } else if (direction1 == direction2.turn_right()) || (direction1 == direction2.turn_left()) {
         1000 + 1
     } else {
-        debug_assert_eq!(direction1, direction2.rotate(180));
+        debug_assert_eq!(direction1, direction2.opposite());
         2 * 1000 + 1
     }
 }
@@ -42,12 +45,10 @@ impl Node {
 }
 
 pub fn process(input: &str) -> miette::Result<String> {
-    let grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-    );
+    solve(&parse(input)?)
+}
 
+pub fn solve(grid: &GridArray<char>) -> miette::Result<String> {
     let start_coor = grid
         .all_cells()
         .filter(|(_coor, cell)| cell == &&'S')
@@ -58,12 +59,14 @@ pub fn process(input: &str) -> miette::Result<String> {
         coor: start_coor,
         direction: Direction::East,
     };
-    let result = dijkstra(
-        &start,
-        |node| node.successors(&grid),
-        |node| node.success(&grid),
-    );
-    let min_costs = &result.unwrap().1;
+    let min_costs = cheapest_paths(
+        start,
+        |node| node.successors(grid),
+        |_node| 0,
+        |node| node.success(grid),
+    )
+    .goal_distance()
+    .ok_or_else(|| miette!("No path from the start to the end"))?;
     Ok(min_costs.to_string())
 }
 
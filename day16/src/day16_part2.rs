@@ -1,95 +1,53 @@
 use grid::{
+    distance_field::{directional_distance_field, on_optimal_paths, FieldDirection},
     grid_array::GridArray,
-    grid_types::{Direction, Neighborhood, Topology, UCoor2D},
+    grid_types::Direction,
 };
-use itertools::Itertools;
-use pathfinding::prelude::astar_bag;
+use miette::miette;
 
-//#[tracing::instrument]
-
-#[derive(Debug, Clone, PartialEq, Hash)]
-struct Node {
-    coor: UCoor2D,
-    direction: Direction,
-}
-
-impl Eq for Node {}
-
-impl Node {
-    fn get_start_node(grid: &GridArray<char>) -> Node {
-        Node {
-            coor: find_cells_coor(grid, 'S'),
-            direction: Direction::East,
-        }
-    }
-
-    fn successors(&self, grid: &GridArray<char>) -> Vec<(Node, usize)> {
-        let mut result = Vec::with_capacity(3);
-        result.push((
-            Node {
-                coor: self.coor.clone(),
-                direction: self.direction.rotate(90),
-            },
-            1000,
-        ));
-        result.push((
-            Node {
-                coor: self.coor.clone(),
-                direction: self.direction.rotate(-90),
-            },
-            1000,
-        ));
-        if let Some(new_coor) =
-            (self.coor.to_icoor2d().unwrap() + self.direction.diff_coor()).to_ucoor2d()
-        {
-            if grid.get_unchecked(new_coor.x, new_coor.y) != &'#' {
-                // we can move in current direction
-                result.push((
-                    Node {
-                        coor: new_coor,
-                        direction: self.direction,
-                    },
-                    1,
-                ));
-            }
-        }
-        result
-    }
+use crate::day16_common::parse;
 
-    fn success(&self, grid: &GridArray<char>) -> bool {
-        grid.get_unchecked(self.coor.x, self.coor.y) == &'E'
-    }
+//#[tracing::instrument]
 
-    fn heuristic(&self, end_coor: &UCoor2D) -> usize {
-        self.coor.manhattan_distance(end_coor)
-    }
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
 }
 
-pub fn process(input: &str) -> miette::Result<String> {
-    let grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
+pub fn solve(grid: &GridArray<char>) -> miette::Result<String> {
+    let step_costs = |_from: &char, &to: &char| (to != '#').then_some(1);
+    // turning around takes two turns
+    let turn_costs = |from: Direction, to: Direction| {
+        (to == from.turn_right() || to == from.turn_left()).then_some(1000)
+    };
+    let from_start = directional_distance_field(
+        grid,
+        [(find_cells_coor(grid, 'S'), Direction::East)],
+        FieldDirection::Forward,
+        step_costs,
+        turn_costs,
     );
-
-    let end_coor = find_cells_coor(&grid, 'E');
-
-    let solutions = astar_bag(
-        &Node::get_start_node(&grid),
-        |node| node.successors(&grid),
-        |node| node.heuristic(&end_coor),
-        |node| node.success(&grid),
+    let end_coor = find_cells_coor(grid, 'E');
+    let to_end = directional_distance_field(
+        grid,
+        grid.all_adjacent_directions()
+            .map(|direction| (end_coor, direction)),
+        FieldDirection::Backward,
+        step_costs,
+        turn_costs,
     );
+    if from_start
+        .get_unchecked(end_coor.x, end_coor.y)
+        .iter()
+        .all(Option::is_none)
+    {
+        return Err(miette!("No path from the start to the end"));
+    }
 
-    Ok(solutions
-        .map(|(solutions, _min_costs)| {
-            solutions
-                .flat_map(|path| path.into_iter().map(|n| n.coor))
-                .unique()
-                .count()
-                .to_string()
-        })
-        .unwrap())
+    Ok(on_optimal_paths(&from_start, &to_end)
+        .iter()
+        .filter(|&&on_path| on_path)
+        .count()
+        .to_string())
 }
 
 fn find_cells_coor(
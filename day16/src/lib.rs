@@ -14,5 +14,41 @@
     clippy::bool_to_int_with_if
 )]
 
+pub mod custom_error;
+pub mod day16_common;
+pub mod day16_generate;
 pub mod day16_part1;
 pub mod day16_part2;
+
+use grid::grid_array::GridArray;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 16: both parts search the cheapest paths through the same maze
+pub struct Day16;
+
+impl Day for Day16 {
+    const DAY: u8 = 16;
+    type Input = GridArray<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day16_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day16_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day16_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day16_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day16>::PART1, &DayPart::<Day16>::PART2];
@@ -0,0 +1,82 @@
+use itertools::Itertools;
+use utils::parse_error::{ParseContext, ParseError};
+
+use crate::custom_error::AocError;
+
+pub type RegistersType = Vec<usize>;
+
+/// The registers and the program (pairs of op code and argument) of the computer
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub registers: RegistersType,
+    pub program: Vec<usize>,
+}
+
+pub fn register_name_to_index(register: char) -> usize {
+    match register {
+        'A' => 0,
+        'B' => 1,
+        'C' => 2,
+        _ => panic!("Unknown register {register}"),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Computer, AocError> {
+    let context = ParseContext::new(input);
+    let (registers_str, program_str) = context.split_once(input, "\n\n")?;
+    let registers: RegistersType = registers_str
+        .lines()
+        .map(|line| {
+            let register_str = context.strip_prefix(line, "Register ")?;
+            let (name_str, value_str) = context.split_once(register_str, ": ")?;
+            let register_index = match name_str {
+                "A" | "B" | "C" => register_name_to_index(name_str.chars().next().unwrap()),
+                _ => return Err(context.error(name_str, "a register name ('A', 'B' or 'C')")),
+            };
+            Ok((register_index, context.parse(value_str, "a register value")?))
+        })
+        .collect::<Result<Vec<(usize, usize)>, ParseError>>()?
+        .into_iter()
+        .sorted_by_key(|(register_index, _)| *register_index)
+        .map(|(_, value)| value)
+        .collect();
+    if registers.len() != 3 {
+        return Err(context
+            .error_after(registers_str, "the registers A, B and C")
+            .into());
+    }
+    let program_str = context.strip_prefix(program_str.trim_end(), "Program: ")?;
+    let program = parse_program(&context, program_str)?;
+    Ok(Computer { registers, program })
+}
+
+/// Parses the program, pairs of op code and argument (`0,1,5,4,3,0`)
+fn parse_program<'a>(
+    context: &ParseContext<'a>,
+    program_str: &'a str,
+) -> Result<Vec<usize>, ParseError> {
+    let mut program = Vec::new();
+    for mut instruction in &program_str.split(',').chunks(2) {
+        let op_str = instruction.next().unwrap();
+        let argument_str = instruction
+            .next()
+            .ok_or_else(|| context.error_after(op_str, "',' and an argument"))?;
+        let op_code = context
+            .parse(op_str, "an op code (0-7)")
+            .ok()
+            .filter(|op_code| *op_code < 8)
+            .ok_or_else(|| context.error(op_str, "an op code (0-7)"))?;
+        // combo operands 0-3 are literals and 4-6 registers A-C, 7 is reserved
+        let (limit, expected) = match op_code {
+            0 | 2 | 5 | 6 | 7 => (7, "a combo operand (0-6)"),
+            _ => (8, "an argument (0-7)"),
+        };
+        let argument = context
+            .parse(argument_str, expected)
+            .ok()
+            .filter(|argument| *argument < limit)
+            .ok_or_else(|| context.error(argument_str, expected))?;
+        program.extend([op_code, argument]);
+    }
+    Ok(program)
+}
@@ -0,0 +1,47 @@
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+use crate::day17_common::Computer;
+use crate::day17_part2;
+
+/// A program like the real puzzle input (`2,4,1,?,7,5,1,?,4,?,5,5,0,3,3,0`) with random
+/// arguments which outputs itself for some value of register A (part 2); the initial value
+/// of register A has `size` octal digits (1 to 16), so the program outputs `size` numbers
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let digits = u32::try_from(size.clamp(1, 16)).unwrap();
+    let a_value = rng.gen_range(8_usize.pow(digits - 1)..8_usize.pow(digits));
+    let program = (0..100)
+        .map(|_| {
+            vec![
+                2,
+                4,
+                1,
+                rng.gen_range(0..8),
+                7,
+                5,
+                1,
+                rng.gen_range(0..8),
+                4,
+                rng.gen_range(0..8),
+                5,
+                5,
+                0,
+                3,
+                3,
+                0,
+            ]
+        })
+        .find(|program| {
+            day17_part2::solve(&Computer {
+                registers: vec![a_value, 0, 0],
+                program: program.clone(),
+            })
+            .is_ok()
+        })
+        .unwrap_or_else(|| vec![2, 4, 1, 5, 7, 5, 1, 6, 4, 1, 5, 5, 0, 3, 3, 0]);
+    let program = program.iter().map(ToString::to_string).collect::<Vec<_>>();
+    GeneratedInput::new(format!(
+        "Register A: {a_value}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+        program.join(",")
+    ))
+}
@@ -3,9 +3,9 @@ use itertools::{join, Itertools};
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
-use miette::{miette, Error, Result};
+use miette::Result;
 
-type RegistersType = Vec<usize>;
+use crate::day17_common::{parse, register_name_to_index, Computer, RegistersType};
 
 #[derive(Debug, Clone)]
 enum Operation {
@@ -20,18 +20,18 @@ enum Operation {
 }
 
 impl Operation {
-    fn create(op_code: char, argument: usize) -> Result<Operation> {
+    fn create(op_code: usize, argument: usize) -> Option<Operation> {
         match op_code {
-            '0' => Ok(Operation::DivisionA(argument)),
-            '1' => Ok(Operation::BitwiseXorB(argument)),
-            '2' => Ok(Operation::Modulo8(argument)),
-            '3' => Ok(Operation::Jump(argument)),
-            '4' => Ok(Operation::BitwiseXorBC()),
-            '5' => Ok(Operation::Output(argument)),
-            '6' => Ok(Operation::DivisionB(argument)),
-            '7' => Ok(Operation::DivisionC(argument)),
-
-            _ => Err(miette!("Unknown op code {op_code}")),
+            0 => Some(Operation::DivisionA(argument)),
+            1 => Some(Operation::BitwiseXorB(argument)),
+            2 => Some(Operation::Modulo8(argument)),
+            3 => Some(Operation::Jump(argument)),
+            4 => Some(Operation::BitwiseXorBC()),
+            5 => Some(Operation::Output(argument)),
+            6 => Some(Operation::DivisionB(argument)),
+            7 => Some(Operation::DivisionC(argument)),
+
+            _ => None,
         }
     }
 
@@ -107,21 +107,14 @@ impl Operation {
     }
 }
 
-fn register_name_to_index(register: char) -> usize {
-    match register {
-        'A' => 0,
-        'B' => 1,
-        'C' => 2,
-        _ => panic!("Unknown register {register}"),
-    }
-}
-
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let (mut registers, program) = parse(input)?;
+    solve(&parse(input)?)
+}
 
-    println!("{registers:?}");
-    println!("{program:?}");
+pub fn solve(computer: &Computer) -> Result<String> {
+    let mut registers = computer.registers.clone();
+    let program = operations(&computer.program);
 
     let mut instruction_ptr = 0;
     let mut result = Vec::new();
@@ -130,41 +123,19 @@ pub fn process(input: &str) -> Result<String> {
         {
             result.push(output);
         }
-        println!("instruction_ptr: {instruction_ptr}");
-        println!("Registers: {registers:?}");
-        println!("Result: {}", result.iter().join(","));
     }
 
     Ok(result.into_iter().join(","))
 }
 
-fn parse(input: &str) -> Result<(RegistersType, Vec<Operation>), Error> {
-    let (registers_str, program_str) = input.split_once("\n\n").ok_or(miette!("Input wrong"))?;
-    let registers: RegistersType = registers_str
-        .lines()
-        .map(|line| {
-            let (name_str, value_str) = line["Register ".len()..].split_once(' ').unwrap();
-            (name_str.chars().next().unwrap(), value_str.parse().unwrap())
-        })
-        .sorted_by_key(|(register_name, _)| register_name_to_index(*register_name))
-        .map(|(_, value)| value)
-        .collect();
-    let program_str = &program_str["Program: ".len()..];
-    let program: Vec<Operation> = program_str
-        .split(',')
+/// Decodes the pairs of op code and argument
+fn operations(program: &[usize]) -> Vec<Operation> {
+    program
         .chunks(2)
-        .into_iter()
-        .map(|mut sub_iter| {
-            let op_str = sub_iter.next().unwrap();
-            let argument_str = sub_iter.next().unwrap();
-            Operation::create(
-                op_str.chars().next().unwrap(),
-                argument_str.parse().unwrap(),
-            )
-            .unwrap()
+        .map(|instruction| {
+            Operation::create(instruction[0], instruction[1]).expect("op code checked by parse")
         })
-        .collect();
-    Ok((registers, program))
+        .collect()
 }
 
 #[cfg(test)]
@@ -188,4 +159,26 @@ Program: 0,1,5,4,3,0";
         assert_eq!("3,5,0,1,5,1,5,1,0", process(&input.replace('\r', ""))?);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,9,4,3,0";
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            "Parse error at line 5, column 14: expected an op code (0-7)",
+            err.to_string()
+        );
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,7";
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            "Parse error at line 5, column 12: expected a combo operand (0-6)",
+            err.to_string()
+        );
+        let input = "Register A: 729\nRegister D: 0\nRegister C: 0\n\nProgram: 0,1";
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 10: expected a register name ('A', 'B' or 'C')",
+            err.to_string()
+        );
+    }
 }
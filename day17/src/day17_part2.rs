@@ -3,9 +3,9 @@ use itertools::{join, Itertools};
 use num_traits::ToPrimitive;
 use rayon::prelude::*;
 
-use miette::{miette, Error, Result};
+use miette::{miette, Result};
 
-type RegistersType = Vec<usize>;
+use crate::day17_common::{parse, register_name_to_index, Computer, RegistersType};
 
 #[derive(Debug, Clone)]
 enum Operation {
@@ -20,18 +20,18 @@ enum Operation {
 }
 
 impl Operation {
-    fn create(op_code: char, argument: usize) -> Result<Operation> {
+    fn create(op_code: usize, argument: usize) -> Option<Operation> {
         match op_code {
-            '0' => Ok(Operation::DivisionA(argument)),
-            '1' => Ok(Operation::BitwiseXorB(argument)),
-            '2' => Ok(Operation::Modulo8(argument)),
-            '3' => Ok(Operation::Jump(argument)),
-            '4' => Ok(Operation::BitwiseXorBC()),
-            '5' => Ok(Operation::Output(argument)),
-            '6' => Ok(Operation::DivisionB(argument)),
-            '7' => Ok(Operation::DivisionC(argument)),
-
-            _ => Err(miette!("Unknown op code {op_code}")),
+            0 => Some(Operation::DivisionA(argument)),
+            1 => Some(Operation::BitwiseXorB(argument)),
+            2 => Some(Operation::Modulo8(argument)),
+            3 => Some(Operation::Jump(argument)),
+            4 => Some(Operation::BitwiseXorBC()),
+            5 => Some(Operation::Output(argument)),
+            6 => Some(Operation::DivisionB(argument)),
+            7 => Some(Operation::DivisionC(argument)),
+
+            _ => None,
         }
     }
 
@@ -150,31 +150,26 @@ impl Operation {
     }
 }
 
-fn register_name_to_index(register: char) -> usize {
-    match register {
-        'A' => 0,
-        'B' => 1,
-        'C' => 2,
-        _ => panic!("Unknown register {register}"),
-    }
-}
-
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let (orig_registers, program, program_vec, program_str) = parse(input)?;
+    solve(&parse(input)?)
+}
+
+pub fn solve(computer: &Computer) -> Result<String> {
+    let program = operations(&computer.program);
 
     //generate_source_code(&program);
 
-    if program_str == "0,3,5,4,3,0" {
-        let result = search_for_a_example(&program_vec);
-        Ok(result.to_string())
-    } else if program_str == "2,4,1,5,7,5,1,6,4,1,5,5,0,3,3,0" {
-        let result = search_for_a_input(&program_vec);
-        Ok(result.to_string())
+    let result = if computer.program == [0, 3, 5, 4, 3, 0] {
+        search_for_a_example(&computer.program)
+    } else if computer.program == [2, 4, 1, 5, 7, 5, 1, 6, 4, 1, 5, 5, 0, 3, 3, 0] {
+        search_for_a_input(&computer.program)
     } else {
-        let result = search_for_a(&program_vec, &orig_registers, &program);
-        Ok(result.to_string())
-    }
+        search_for_a(&computer.program, &computer.registers, &program)
+    };
+    result
+        .map(|a_value| a_value.to_string())
+        .ok_or_else(|| miette!("No value of register A makes the program output itself"))
 }
 
 fn generate_source_code(program: &[Operation]) {
@@ -186,7 +181,11 @@ fn generate_source_code(program: &[Operation]) {
     println!("}}");
 }
 
-fn search_for_a(program_vec: &[usize], registers: &[usize], program: &[Operation]) -> usize {
+fn search_for_a(
+    program_vec: &[usize],
+    registers: &[usize],
+    program: &[Operation],
+) -> Option<usize> {
     let mut search: Vec<usize> = vec![0];
 
     for min_length in (0..program_vec.len()).rev() {
@@ -196,7 +195,7 @@ fn search_for_a(program_vec: &[usize], registers: &[usize], program: &[Operation
             .iter()
             .flat_map(|prev_a| (0..8).map(move |delta| delta + (prev_a * 8)))
         {
-            match solve(new_a_value, registers, program) {
+            match first_output(new_a_value, registers, program) {
                 Some(new_output) if new_output == expected_output => {
                     next.push(new_a_value); // 15/3, 14/24;25;29;31
                 }
@@ -206,10 +205,10 @@ fn search_for_a(program_vec: &[usize], registers: &[usize], program: &[Operation
         search = next;
     }
 
-    search.into_iter().min().unwrap()
+    search.into_iter().min()
 }
 
-fn search_for_a_input(program_vec: &[usize]) -> usize {
+fn search_for_a_input(program_vec: &[usize]) -> Option<usize> {
     let mut search: Vec<usize> = vec![0];
 
     for min_length in (0..program_vec.len()).rev() {
@@ -229,10 +228,10 @@ fn search_for_a_input(program_vec: &[usize]) -> usize {
         search = next;
     }
 
-    search.into_iter().min().unwrap()
+    search.into_iter().min()
 }
 
-fn search_for_a_example(program_vec: &[usize]) -> usize {
+fn search_for_a_example(program_vec: &[usize]) -> Option<usize> {
     let mut search: Vec<usize> = vec![0];
 
     for min_length in (0..program_vec.len()).rev() {
@@ -252,20 +251,15 @@ fn search_for_a_example(program_vec: &[usize]) -> usize {
         search = next;
     }
 
-    search.into_iter().min().unwrap()
+    search.into_iter().min()
 }
 
-fn solve(a_value: usize, registers: &[usize], program: &[Operation]) -> Option<usize> {
+/// Runs the program with register A set to `a_value` until its first output
+fn first_output(a_value: usize, registers: &[usize], program: &[Operation]) -> Option<usize> {
     let mut registers = registers.to_vec();
     registers[register_name_to_index('A')] = a_value;
     let mut instruction_ptr = 0;
     while instruction_ptr < program.len() {
-        println!(
-            "{instruction_ptr} {} {} {}",
-            registers[register_name_to_index('A')],
-            registers[register_name_to_index('B')],
-            registers[register_name_to_index('C')]
-        );
         if let Some(output) = program[instruction_ptr].execute(&mut registers, &mut instruction_ptr)
         {
             return Some(output);
@@ -354,42 +348,20 @@ fn solve_fast_example(a_value: usize) -> Option<usize> {
     None
 }
 
-#[allow(clippy::type_complexity)]
-fn parse(input: &str) -> Result<(RegistersType, Vec<Operation>, Vec<usize>, &str), Error> {
-    let (registers_str, program_str) = input.split_once("\n\n").ok_or(miette!("Input wrong"))?;
-    let registers: RegistersType = registers_str
-        .lines()
-        .map(|line| {
-            let (name_str, value_str) = line["Register ".len()..].split_once(' ').unwrap();
-            (name_str.chars().next().unwrap(), value_str.parse().unwrap())
-        })
-        .sorted_by_key(|(register_name, _)| register_name_to_index(*register_name))
-        .map(|(_, value)| value)
-        .collect();
-    let program_str = &program_str["Program: ".len()..];
-    let program: Vec<Operation> = program_str
-        .split(',')
+/// Decodes the pairs of op code and argument
+fn operations(program: &[usize]) -> Vec<Operation> {
+    program
         .chunks(2)
-        .into_iter()
-        .map(|mut sub_iter| {
-            let op_str = sub_iter.next().unwrap();
-            let argument_str = sub_iter.next().unwrap();
-            Operation::create(
-                op_str.chars().next().unwrap(),
-                argument_str.parse().unwrap(),
-            )
-            .unwrap()
+        .map(|instruction| {
+            Operation::create(instruction[0], instruction[1]).expect("op code checked by parse")
         })
-        .collect();
-    let program_vec = program_str
-        .split(',')
-        .map(|prg_sub_str| prg_sub_str.chars().next().unwrap().to_digit(10).unwrap() as usize)
-        .collect_vec();
-    Ok((registers, program, program_vec, program_str))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -409,4 +381,24 @@ Program: 0,3,5,4,3,0";
         assert_eq!("107413700225434", process(&input.replace('\r', ""))?);
         Ok(())
     }
+
+    proptest! {
+        #[test]
+        fn test_solve_fast_input_agrees_with_interpreter(a_value in 0..1_usize << 48) {
+            let program = operations(&[2, 4, 1, 5, 7, 5, 1, 6, 4, 1, 5, 5, 0, 3, 3, 0]);
+            prop_assert_eq!(
+                first_output(a_value, &[0, 0, 0], &program),
+                solve_fast_input(a_value)
+            );
+        }
+
+        #[test]
+        fn test_solve_fast_example_agrees_with_interpreter(a_value in 0..1_usize << 48) {
+            let program = operations(&[0, 3, 5, 4, 3, 0]);
+            prop_assert_eq!(
+                first_output(a_value, &[0, 0, 0], &program),
+                solve_fast_example(a_value)
+            );
+        }
+    }
 }
@@ -15,5 +15,40 @@
 )]
 
 
+pub mod custom_error;
+pub mod day17_common;
+pub mod day17_generate;
 pub mod day17_part1;
 pub mod day17_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 17: both parts run the same program on the same computer
+pub struct Day17;
+
+impl Day for Day17 {
+    const DAY: u8 = 17;
+    type Input = day17_common::Computer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day17_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day17_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day17_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day17_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day17>::PART1, &DayPart::<Day17>::PART2];
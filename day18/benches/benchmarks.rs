@@ -1,4 +1,5 @@
 use day18::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,13 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day18_part1() {
-    day18_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")), 70+1, 1024)
-    .unwrap();
+fn day18_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day18/input1.txt").unwrap();
+    bencher.bench(|| Day18::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day18_part2() {
-    day18_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")), 70+1)
-    .unwrap();
-}
\ No newline at end of file
+fn day18_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day18/input1.txt").unwrap();
+    let input = Day18::parse(&input).unwrap();
+    let params = Day18::default_params(1);
+    bencher.bench(|| Day18::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day18_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day18/input2.txt").unwrap();
+    let input = Day18::parse(&input).unwrap();
+    let params = Day18::default_params(2);
+    bencher.bench(|| Day18::part2(divan::black_box(&input), &params).unwrap());
+}
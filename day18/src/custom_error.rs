@@ -0,0 +1,27 @@
+use miette::Diagnostic;
+use thiserror::Error;
+use utils::parse_error::ParseError;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] ParseError),
+
+    #[error("Byte {x},{y} in line {line} falls outside of the memory space (0-{max})")]
+    #[diagnostic(code(aoc::outside_memory))]
+    OutsideMemory {
+        line: usize,
+        x: usize,
+        y: usize,
+        max: usize,
+    },
+
+    #[error("The memory space has no cells (width 0)")]
+    #[diagnostic(code(aoc::empty_memory))]
+    EmptyMemory,
+}
@@ -0,0 +1,41 @@
+use grid::grid_types::UCoor2D;
+use utils::parse_error::{ParseContext, ParseError};
+
+use crate::custom_error::AocError;
+
+/// Parses the positions of the falling bytes, one `x,y` per line
+pub fn parse(input: &str) -> Result<Vec<UCoor2D>, AocError> {
+    let context = ParseContext::new(input);
+    Ok(input
+        .lines()
+        .map(|line| parse_coor(&context, line))
+        .collect::<Result<_, _>>()?)
+}
+
+fn parse_coor<'a>(context: &ParseContext<'a>, line: &'a str) -> Result<UCoor2D, ParseError> {
+    let (x_str, y_str) = context.split_once(line, ",")?;
+    Ok(UCoor2D::new(
+        context.parse(x_str, "a coordinate")?,
+        context.parse(y_str, "a coordinate")?,
+    ))
+}
+
+/// Checks that the memory space is not empty and that all bytes fall inside of it (its width
+/// depends on the puzzle parameters)
+pub fn check_bytes(bytes: &[UCoor2D], width: usize) -> Result<(), AocError> {
+    if width == 0 {
+        return Err(AocError::EmptyMemory);
+    }
+    match bytes
+        .iter()
+        .position(|coor| coor.x >= width || coor.y >= width)
+    {
+        Some(index) => Err(AocError::OutsideMemory {
+            line: index + 1,
+            x: bytes[index].x,
+            y: bytes[index].y,
+            max: width - 1,
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,29 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use utils::generate::{maze, GeneratedInput, StdRng};
+
+/// Falling bytes in a memory space of `size` x `size` (`size` rounded up to an odd number)
+///
+/// The first bytes are the walls of a maze, so a path from the upper left to the lower right
+/// corner remains (part 1 takes all of them). The remaining bytes fall on the paths of the maze
+/// until one of them blocks the exit (part 2).
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    // the maze without its surrounding walls
+    let rows = maze(rng, size.max(3) + 2, size.max(3) + 2);
+    let width = rows.len() - 2;
+    let (mut walls, mut paths): (Vec<_>, Vec<_>) = (0..width)
+        .cartesian_product(0..width)
+        .filter(|&(x, y)| (x, y) != (0, 0) && (x, y) != (width - 1, width - 1))
+        .partition(|&(x, y)| rows[y + 1][x + 1] == '#');
+    walls.shuffle(rng);
+    paths.shuffle(rng);
+    let bytes_to_take = walls.len();
+    let bytes = walls
+        .into_iter()
+        .chain(paths)
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect_vec();
+    GeneratedInput::new(bytes.join("\n"))
+        .with_param("width", &width)
+        .with_param("bytes_to_take", &bytes_to_take)
+}
@@ -1,60 +1,46 @@
 use grid::{
-    grid_array::{GridArray, GridArrayBuilder},
+    bit_grid::BitGrid,
     grid_types::{Neighborhood, Topology, UCoor2D},
 };
-use itertools::Itertools;
-use pathfinding::prelude::*;
 
-use miette::{Error, Result};
+use miette::{miette, Error, Result};
 
-//#[tracing::instrument]
-pub fn process(input: &str, width: usize, bytes_to_take: usize) -> Result<String, Error> {
-    let mut grid = GridArrayBuilder::default().topology(Topology::Bounded).neighborhood(
-        Neighborhood::Orthogonal).width(width).height(width).build().unwrap();
-
-    for x in 0..width {
-        for y in 0..width {
-            grid.set(x, y, '.');
-        }
-    }
+use crate::day18_common::{check_bytes, parse};
 
-    for line in input.lines().take(bytes_to_take) {
-        let (x_str, y_str) = line.split_once(',').unwrap();
-        grid.set(
-            x_str.parse::<usize>().unwrap(),
-            y_str.parse::<usize>().unwrap(),
-            '#',
-        );
-    }
-    //grid.println(false);
-
-    let result = astar(
-        &UCoor2D::new(0, 0),
-        |coor| successors(coor, &grid),
-        |coor| heuristic(coor, &grid),
-        |coor| success(coor, &grid),
-    );
-    let min_costs = &result.unwrap().1;
-    Ok(min_costs.to_string())
-}
-
-fn successors(coor: &UCoor2D, grid: &GridArray<char>) -> Vec<(UCoor2D, usize)> {
-    grid.neighborhood_cells(coor.x, coor.y)
-        .filter(|(_coor, cell)| cell != &&'#')
-        .map(|(coor, _cell)| (coor, 1))
-        .collect_vec()
-}
+/// Size of the memory space and number of fallen bytes of the real puzzle (the example uses 6+1 and 12)
+pub const WIDTH: usize = 70 + 1;
+pub const BYTES_TO_TAKE: usize = 1024;
 
-fn success(coor: &UCoor2D, grid: &GridArray<char>) -> bool {
-    *coor == end_coor(grid)
+//#[tracing::instrument]
+pub fn process(input: &str) -> Result<String, Error> {
+    solve(&parse(input)?, WIDTH, BYTES_TO_TAKE)
 }
 
-fn end_coor(grid: &GridArray<char>) -> UCoor2D {
-    UCoor2D::new(grid.width() - 1, grid.height() - 1)
-}
+pub fn solve(bytes: &[UCoor2D], width: usize, bytes_to_take: usize) -> Result<String, Error> {
+    check_bytes(bytes, width)?;
+    let corrupted = BitGrid::from_coors(
+        Topology::Bounded,
+        Neighborhood::Orthogonal,
+        width,
+        width,
+        bytes.iter().take(bytes_to_take).copied(),
+    );
+    let free = !&corrupted;
 
-fn heuristic(coor: &UCoor2D, grid: &GridArray<char>) -> usize {
-    coor.manhattan_distance(&end_coor(grid))
+    // breadth first search on whole rows of bits: every step adds the free neighbors of all
+    // reached cells
+    let mut reached = BitGrid::new(Topology::Bounded, Neighborhood::Orthogonal, width, width);
+    reached.set(0, 0, true);
+    let mut steps = 0;
+    while reached.get(width - 1, width - 1) != Some(true) {
+        let next = &reached.dilate() & &free;
+        if next == reached {
+            return Err(miette!("No path to the exit"));
+        }
+        reached = next;
+        steps += 1;
+    }
+    Ok(steps.to_string())
 }
 
 #[cfg(test)]
@@ -88,14 +74,31 @@ mod tests {
 0,5
 1,6
 2,0";
-        assert_eq!("22", process(&input.replace('\r', ""), 6+1, 12)?);
+        assert_eq!("22", solve(&parse(&input.replace('\r', ""))?, 6+1, 12)?);
         Ok(())
     }
 
     #[test]
     fn test_input() -> miette::Result<()> {
         let input = include_str!("../input1.txt");
-        assert_eq!("276", process(&input.replace('\r', ""), 70+1, 1024)?);
+        assert_eq!("276", process(&input.replace('\r', ""))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() -> miette::Result<()> {
+        let err = solve(&parse("5,4\n4,2\n4,7")?, 7, 12).unwrap_err();
+        assert_eq!(
+            "Byte 4,7 in line 3 falls outside of the memory space (0-6)",
+            err.to_string()
+        );
+        let err = solve(&[], 0, 12).unwrap_err();
+        assert_eq!("The memory space has no cells (width 0)", err.to_string());
+        let err = parse("5,4\n4 2").unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 4: expected ','",
+            err.to_string()
+        );
         Ok(())
     }
 }
@@ -1,90 +1,66 @@
-use fxhash::FxHashMap;
 use grid::{
-    grid_iteration::{adjacent_cell, all_adjacent_directions},
+    grid_array::GridArray,
     grid_types::{Neighborhood, Topology, UCoor2D},
+    search::bfs,
 };
-use pathfinding::prelude::*;
 
 use miette::{miette, Error, Result};
 
+use crate::day18_common::{check_bytes, parse};
+
+/// Size of the memory space of the real puzzle (the example uses 6+1)
+pub const WIDTH: usize = 70 + 1;
+
 //#[tracing::instrument]
-pub fn process(input: &str, width: usize) -> Result<String, Error> {
-    //let width = 6+1;
+pub fn process(input: &str) -> Result<String, Error> {
+    solve(&parse(input)?, WIDTH)
+}
 
-    let maze = Maze::parse(input, width);
-    binary_search(0, maze.walls_with_time.len(), |mid| maze.find_path(mid))
-        .and_then(|found_time| input.lines().nth(found_time))
-        .map(ToString::to_string)
+pub fn solve(bytes: &[UCoor2D], width: usize) -> Result<String, Error> {
+    check_bytes(bytes, width)?;
+    let maze = Maze::new(bytes, width);
+    // the first byte count without a path, the last of these bytes blocks it
+    binary_search(1, bytes.len() + 1, |mid| maze.find_path(mid))
+        .map(|blocked_time| &bytes[blocked_time - 1])
+        .map(|coor| format!("{},{}", coor.x, coor.y))
         .ok_or(miette!("No path blocker found!"))
 }
 
 #[derive(Debug)]
 struct Maze {
-    width: usize,
-    walls_with_time: FxHashMap<UCoor2D, usize>,
+    /// time at which a byte falls onto the cell (`usize::MAX` if none does)
+    fall_times: GridArray<usize>,
 }
 
 impl Maze {
-    fn parse(input: &str, width: usize) -> Maze {
-        let walls_with_time = input
-            .lines()
-            .enumerate()
-            .map(|(i, line)| {
-                let (x_str, y_str) = line.split_once(',').unwrap();
-
-                (
-                    UCoor2D::new(
-                        x_str.parse::<usize>().unwrap(),
-                        y_str.parse::<usize>().unwrap(),
-                    ),
-                    i,
-                )
-            })
-            .collect();
-        Maze {
+    fn new(bytes: &[UCoor2D], width: usize) -> Maze {
+        let mut fall_times = GridArray::from_1d_vec(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
             width,
-            walls_with_time,
+            vec![usize::MAX; width * width],
+        );
+        for (time, coor) in bytes.iter().enumerate().rev() {
+            fall_times.set(coor.x, coor.y, time);
         }
+        Maze { fall_times }
     }
 
     fn find_path(&self, time: usize) -> bool {
-        astar(
+        let goal = UCoor2D::new(self.fall_times.width() - 1, self.fall_times.height() - 1);
+        bfs(
+            &self.fall_times,
             &UCoor2D::new(0, 0),
-            |coor| self.successors(coor, time),
-            |coor| self.heuristic(coor),
-            |coor| self.success(coor),
+            Some(&goal),
+            |&fall_time| fall_time >= time,
         )
+        .goal_distance()
         .is_some()
     }
-
-    fn successors(&self, coor: &UCoor2D, time: usize) -> Vec<(UCoor2D, usize)> {
-        all_adjacent_directions(Neighborhood::Orthogonal)
-            .filter_map(|dir| {
-                adjacent_cell(Topology::Bounded, self.width, self.width, coor.clone(), dir)
-            })
-            .filter(|new_coor| !self.is_occupied_by_wall(new_coor, time))
-            .map(|new_coor| (new_coor, 1))
-            .collect()
-    }
-
-    fn is_occupied_by_wall(&self, new_coor: &UCoor2D, time: usize) -> bool {
-        self.walls_with_time
-            .get(new_coor)
-            .map_or(false, |wall_fall_time| wall_fall_time < &time)
-    }
-
-    fn success(&self, coor: &UCoor2D) -> bool {
-        *coor == self.coor_goal()
-    }
-
-    fn heuristic(&self, coor: &UCoor2D) -> usize {
-        coor.manhattan_distance(&self.coor_goal())
-    }
-    fn coor_goal(&self) -> UCoor2D {
-        UCoor2D::new(self.width - 1, self.width - 1)
-    }
 }
 
+/// Smallest value in `lower_end..upper_end` for which `match_fn` fails,
+/// `None` if it holds for all of them (`match_fn` must hold up to some value only)
 fn binary_search(
     lower_end: usize,
     upper_end: usize,
@@ -92,9 +68,10 @@ fn binary_search(
 ) -> Option<usize> {
     let mut lower_end = lower_end;
     let mut upper_end = upper_end;
+    let end = upper_end;
 
     while lower_end < upper_end {
-        let mid = (lower_end + upper_end) / 2;
+        let mid = usize::midpoint(lower_end, upper_end);
         if match_fn(mid) {
             lower_end = mid + 1;
         } else {
@@ -102,11 +79,7 @@ fn binary_search(
         }
     }
 
-    if match_fn(upper_end - 1) {
-        Some(upper_end - 1)
-    } else {
-        None
-    }
+    (upper_end < end).then_some(upper_end)
 }
 
 #[cfg(test)]
@@ -140,14 +113,27 @@ mod tests {
 0,5
 1,6
 2,0";
-        assert_eq!("6,1", process(&input.replace('\r', ""), 6 + 1)?);
+        assert_eq!("6,1", solve(&parse(&input.replace('\r', ""))?, 6 + 1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_bytes() {
+        let err = solve(&[], 6 + 1).unwrap_err();
+        assert_eq!("No path blocker found!", err.to_string());
+    }
+
+    #[test]
+    fn test_no_blocker() -> miette::Result<()> {
+        let err = solve(&parse("5,4\n4,2\n4,5")?, 6 + 1).unwrap_err();
+        assert_eq!("No path blocker found!", err.to_string());
         Ok(())
     }
 
     #[test]
     fn test_input() -> miette::Result<()> {
         let input = include_str!("../input2.txt");
-        assert_eq!("60,37", process(&input.replace('\r', ""), 70 + 1)?);
+        assert_eq!("60,37", process(&input.replace('\r', ""))?);
         Ok(())
     }
 }
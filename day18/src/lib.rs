@@ -15,5 +15,51 @@
 )]
 
 
+pub mod custom_error;
+pub mod day18_common;
+pub mod day18_generate;
 pub mod day18_part1;
 pub mod day18_part2;
+
+use grid::grid_types::UCoor2D;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 18: both parts let the same bytes fall into the memory space
+pub struct Day18;
+
+impl Day for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<UCoor2D>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day18_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<String> {
+        day18_part1::solve(input, params.get("width")?, params.get("bytes_to_take")?)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<String> {
+        day18_part2::solve(input, params.get("width")?)
+    }
+
+    fn default_params(part: u8) -> Params {
+        match part {
+            1 => Params::from([
+                ("width", day18_part1::WIDTH),
+                ("bytes_to_take", day18_part1::BYTES_TO_TAKE),
+            ]),
+            _ => Params::from([("width", day18_part2::WIDTH)]),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day18_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day18>::PART1, &DayPart::<Day18>::PART2];
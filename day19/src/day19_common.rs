@@ -0,0 +1,19 @@
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+/// The available towel patterns and the designs of the wanted towels
+#[derive(Debug, Clone)]
+pub struct Onsen {
+    pub patterns: Vec<String>,
+    pub towels: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Result<Onsen, AocError> {
+    let context = ParseContext::new(input);
+    let (patterns, towels) = context.split_once(input, "\n\n")?;
+    Ok(Onsen {
+        patterns: patterns.split(", ").map(ToString::to_string).collect(),
+        towels: towels.lines().map(ToString::to_string).collect(),
+    })
+}
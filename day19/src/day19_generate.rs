@@ -0,0 +1,39 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Colors of the stripes
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// `size` towel patterns (1 to 8 stripes) and `size` designs; about half of the designs are
+/// made of the patterns
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let random_stripes = |rng: &mut StdRng, len: usize| {
+        (0..len)
+            .map(|_| *COLORS.choose(rng).unwrap())
+            .collect::<String>()
+    };
+    let patterns = (0..size.max(1))
+        .map(|_| {
+            let len = rng.gen_range(1..=8);
+            random_stripes(rng, len)
+        })
+        .unique()
+        .collect_vec();
+    let designs = (0..size.max(1))
+        .map(|_| {
+            let len = rng.gen_range(20..=60);
+            if rng.gen_bool(0.5) {
+                let mut design = String::new();
+                while design.len() < len {
+                    design.push_str(patterns.choose(rng).unwrap());
+                }
+                design
+            } else {
+                random_stripes(rng, len)
+            }
+        })
+        .collect_vec();
+    GeneratedInput::new(format!("{}\n\n{}", patterns.join(", "), designs.join("\n")))
+}
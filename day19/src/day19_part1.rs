@@ -1,37 +1,52 @@
 use fxhash::FxHashMap;
 use itertools::Itertools;
 
-use miette::{miette, Result};
+use miette::Result;
 
 use crate::cache_it;
+use crate::day19_common::{parse, Onsen};
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let (patterns, towels) = input.split_once("\n\n").ok_or(miette!("Invalid input"))?;
-    let patterns = patterns.split(", ").collect_vec();
-    let result = towels
-        .lines()
-        .filter(|towel| is_matching_any_pattern_cached(towel, &patterns))
+    solve(&parse(input)?)
+}
+
+pub fn solve(onsen: &Onsen) -> Result<String> {
+    let patterns = onsen.patterns.iter().map(String::as_str).collect_vec();
+    let patterns_fingerprint = fxhash::hash64(&patterns);
+    let result = onsen
+        .towels
+        .iter()
+        .filter(|towel| is_matching_any_pattern_cached(towel, &patterns, patterns_fingerprint))
         .count();
     Ok(result.to_string())
 }
 
-fn is_matching_any_pattern_cached(towel: &str, patterns: &Vec<&str>) -> bool {
+/// The cache is keyed by the patterns' fingerprint as well, so different inputs don't share results
+fn is_matching_any_pattern_cached(
+    towel: &str,
+    patterns: &Vec<&str>,
+    patterns_fingerprint: u64,
+) -> bool {
     cache_it!(
-        FxHashMap<String, bool>,
+        FxHashMap<(u64, String), bool>,
         FxHashMap::default(),
-        towel.to_string(),
-        is_matching_any_pattern(towel, patterns)
+        (patterns_fingerprint, towel.to_string()),
+        is_matching_any_pattern(towel, patterns, patterns_fingerprint)
     )
 }
 
-fn is_matching_any_pattern(towel: &str, patterns: &Vec<&str>) -> bool {
+fn is_matching_any_pattern(towel: &str, patterns: &Vec<&str>, patterns_fingerprint: u64) -> bool {
     if towel.is_empty() {
         return true;
     }
     let matched = patterns.iter().any(|pattern| {
         towel.starts_with(pattern)
-            && is_matching_any_pattern_cached(&towel[pattern.len()..], patterns)
+            && is_matching_any_pattern_cached(
+                &towel[pattern.len()..],
+                patterns,
+                patterns_fingerprint,
+            )
     });
 
     matched
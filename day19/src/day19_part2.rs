@@ -2,31 +2,47 @@ use fxhash::FxHashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
 
-use miette::{miette, Result};
+use miette::Result;
 
 use crate::cache_it;
+use crate::day19_common::{parse, Onsen};
 
 pub fn process(input: &str) -> Result<String> {
-    let (patterns, towels) = input.split_once("\n\n").ok_or(miette!("Invalid input"))?;
-    let patterns = patterns.split(", ").collect_vec();
-    let result = towels
-        .lines()
-        .par_bridge()
-        .map(|towel| count_matching_pattern_combinations_cached(towel, &patterns))
+    solve(&parse(input)?)
+}
+
+pub fn solve(onsen: &Onsen) -> Result<String> {
+    let patterns = onsen.patterns.iter().map(String::as_str).collect_vec();
+    let patterns_fingerprint = fxhash::hash64(&patterns);
+    let result = onsen
+        .towels
+        .par_iter()
+        .map(|towel| {
+            count_matching_pattern_combinations_cached(towel, &patterns, patterns_fingerprint)
+        })
         .sum::<usize>();
     Ok(result.to_string())
 }
 
-pub fn count_matching_pattern_combinations_cached(towel: &str, patterns: &Vec<&str>) -> usize {
+/// The cache is keyed by the patterns' fingerprint as well, so different inputs don't share results
+pub fn count_matching_pattern_combinations_cached(
+    towel: &str,
+    patterns: &Vec<&str>,
+    patterns_fingerprint: u64,
+) -> usize {
     cache_it!(
-        FxHashMap<String, usize>,
+        FxHashMap<(u64, String), usize>,
         FxHashMap::default(),
-        towel.to_string(),
-        count_matching_pattern_combinations(towel, patterns)
+        (patterns_fingerprint, towel.to_string()),
+        count_matching_pattern_combinations(towel, patterns, patterns_fingerprint)
     )
 }
 
-fn count_matching_pattern_combinations(towel: &str, patterns: &Vec<&str>) -> usize {
+fn count_matching_pattern_combinations(
+    towel: &str,
+    patterns: &Vec<&str>,
+    patterns_fingerprint: u64,
+) -> usize {
     if towel.is_empty() {
         1
     } else {
@@ -34,7 +50,11 @@ fn count_matching_pattern_combinations(towel: &str, patterns: &Vec<&str>) -> usi
             .par_iter()
             .filter(|&pattern| towel.starts_with(pattern))
             .map(|pattern| {
-                count_matching_pattern_combinations_cached(&towel[pattern.len()..], patterns)
+                count_matching_pattern_combinations_cached(
+                    &towel[pattern.len()..],
+                    patterns,
+                    patterns_fingerprint,
+                )
             })
             .sum::<usize>()
     }
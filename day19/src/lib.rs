@@ -15,5 +15,40 @@
 )]
 
 mod cache_it_macro;
+pub mod custom_error;
+pub mod day19_common;
+pub mod day19_generate;
 pub mod day19_part1;
 pub mod day19_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 19: both parts arrange towels from the same patterns
+pub struct Day19;
+
+impl Day for Day19 {
+    const DAY: u8 = 19;
+    type Input = day19_common::Onsen;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day19_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day19_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day19_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day19_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day19>::PART1, &DayPart::<Day19>::PART2];
@@ -1,4 +1,5 @@
 use day20::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,13 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day20_part1() {
-    day20_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")))
-    .unwrap();
+fn day20_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day20/input1.txt").unwrap();
+    bencher.bench(|| Day20::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day20_part2() {
-    day20_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")))
-    .unwrap();
-}
\ No newline at end of file
+fn day20_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day20/input1.txt").unwrap();
+    let input = Day20::parse(&input).unwrap();
+    let params = Day20::default_params(1);
+    bencher.bench(|| Day20::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day20_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day20/input2.txt").unwrap();
+    let input = Day20::parse(&input).unwrap();
+    let params = Day20::default_params(2);
+    bencher.bench(|| Day20::part2(divan::black_box(&input), &params).unwrap());
+}
@@ -0,0 +1,40 @@
+use std::collections::VecDeque;
+
+use utils::generate::{grid_to_string, maze, GeneratedInput, StdRng};
+
+/// A `size` x `size` race track (`size` rounded up to an odd number, at least 5) without branches: the path
+/// of a maze from the upper left to the farthest cell
+///
+/// Cheats have to save at least 2 picoseconds (`min_saving_time`), so even small tracks have cheats.
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let rows = maze(rng, size.max(5), size.max(5));
+    let (width, height) = (rows[0].len(), rows.len());
+
+    // breadth first search from the start, remembering the predecessors
+    let start = (1, 1);
+    let mut predecessors = vec![vec![None; width]; height];
+    let mut queue = VecDeque::from([start]);
+    let mut end = start;
+    while let Some((x, y)) = queue.pop_front() {
+        end = (x, y);
+        for (next_x, next_y) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if rows[next_y][next_x] == '.'
+                && (next_x, next_y) != start
+                && predecessors[next_y][next_x].is_none()
+            {
+                predecessors[next_y][next_x] = Some((x, y));
+                queue.push_back((next_x, next_y));
+            }
+        }
+    }
+
+    let mut track = vec![vec!['#'; width]; height];
+    track[end.1][end.0] = 'E';
+    let mut pos = end;
+    while let Some((x, y)) = predecessors[pos.1][pos.0] {
+        track[y][x] = '.';
+        pos = (x, y);
+    }
+    track[start.1][start.0] = 'S';
+    GeneratedInput::new(grid_to_string(&track)).with_param("min_saving_time", &2)
+}
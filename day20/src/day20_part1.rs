@@ -1,8 +1,22 @@
+use grid::grid_array::GridArray;
 use miette::Result;
 
+use crate::day20_common::parse;
+
+/// Minimum time a cheat has to save and maximum cheat length of the real puzzle
+pub const MIN_SAVING_TIME: usize = 100;
+pub const CHEAT_LENGTH: usize = 2;
+
 pub fn process(input: &str) -> Result<String> {
-    Ok(crate::solve::solve(input, 100, 2).to_string())
-    //Ok(crate::solve::solve(input, 1, 2).to_string())
+    solve(&parse(input)?, MIN_SAVING_TIME, CHEAT_LENGTH)
+}
+
+pub fn solve(
+    grid: &GridArray<char>,
+    min_saving_time: usize,
+    cheat_length: usize,
+) -> Result<String> {
+    Ok(crate::solve::solve(grid, min_saving_time, cheat_length).to_string())
 }
 
 #[cfg(test)]
@@ -28,7 +42,7 @@ mod tests {
 ###############";
         assert_eq!(
             14 + 14 + 2 + 4 + 2 + 3 + 1 + 1 + 1 + 1 + 1, // 44
-            crate::solve::solve(input, 1, 2)
+            crate::solve::solve(&parse(input)?, 1, 2)
         );
         Ok(())
     }
@@ -1,9 +1,23 @@
+use grid::grid_array::GridArray;
 use miette::Result;
 
+use crate::day20_common::parse;
+
+/// Minimum time a cheat has to save and maximum cheat length of the real puzzle
+pub const MIN_SAVING_TIME: usize = 100;
+pub const CHEAT_LENGTH: usize = 20;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    Ok(crate::solve::solve(input, 100, 20).to_string())
-    //Ok(crate::solve::solve(input, 50, 20).to_string())
+    solve(&parse(input)?, MIN_SAVING_TIME, CHEAT_LENGTH)
+}
+
+pub fn solve(
+    grid: &GridArray<char>,
+    min_saving_time: usize,
+    cheat_length: usize,
+) -> Result<String> {
+    Ok(crate::solve::solve(grid, min_saving_time, cheat_length).to_string())
 }
 
 #[cfg(test)]
@@ -29,7 +43,7 @@ mod tests {
 ###############";
         assert_eq!(
             32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3, // 285
-            crate::solve::solve(input, 50, 20)
+            crate::solve::solve(&parse(input)?, 50, 20)
         );
         Ok(())
     }
@@ -16,5 +16,62 @@
 
 mod solve;
 
+pub mod custom_error;
+pub mod day20_common;
+pub mod day20_generate;
 pub mod day20_part1;
 pub mod day20_part2;
+
+use grid::grid_array::GridArray;
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 20: both parts search cheats on the same racetrack
+pub struct Day20;
+
+impl Day for Day20 {
+    const DAY: u8 = 20;
+    type Input = GridArray<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day20_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<String> {
+        day20_part1::solve(
+            input,
+            params.get("min_saving_time")?,
+            params.get("cheat_length")?,
+        )
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<String> {
+        day20_part2::solve(
+            input,
+            params.get("min_saving_time")?,
+            params.get("cheat_length")?,
+        )
+    }
+
+    fn default_params(part: u8) -> Params {
+        match part {
+            1 => Params::from([
+                ("min_saving_time", day20_part1::MIN_SAVING_TIME),
+                ("cheat_length", day20_part1::CHEAT_LENGTH),
+            ]),
+            _ => Params::from([
+                ("min_saving_time", day20_part2::MIN_SAVING_TIME),
+                ("cheat_length", day20_part2::CHEAT_LENGTH),
+            ]),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day20_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day20>::PART1, &DayPart::<Day20>::PART2];
@@ -1,18 +1,9 @@
-use fxhash::FxHashMap;
+use grid::distance_field::distance_field;
 use grid::grid_array::GridArray;
-use grid::grid_types::Neighborhood;
-use grid::grid_types::Topology;
-use grid::grid_types::UCoor2D;
-use pathfinding::prelude::dijkstra;
+use itertools::Itertools;
 use rayon::prelude::*;
 
-pub(crate) fn solve(input: &str, min_saving_time: usize, cheat_length: usize) -> usize {
-    let grid = GridArray::from_newline_separated_string(
-        Topology::Bounded,
-        Neighborhood::Orthogonal,
-        input,
-    );
-
+pub(crate) fn solve(grid: &GridArray<char>, min_saving_time: usize, cheat_length: usize) -> usize {
     let start_pos = grid
         .all_cells()
         .filter(|(_, &ch)| ch == 'S')
@@ -26,41 +17,43 @@ pub(crate) fn solve(input: &str, min_saving_time: usize, cheat_length: usize) ->
         .next()
         .unwrap();
 
-    let (path, min_costs_without_cheat) = dijkstra(
-        &start_pos,
-        |pos| {
-            grid.neighborhood_cells(pos.x, pos.y)
-                .filter(|(_, &neighbor_cell)| neighbor_cell != '#')
-                .map(|(neighbor_coor, _)| (neighbor_coor, 1))
-        },
-        |pos| pos == &end_pos,
-    )
-    .unwrap();
+    let is_track = |&cell: &char| cell != '#';
+    let costs_from_start = distance_field(grid, [start_pos], is_track);
+    let costs_to_end = distance_field(grid, [end_pos], is_track);
+    let min_costs_without_cheat = costs_from_start
+        .get_unchecked(end_pos.x, end_pos.y)
+        .unwrap();
 
-    let coor_to_costs: FxHashMap<&UCoor2D, usize> = path
-        .iter()
-        .rev()
-        .enumerate()
-        .map(|(costs, coor)| (coor, costs))
-        .collect();
-    debug_assert_eq!(coor_to_costs[&end_pos], 0);
-    debug_assert_eq!(coor_to_costs[&start_pos], min_costs_without_cheat);
+    // cells on a path from the start to the end, ordered by their costs from the start
+    // (a cheat only saves time if it ends at a cell with higher costs than where it starts)
+    let track = grid
+        .all_indexes()
+        .filter_map(|coor| {
+            let from_start = (*costs_from_start.get_unchecked(coor.x, coor.y))?;
+            let to_end = (*costs_to_end.get_unchecked(coor.x, coor.y))?;
+            Some((coor, from_start, to_end))
+        })
+        .sorted_by_key(|(_coor, from_start, _to_end)| *from_start)
+        .collect_vec();
 
-    path.par_iter()
+    let cheat_count = track
+        .par_iter()
         .enumerate()
-        .map(|(index, cheat_start_pos)| {
-            let cheat_start_costs = coor_to_costs[cheat_start_pos];
-            path.iter()
-                .skip(index + 2)
-                .filter(|cheat_end_pos| {
+        .map(|(index, (cheat_start_pos, cheat_start_costs, _))| {
+            track
+                .iter()
+                .skip(index + 1)
+                .filter(|(cheat_end_pos, _, cheat_end_costs)| {
                     let shortcut_costs = cheat_start_pos.manhattan_distance(cheat_end_pos);
                     (2..=cheat_length).contains(&shortcut_costs) && {
-                        let cheat_end_costs: usize = coor_to_costs[cheat_end_pos];
-                        let cheat_costs: usize = cheat_end_costs + shortcut_costs;
-                        cheat_start_costs - cheat_costs >= min_saving_time
+                        let cheat_costs = cheat_start_costs + shortcut_costs + cheat_end_costs;
+                        min_costs_without_cheat
+                            .checked_sub(cheat_costs)
+                            .is_some_and(|saving| saving >= min_saving_time)
                     }
                 })
                 .count()
         })
-        .sum()
+        .sum();
+    cheat_count
 }
@@ -1,26 +1,25 @@
 use fxhash::FxHashMap;
 use grid::grid_array::GridArray;
 use itertools::Itertools;
-use pathfinding::prelude::astar_bag;
 
-use grid::grid_types::Direction;
 use grid::grid_types::Neighborhood;
 use grid::grid_types::Topology;
+use grid::search::bfs;
 
 use crate::cache_it;
+use crate::day21_common::DoorCode;
 
 type ShortestPathCacheType = FxHashMap<(char, char), (Vec<String>, usize)>;
 
-pub(crate) fn solve(input: &str, depth: usize) -> usize {
+pub(crate) fn solve(codes: &[DoorCode], depth: usize) -> usize {
     let numeric_paths = create_numeric_paths();
     let direction_paths = create_direction_paths();
 
-    let result = input
-        .lines()
-        .map(|line| {
-            let line = line.trim_end_matches('A');
+    codes
+        .iter()
+        .map(|code| {
             let min_costs = get_min_costs(
-                line,
+                &code.keys,
                 &numeric_paths,
                 |(shortest_paths, _costs): &(Vec<String>, usize)| -> usize {
                     shortest_paths
@@ -32,10 +31,9 @@ pub(crate) fn solve(input: &str, depth: usize) -> usize {
                         .unwrap()
                 },
             );
-            min_costs * line.parse::<usize>().unwrap()
+            min_costs * code.value
         })
-        .sum::<usize>();
-    result
+        .sum()
 }
 
 pub(crate) fn create_numeric_paths() -> ShortestPathCacheType {
@@ -68,29 +66,21 @@ pub(crate) fn find_shortest_paths(grid: &GridArray<char>) -> ShortestPathCacheTy
     for ((start_coor, start), (end_coor, end)) in
         all_indexes.iter().cartesian_product(all_indexes.iter())
     {
-        let (solutions, costs) = astar_bag(
-            start_coor,
-            |coor| {
-                grid.neighborhood_cells(coor.x, coor.y)
-                    .filter(|(_neighbor_coor, neighbor_cell)| neighbor_cell != &&' ')
-                    .map(|(neighbor_coor, _neighbor_cell)| (neighbor_coor, 1))
-            },
-            |coor| coor.manhattan_distance(end_coor),
-            |coor| coor == end_coor,
-        )
-        .unwrap();
-        let solutions = solutions
+        let result = bfs(grid, start_coor, Some(end_coor), |&cell| cell != ' ');
+        let costs = result.goal_distance().unwrap();
+        let solutions = result
+            .all_paths(end_coor)
+            .iter()
             .map(|solution| {
                 solution
                     .iter()
                     .tuple_windows()
-                    .filter_map(|(coor1, coor2)| coor1.direction(coor2))
-                    .map(|dir| match dir {
-                        Direction::North => '^',
-                        Direction::South => 'v',
-                        Direction::West => '<',
-                        Direction::East => '>',
-                        _ => panic!("Invalid direction: {dir:?}"),
+                    .map(|(coor1, coor2)| {
+                        // paths of the breadth first search only make orthogonal unit steps
+                        coor1
+                            .direction(coor2)
+                            .and_then(|direction| direction.to_arrow())
+                            .unwrap_or_else(|| panic!("Invalid step from {coor1} to {coor2}"))
                     })
                     .collect::<String>()
             })
@@ -0,0 +1,28 @@
+use utils::parse_error::{ParseContext, ParseError};
+
+use crate::custom_error::AocError;
+
+/// A door code like `029A`
+#[derive(Debug, Clone)]
+pub struct DoorCode {
+    /// The keys to press before the final `A`
+    pub keys: String,
+    /// The numeric part of the code
+    pub value: usize,
+}
+
+pub fn parse(input: &str) -> Result<Vec<DoorCode>, AocError> {
+    let context = ParseContext::new(input);
+    context.check_chars(input, |ch| ch.is_ascii_digit() || ch == 'A', "a digit or 'A'")?;
+    Ok(input
+        .lines()
+        .map(|line| {
+            let keys = line.trim_end_matches('A');
+            let value = context.parse(keys, "a door code (digits followed by 'A')")?;
+            Ok(DoorCode {
+                keys: keys.to_string(),
+                value,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?)
+}
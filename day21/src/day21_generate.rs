@@ -0,0 +1,11 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// `size` door codes of three digits followed by `A`
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let codes = (0..size.max(1))
+        .map(|_| format!("{:03}A", rng.gen_range(0..1000)))
+        .collect_vec();
+    GeneratedInput::new(codes.join("\n"))
+}
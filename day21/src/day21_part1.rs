@@ -1,8 +1,14 @@
 use miette::Result;
 
+use crate::day21_common::{parse, DoorCode};
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    Ok(crate::day21::solve(input, 2).to_string())
+    solve(&parse(input)?)
+}
+
+pub fn solve(codes: &[DoorCode]) -> Result<String> {
+    Ok(crate::day21::solve(codes, 2).to_string())
 }
 
 #[cfg(test)]
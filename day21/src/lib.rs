@@ -16,5 +16,40 @@
 
 mod cache_it_macro;
 mod day21;
+pub mod custom_error;
+pub mod day21_common;
+pub mod day21_generate;
 pub mod day21_part1;
 pub mod day21_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 21: both parts type the same door codes (through 2 or 25 robots)
+pub struct Day21;
+
+impl Day for Day21 {
+    const DAY: u8 = 21;
+    type Input = Vec<day21_common::DoorCode>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day21_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day21_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day21_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day21_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day21>::PART1, &DayPart::<Day21>::PART2];
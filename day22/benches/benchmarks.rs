@@ -1,4 +1,5 @@
 use day22::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,13 +7,23 @@ fn main() {
 }
 
 #[divan::bench]
-fn day22_part1() {
-    day22_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")))
-    .unwrap();
+fn day22_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day22/input1.txt").unwrap();
+    bencher.bench(|| Day22::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day22_part2() {
-    day22_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")))
-    .unwrap();
-}
\ No newline at end of file
+fn day22_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day22/input1.txt").unwrap();
+    let input = Day22::parse(&input).unwrap();
+    let params = Day22::default_params(1);
+    bencher.bench(|| Day22::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day22_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day22/input2.txt").unwrap();
+    let input = Day22::parse(&input).unwrap();
+    let params = Day22::default_params(2);
+    bencher.bench(|| Day22::part2(divan::black_box(&input), &params).unwrap());
+}
@@ -0,0 +1,15 @@
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+pub type Secret = u32;
+
+/// Parses the initial secret number of each buyer
+pub fn parse(input: &str) -> Result<Vec<Secret>, AocError> {
+    let context = ParseContext::new(input);
+    input
+        .lines()
+        .map(|line| context.parse(line, "an initial secret number"))
+        .collect::<Result<_, _>>()
+        .map_err(AocError::from)
+}
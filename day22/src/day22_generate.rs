@@ -0,0 +1,11 @@
+use itertools::Itertools;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// `size` initial secret numbers (below 2^24)
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let secrets = (0..size.max(1))
+        .map(|_| rng.gen_range(1..1 << 24).to_string())
+        .collect_vec();
+    GeneratedInput::new(secrets.join("\n"))
+}
@@ -2,17 +2,22 @@ use miette::Result;
 use rayon::prelude::*;
 use std::iter::successors;
 
+use crate::day22_common::{parse, Secret};
+
 type NumberType = usize;
 
+/// Number of generated secrets of the real puzzle
+pub const SECRET_COUNT: usize = 2000;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let input = input
-        .lines()
-        .map(|line| line.parse::<NumberType>().unwrap());
+    solve(&parse(input)?, SECRET_COUNT)
+}
 
-    let result: usize = input
-        .par_bridge()
-        .map(|secret| gen_secrets(secret).nth(2000).unwrap())
+pub fn solve(secrets: &[Secret], secret_count: usize) -> Result<String> {
+    let result: usize = secrets
+        .par_iter()
+        .map(|&secret| gen_secrets(secret as NumberType).nth(secret_count).unwrap())
         .sum();
     Ok(result.to_string())
 }
@@ -6,23 +6,27 @@ use rayon::prelude::*;
 
 use miette::Result;
 
+use crate::day22_common::{parse, Secret};
+
 type NumberType = i32;
 type PriceType = u8;
 type ChangesType = i8;
 type SequenceType = (ChangesType, ChangesType, ChangesType, ChangesType);
 const SEQUENCE_LENGTH: usize = 4;
 
+/// Number of generated secrets of the real puzzle
+pub const SECRET_COUNT: usize = 2000;
+
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let secret_count = 2000;
-    let input = input
-        .lines()
-        .map(|line| line.parse::<NumberType>().unwrap());
+    solve(&parse(input)?, SECRET_COUNT)
+}
 
+pub fn solve(secrets: &[Secret], secret_count: usize) -> Result<String> {
     // Generate all possible changes maps in parallel
-    let all_possible_changes_map_list: Vec<_> = input
-        .par_bridge()
-        .map(|secret| get_all_possible_changes_map(secret, secret_count))
+    let all_possible_changes_map_list: Vec<_> = secrets
+        .par_iter()
+        .map(|&secret| get_all_possible_changes_map(secret.cast_signed(), secret_count))
         .collect();
 
     // Get unique sequences across all maps
@@ -14,5 +14,47 @@
     clippy::bool_to_int_with_if
 )]
 
+pub mod custom_error;
+pub mod day22_common;
+pub mod day22_generate;
 pub mod day22_part1;
 pub mod day22_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 22: both parts generate secret numbers from the same initial secrets
+pub struct Day22;
+
+impl Day for Day22 {
+    const DAY: u8 = 22;
+    type Input = Vec<day22_common::Secret>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day22_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<String> {
+        day22_part1::solve(input, params.get("secret_count")?)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<String> {
+        day22_part2::solve(input, params.get("secret_count")?)
+    }
+
+    fn default_params(part: u8) -> Params {
+        match part {
+            1 => Params::from([("secret_count", day22_part1::SECRET_COUNT)]),
+            _ => Params::from([("secret_count", day22_part2::SECRET_COUNT)]),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day22_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day22>::PART1, &DayPart::<Day22>::PART2];
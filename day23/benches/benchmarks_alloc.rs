@@ -1,23 +1,33 @@
 use day23::*;
+use utils::solution::Day;
 use divan::AllocProfiler;
 
 #[global_allocator]
 static ALLOC: AllocProfiler = AllocProfiler::system();
 
-
 fn main() {
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
-fn day23_part1() {
-    day23_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")))
-    .unwrap();
+fn day23_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day23/input1.txt").unwrap();
+    bencher.bench(|| Day23::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day23_part2() {
-    day23_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")))
-    .unwrap();
-}
\ No newline at end of file
+fn day23_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day23/input1.txt").unwrap();
+    let input = Day23::parse(&input).unwrap();
+    let params = Day23::default_params(1);
+    bencher.bench(|| Day23::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day23_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day23/input2.txt").unwrap();
+    let input = Day23::parse(&input).unwrap();
+    let params = Day23::default_params(2);
+    bencher.bench(|| Day23::part2(divan::black_box(&input), &params).unwrap());
+}
@@ -0,0 +1,13 @@
+use utils::parse::{edge_list, parse_all};
+
+use crate::custom_error::AocError;
+
+/// Connection between two computers, e.g. `kh-tc`
+pub type Edge = (String, String);
+
+pub fn parse(input: &str) -> Result<Vec<Edge>, AocError> {
+    Ok(parse_all(input, edge_list('-'))?
+        .into_iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect())
+}
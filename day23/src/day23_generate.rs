@@ -0,0 +1,41 @@
+use itertools::Itertools;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// A network of `size` computers (4 to 676) with two letter names; every computer is connected
+/// to about three others and a random LAN party of 4 to 6 computers is fully connected
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let mut names = (b'a'..=b'z')
+        .cartesian_product(b'a'..=b'z')
+        .map(|(first, second)| format!("{}{}", char::from(first), char::from(second)))
+        .collect_vec();
+    names.shuffle(rng);
+    names.truncate(size.clamp(4, names.len()));
+
+    let mut edges = (0..names.len())
+        .flat_map(|computer| {
+            (0..3)
+                .map(|_| rng.gen_range(0..names.len()))
+                .filter(move |&other| other != computer)
+                .map(move |other| (computer.min(other), computer.max(other)))
+                .collect_vec()
+        })
+        .collect_vec();
+    let party_size = rng.gen_range(4..=6).min(names.len());
+    let party = index::sample(rng, names.len(), party_size).into_vec();
+    edges.extend(
+        party
+            .into_iter()
+            .tuple_combinations()
+            .map(|(a, b)| (a.min(b), a.max(b))),
+    );
+
+    let mut lines = edges
+        .into_iter()
+        .unique()
+        .map(|(a, b)| format!("{}-{}", names[a], names[b]))
+        .collect_vec();
+    lines.shuffle(rng);
+    GeneratedInput::new(lines.join("\n"))
+}
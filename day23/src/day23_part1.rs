@@ -4,11 +4,16 @@ use rayon::prelude::*;
 
 use miette::Result;
 
-#[allow(clippy::similar_names)]
+use crate::day23_common::{parse, Edge};
+
 pub fn process(input: &str) -> Result<String> {
+    solve(&parse(input)?)
+}
+
+#[allow(clippy::similar_names)]
+pub fn solve(edges: &[Edge]) -> Result<String> {
     let mut nodes_with_edges: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
-    for line in input.lines() {
-        let (a, b) = line.split_once('-').unwrap();
+    for (a, b) in edges {
         nodes_with_edges.entry(a).or_default().push(b);
         nodes_with_edges.entry(b).or_default().push(a);
     }
@@ -6,20 +6,33 @@ use miette::Result;
 use rayon::prelude::*;
 use utils::cache_it_with_fxhashmap;
 
+use crate::day23_common::{parse, Edge};
+
 type NodeSet<'a> = FxHashSet<&'a str>;
 type NodesWithEdges<'a> = FxHashMap<&'a str, Vec<&'a str>>;
 
 pub fn process(input: &str) -> Result<String> {
-    let nodes_with_edges = create_nodes_with_edges(input);
-    let largest_node_set = largest_node_set(NodeSet::default(), &nodes_with_edges);
+    solve(&parse(input)?)
+}
+
+pub fn solve(edges: &[Edge]) -> Result<String> {
+    let nodes_with_edges = create_nodes_with_edges(edges);
+    let input_fingerprint = fxhash::hash64(edges);
+    let largest_node_set =
+        largest_node_set(NodeSet::default(), &nodes_with_edges, input_fingerprint);
     Ok(largest_node_set.1)
 }
 
-fn largest_node_set(node_set: NodeSet, nodes_with_edges: &NodesWithEdges) -> (usize, String) {
+/// The cache is keyed by the edges' fingerprint as well, so different inputs don't share results
+fn largest_node_set(
+    node_set: NodeSet,
+    nodes_with_edges: &NodesWithEdges,
+    input_fingerprint: u64,
+) -> (usize, String) {
     cache_it_with_fxhashmap!(
-        String,
+        (u64, String),
         (usize, String),
-        node_set.iter().sorted().join(","),
+        (input_fingerprint, node_set.iter().sorted().join(",")),
         {
             nodes_with_edges
                 .keys()
@@ -28,7 +41,7 @@ fn largest_node_set(node_set: NodeSet, nodes_with_edges: &NodesWithEdges) -> (us
                 .filter(|&node| is_connected(node, &node_set, nodes_with_edges))
                 .map(|&node| {
                     let new_node_set = node_set.iter().copied().chain(once(node)).collect();
-                    largest_node_set(new_node_set, nodes_with_edges)
+                    largest_node_set(new_node_set, nodes_with_edges, input_fingerprint)
                 })
                 .max_by_key(|(size, _node_set_as_str)| *size)
                 .unwrap_or_else(|| (node_set.len(), node_set.into_iter().sorted().join(",")))
@@ -43,10 +56,9 @@ fn is_connected(node: &str, node_set: &NodeSet, nodes_with_edges: &NodesWithEdge
         .all(|node2| nodes_with_edges[node].contains(node2))
 }
 
-fn create_nodes_with_edges(input: &str) -> NodesWithEdges {
+fn create_nodes_with_edges(edges: &[Edge]) -> NodesWithEdges<'_> {
     let mut nodes_with_edges: NodesWithEdges = FxHashMap::default();
-    for line in input.lines() {
-        let (a, b) = line.split_once('-').unwrap();
+    for (a, b) in edges {
         nodes_with_edges.entry(a).or_default().push(b);
         nodes_with_edges.entry(b).or_default().push(a);
     }
@@ -14,5 +14,40 @@
     clippy::bool_to_int_with_if
 )]
 
+pub mod custom_error;
+pub mod day23_common;
+pub mod day23_generate;
 pub mod day23_part1;
 pub mod day23_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 23: both parts search sets of connected computers in the same network
+pub struct Day23;
+
+impl Day for Day23 {
+    const DAY: u8 = 23;
+    type Input = Vec<day23_common::Edge>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day23_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day23_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day23_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day23_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day23>::PART1, &DayPart::<Day23>::PART2];
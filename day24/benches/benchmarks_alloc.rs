@@ -1,23 +1,33 @@
 use day24::*;
+use utils::solution::Day;
 use divan::AllocProfiler;
 
 #[global_allocator]
 static ALLOC: AllocProfiler = AllocProfiler::system();
 
-
 fn main() {
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
-fn day24_part1() {
-    day24_part1::process(divan::black_box(&include_str!("../input1.txt").replace('\r', "")))
-    .unwrap();
+fn day24_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day24/input1.txt").unwrap();
+    bencher.bench(|| Day24::parse(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn day24_part2() {
-    day24_part2::process(divan::black_box(&include_str!("../input2.txt").replace('\r', "")))
-    .unwrap();
-}
\ No newline at end of file
+fn day24_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day24/input1.txt").unwrap();
+    let input = Day24::parse(&input).unwrap();
+    let params = Day24::default_params(1);
+    bencher.bench(|| Day24::part1(divan::black_box(&input), &params).unwrap());
+}
+
+#[divan::bench]
+fn day24_part2(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day24/input2.txt").unwrap();
+    let input = Day24::parse(&input).unwrap();
+    let params = Day24::default_params(2);
+    bencher.bench(|| Day24::part2(divan::black_box(&input), &params).unwrap());
+}
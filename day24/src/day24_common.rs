@@ -1,15 +1,18 @@
 use std::{
     fmt::Display,
     mem::swap,
-    str::FromStr,
     sync::{LazyLock, RwLock},
 };
 
 use fxhash::FxHashMap;
 use itertools::Itertools;
+use utils::parse_error::{ParseContext, ParseError};
 
+use crate::custom_error::AocError;
+
+/// The initial wire values and the gates of the circuit
 #[derive(Debug, Clone)]
-pub(crate) struct Puzzle {
+pub struct Puzzle {
     pub(crate) wire_values: FxHashMap<Wire, bool>,
     pub(crate) ops: FxHashMap<Wire, Expr>,
 }
@@ -32,7 +35,8 @@ pub(crate) enum Wire {
     InputX(u8),
     InputY(u8),
     OutputZ(u8),
-    Other(u8),
+    // id of the name in INTERIM_WIRE_NAME_MAP, which is shared by all parsed inputs
+    Other(u16),
 }
 
 impl PartialOrd for Wire {
@@ -46,8 +50,8 @@ impl Ord for Wire {
         match (self, other) {
             (Wire::InputX(a), Wire::InputX(b))
             | (Wire::InputY(a), Wire::InputY(b))
-            | (Wire::OutputZ(a), Wire::OutputZ(b))
-            | (Wire::Other(a), Wire::Other(b)) => a.cmp(b),
+            | (Wire::OutputZ(a), Wire::OutputZ(b)) => a.cmp(b),
+            (Wire::Other(a), Wire::Other(b)) => a.cmp(b),
 
             (Wire::InputY(_), Wire::InputX(_))
             | (Wire::OutputZ(_), Wire::InputY(_))
@@ -60,21 +64,19 @@ impl Ord for Wire {
     }
 }
 
-static INTERIM_WIRE_NAME_MAP: LazyLock<RwLock<FxHashMap<String, u8>>> =
+static INTERIM_WIRE_NAME_MAP: LazyLock<RwLock<FxHashMap<String, u16>>> =
     LazyLock::new(|| RwLock::new(FxHashMap::default()));
 
-impl FromStr for Wire {
-    type Err = ();
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+impl Wire {
+    fn parse(context: &ParseContext, s: &str) -> Result<Self, ParseError> {
         if let Some(remaining_str) = s.strip_prefix('x') {
-            Ok(Wire::InputX(remaining_str.parse().unwrap()))
+            Ok(Wire::InputX(context.parse(remaining_str, "a wire number")?))
         } else if let Some(remaining_str) = s.strip_prefix('y') {
-            Ok(Wire::InputY(remaining_str.parse().unwrap()))
+            Ok(Wire::InputY(context.parse(remaining_str, "a wire number")?))
         } else if let Some(remaining_str) = s.strip_prefix('z') {
-            Ok(Wire::OutputZ(remaining_str.parse().unwrap()))
+            Ok(Wire::OutputZ(context.parse(remaining_str, "a wire number")?))
         } else {
-            let id: u8 = {
+            let id: u16 = {
                 let key = s.to_string();
                 let mut map = INTERIM_WIRE_NAME_MAP.write().unwrap();
                 if let Some(id) = map.get(&key) {
@@ -102,7 +104,7 @@ impl Display for Wire {
                     .find(|(_name, id)| id == &num)
                     .unwrap()
                     .0
-                    .to_string()
+                    .clone()
             }
         })
     }
@@ -167,38 +169,45 @@ impl Expr {
     }
 }
 
-impl FromStr for Puzzle {
-    type Err = ();
-
-    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
-        let (initial, connections) = input.split_once("\n\n").unwrap();
+impl Puzzle {
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let context = ParseContext::new(input);
+        let (initial, connections) = context.split_once(input, "\n\n")?;
         let wire_values = initial
             .lines()
             .map(|line| {
-                let (left, right) = line.split_once(": ").unwrap();
-                (Wire::from_str(left).unwrap(), right == "1")
+                let (left, right) = context.split_once(line, ": ")?;
+                let value = match right {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(context.error(right, "'0' or '1'")),
+                };
+                Ok((Wire::parse(&context, left)?, value))
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
         let ops = connections
             .lines()
             .map(|line| {
-                let (input, output) = line.split_once(" -> ").unwrap();
-                let (lhs_str, op_str, rhs_str) = input.split_whitespace().collect_tuple().unwrap();
+                let (input, output) = context.split_once(line, " -> ")?;
+                let (lhs_str, op_str, rhs_str) = input
+                    .split_whitespace()
+                    .collect_tuple()
+                    .ok_or_else(|| context.error(input, "an expression like 'x00 AND y00'"))?;
                 let op = match op_str {
                     "AND" => Op::And,
                     "OR" => Op::Or,
                     "XOR" => Op::Xor,
-                    _ => panic!("at the disco"),
+                    _ => return Err(context.error(op_str, "'AND', 'OR' or 'XOR'")),
                 };
 
-                let mut lhs = Wire::from_str(lhs_str).unwrap();
-                let mut rhs = Wire::from_str(rhs_str).unwrap();
+                let mut lhs = Wire::parse(&context, lhs_str)?;
+                let mut rhs = Wire::parse(&context, rhs_str)?;
                 if lhs > rhs {
                     swap(&mut lhs, &mut rhs);
                 }
-                (Wire::from_str(output).unwrap(), Expr { op, lhs, rhs })
+                Ok((Wire::parse(&context, output)?, Expr { op, lhs, rhs }))
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
         Ok(Puzzle { wire_values, ops })
     }
 }
@@ -0,0 +1,135 @@
+use itertools::Itertools;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// One gate of the netlist: `lhs op rhs -> output`
+struct Gate {
+    lhs: String,
+    op: &'static str,
+    rhs: String,
+    output: String,
+}
+
+/// A ripple carry adder of `size` bits (12 to 60) with random initial values of x and y
+///
+/// The outputs of four pairs of gates are swapped, each pair inside the full adder of another bit,
+/// like in the real puzzle input (part 2 finds the swapped outputs).
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let bits = size.clamp(12, 60);
+    // interim wires have three letter names, but must not start with x, y or z
+    let mut names = index::sample(rng, 23 * 26 * 26, 4 * bits)
+        .into_iter()
+        .map(|index| {
+            let letter = |offset: usize| char::from(b'a' + u8::try_from(offset).unwrap());
+            format!(
+                "{}{}{}",
+                letter(index / 676),
+                letter(index / 26 % 26),
+                letter(index % 26)
+            )
+        })
+        .collect_vec()
+        .into_iter();
+    let mut interim_name = || names.next().unwrap();
+
+    let gate = |lhs: &str, op, rhs: &str, output: &str| Gate {
+        lhs: lhs.to_string(),
+        op,
+        rhs: rhs.to_string(),
+        output: output.to_string(),
+    };
+    // the gates of every bit: sum, output, direct carry, recarry and carry
+    let mut gates = vec![
+        gate("x00", "XOR", "y00", "z00"),
+        gate("x00", "AND", "y00", &interim_name()),
+    ];
+    let mut bit_gates = Vec::new();
+    for bit in 1..bits {
+        let carry = gates.last().unwrap().output.clone();
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let sum = interim_name();
+        let direct_carry = interim_name();
+        let recarry = interim_name();
+        let next_carry = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            interim_name()
+        };
+        bit_gates.push(gates.len());
+        gates.extend([
+            gate(&x, "XOR", &y, &sum),
+            gate(&sum, "XOR", &carry, &z),
+            gate(&x, "AND", &y, &direct_carry),
+            gate(&sum, "AND", &carry, &recarry),
+            gate(&direct_carry, "OR", &recarry, &next_carry),
+        ]);
+    }
+
+    swap_outputs(rng, &mut gates, &bit_gates);
+
+    let initial_values = ['x', 'y']
+        .into_iter()
+        .flat_map(|wire| (0..bits).map(move |bit| format!("{wire}{bit:02}")))
+        .map(|wire| format!("{wire}: {}", u8::from(rng.gen_bool(0.5))))
+        .collect_vec();
+    gates.shuffle(rng);
+    let connections = gates
+        .into_iter()
+        .map(
+            |Gate {
+                 lhs,
+                 op,
+                 rhs,
+                 output,
+             }| {
+                let (lhs, rhs) = if rng.gen_bool(0.5) {
+                    (lhs, rhs)
+                } else {
+                    (rhs, lhs)
+                };
+                format!("{lhs} {op} {rhs} -> {output}")
+            },
+        )
+        .collect_vec();
+    GeneratedInput::new(format!(
+        "{}\n\n{}",
+        initial_values.join("\n"),
+        connections.join("\n")
+    ))
+}
+
+/// Swaps the outputs of two gates in the full adders of four bits (at least two apart);
+/// `bit_gates` are the indexes of the first gate of every full adder (starting with bit 1)
+fn swap_outputs(rng: &mut StdRng, gates: &mut [Gate], bit_gates: &[usize]) {
+    let bits = bit_gates.len() + 1;
+    let mut swapped_bits: Vec<usize> = Vec::new();
+    while swapped_bits.len() < 4 {
+        let bit = rng.gen_range(2..bits - 1);
+        if swapped_bits
+            .iter()
+            .all(|swapped_bit| swapped_bit.abs_diff(bit) >= 2)
+        {
+            swapped_bits.push(bit);
+        }
+    }
+    for bit in swapped_bits {
+        let first_gate = bit_gates[bit - 1];
+        let (sum, output, direct_carry, recarry, carry) = (0, 1, 2, 3, 4);
+        let (a, b) = *[
+            (output, carry),
+            (output, recarry),
+            (output, direct_carry),
+            (sum, direct_carry),
+        ]
+        .choose(rng)
+        .unwrap();
+        let output_a = gates[first_gate + a].output.clone();
+        let output_b = std::mem::replace(&mut gates[first_gate + b].output, output_a);
+        gates[first_gate + a].output = output_b;
+    }
+}
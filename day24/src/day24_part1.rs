@@ -6,13 +6,16 @@ use crate::day24_common::{Puzzle, Wire};
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let puzzle: Puzzle = input.parse().unwrap();
+    solve(&Puzzle::parse(input)?)
+}
+
+pub fn solve(puzzle: &Puzzle) -> Result<String> {
     let result = puzzle
         .all_wires()
         .filter(|wire| matches!(wire, Wire::OutputZ(_)))
         .sorted_unstable_by(|a, b| b.cmp(a))
         .fold(0usize, |acc, wire| {
-            acc * 2 + usize::from(wire.value_of(&puzzle).unwrap())
+            acc * 2 + usize::from(wire.value_of(puzzle).unwrap())
         });
 
     Ok(result.to_string())
@@ -91,6 +94,15 @@ tnw OR pbm -> gnj";
         Ok(())
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = Puzzle::parse("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00").unwrap_err();
+        assert_eq!(
+            "Parse error at line 4, column 5: expected 'AND', 'OR' or 'XOR'",
+            err.to_string()
+        );
+    }
+
     #[test]
     fn test_input() -> miette::Result<()> {
         let input = include_str!("../input1.txt");
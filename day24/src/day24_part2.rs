@@ -5,7 +5,11 @@ use miette::Result;
 use crate::day24_common::{Expr, Op, Puzzle, Wire};
 
 pub fn process(input: &str) -> Result<String> {
-    let mut puzzle: Puzzle = input.parse().unwrap();
+    solve(&Puzzle::parse(input)?)
+}
+
+pub fn solve(puzzle: &Puzzle) -> Result<String> {
+    let mut puzzle = puzzle.clone();
     let mut swaps = Vec::new();
 
     let all_wires: Vec<Wire> = puzzle.all_wires().collect();
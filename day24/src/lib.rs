@@ -14,6 +14,40 @@
     clippy::bool_to_int_with_if
 )]
 
-mod day24_common;
+pub mod custom_error;
+pub mod day24_common;
+pub mod day24_generate;
 pub mod day24_part1;
 pub mod day24_part2;
+
+use miette::Result;
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 24: both parts evaluate the same circuit of logic gates
+pub struct Day24;
+
+impl Day for Day24 {
+    const DAY: u8 = 24;
+    type Input = day24_common::Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day24_common::Puzzle::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day24_part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<String> {
+        day24_part2::solve(input)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day24_generate::generate(rng, size)
+    }
+}
+
+/// Both parts of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day24>::PART1, &DayPart::<Day24>::PART2];
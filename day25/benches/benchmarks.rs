@@ -1,4 +1,5 @@
 use day25::*;
+use utils::solution::Day;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,9 +7,15 @@ fn main() {
 }
 
 #[divan::bench]
-fn day25_part1() {
-    day25_part1::process(divan::black_box(
-        &include_str!("../input1.txt").replace('\r', ""),
-    ))
-    .unwrap();
+fn day25_parse(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day25/input1.txt").unwrap();
+    bencher.bench(|| Day25::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn day25_part1(bencher: divan::Bencher) {
+    let input = utils::input::read_input_file("day25/input1.txt").unwrap();
+    let input = Day25::parse(&input).unwrap();
+    let params = Day25::default_params(1);
+    bencher.bench(|| Day25::part1(divan::black_box(&input), &params).unwrap());
 }
@@ -0,0 +1,54 @@
+use itertools::Itertools;
+use utils::parse_error::ParseContext;
+
+use crate::custom_error::AocError;
+
+fn transpose(matrix: Vec<Vec<char>>) -> Vec<Vec<char>> {
+    if matrix.is_empty() {
+        return vec![];
+    }
+    let row_count = matrix.len();
+    let col_count = matrix[0].len();
+    (0..col_count)
+        .map(|i| (0..row_count).map(|j| matrix[j][i]).collect())
+        .collect()
+}
+
+pub type Heights = Vec<usize>;
+
+/// The column heights of the locks and keys
+#[derive(Debug, Clone)]
+pub struct Schematics {
+    pub locks: Vec<Heights>,
+    pub keys: Vec<Heights>,
+}
+
+pub fn parse(input: &str) -> Result<Schematics, AocError> {
+    let context = ParseContext::new(input);
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+    for block in input.split("\n\n") {
+        let first_line = block.lines().next().unwrap_or(block);
+        let is_lock = match first_line {
+            "#####" => true,
+            "....." => false,
+            _ => return Err(context.error(first_line, "'#####' or '.....'").into()),
+        };
+        context.check_chars(block, |ch| ch == '#' || ch == '.', "'#' or '.'")?;
+        let info = transpose(
+            block
+                .lines()
+                .map(|line| line.chars().collect_vec())
+                .collect_vec(),
+        )
+        .into_iter()
+        .map(|row| row.into_iter().filter(|ch| ch == &'#').count() - 1)
+        .collect_vec();
+        if is_lock {
+            locks.push(info);
+        } else {
+            keys.push(info);
+        }
+    }
+    Ok(Schematics { locks, keys })
+}
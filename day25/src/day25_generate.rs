@@ -0,0 +1,42 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use utils::generate::{GeneratedInput, StdRng};
+
+/// Number of columns of a schematic
+const COLUMNS: usize = 5;
+/// Number of rows of a schematic
+const ROWS: usize = 7;
+
+/// `size` locks and `size` keys with random heights, in random order
+pub fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+    let mut schematics = (0..2 * size.max(1))
+        .map(|index| {
+            let is_lock = index % 2 == 0;
+            let heights = (0..COLUMNS)
+                .map(|_| rng.gen_range(0..ROWS - 1))
+                .collect_vec();
+            (0..ROWS)
+                .map(|row| {
+                    heights
+                        .iter()
+                        .map(|&height| {
+                            let filled = if is_lock {
+                                row <= height
+                            } else {
+                                ROWS - 1 - row <= height
+                            };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .join("\n")
+        })
+        .collect_vec();
+    schematics.shuffle(rng);
+    GeneratedInput::new(schematics.join("\n\n"))
+}
@@ -5,54 +5,19 @@ use rayon::prelude::*;
 
 use miette::{miette, Error, Result};
 
-fn transpose(matrix: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    if matrix.is_empty() {
-        return vec![];
-    }
-    let row_count = matrix.len();
-    let col_count = matrix[0].len();
-    (0..col_count)
-        .map(|i| (0..row_count).map(|j| matrix[j][i]).collect())
-        .collect()
-}
+use crate::day25_common::{parse, Schematics};
 
 //#[tracing::instrument]
 pub fn process(input: &str) -> Result<String> {
-    let mut locks = Vec::new();
-    let mut keys = Vec::new();
-    input.split("\n\n").for_each(|block| {
-        let mut lines = block.lines();
-        let is_lock = match lines.next().unwrap() {
-            "#####" => true,
-            "....." => false,
-            _ => panic!("Invalid block {}", block),
-        };
-        let info = transpose(
-            block
-                .lines()
-                .map(|line| line.chars().collect_vec())
-                .collect_vec(),
-        )
-        .into_iter()
-        .map(|row| row.into_iter().filter(|ch| ch == &'#').count() - 1)
-        .collect_vec();
-        if is_lock {
-            locks.push(info);
-        } else {
-            keys.push(info);
-        }
-    });
+    solve(&parse(input)?)
+}
 
-    println!(
-        "{}*{}={}",
-        locks.len(),
-        keys.len(),
-        locks.len() * keys.len()
-    );
+pub fn solve(schematics: &Schematics) -> Result<String> {
+    let Schematics { locks, keys } = schematics;
 
     let result: usize = locks
-        .into_par_iter()
-        .map(|lock| keys.iter().filter(|key| fits(&lock, key)).count())
+        .par_iter()
+        .map(|lock| keys.iter().filter(|key| fits(lock, key)).count())
         .sum();
 
     Ok(result.to_string())
@@ -111,6 +76,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_error() {
+        let err = parse("#####\n.#.#.\n\n.....\n.#x..\n#####").unwrap_err();
+        assert_eq!(
+            "Parse error at line 5, column 3: expected '#' or '.'",
+            err.to_string()
+        );
+    }
+
     #[test]
     fn test_input() -> miette::Result<()> {
         let input = include_str!("../input1.txt");
@@ -14,4 +14,39 @@
     clippy::bool_to_int_with_if
 )]
 
+pub mod custom_error;
+pub mod day25_common;
+pub mod day25_generate;
 pub mod day25_part1;
+
+use miette::{miette, Result};
+use utils::generate::{GeneratedInput, StdRng};
+use utils::params::Params;
+use utils::solution::{Day, DayPart, Solution};
+
+/// Day 25: matches the keys against the locks (there is no second part)
+pub struct Day25;
+
+impl Day for Day25 {
+    const DAY: u8 = 25;
+    type Input = day25_common::Schematics;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(day25_common::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<String> {
+        day25_part1::solve(input)
+    }
+
+    fn part2(_input: &Self::Input, _params: &Params) -> Result<String> {
+        Err(miette!("Day 25 has no second part"))
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput {
+        day25_generate::generate(rng, size)
+    }
+}
+
+/// The part of the day, registered for the `aoc` runner
+pub static SOLUTIONS: &[&dyn Solution] = &[&DayPart::<Day25>::PART1];
@@ -0,0 +1,424 @@
+//! Cellular automata on grids
+//!
+//! An [`Automaton`] computes the next generation of a grid from the current one with a rule.
+//! The storage of a generation implements [`Generation`]: a [`GridArray`] applies any rule to
+//! every cell and the cells of its neighborhood (based on the topology and neighborhood of the
+//! grid), a [`BitGrid`] applies a [`LifeRule`] to 64 cells at a time by counting the neighbors
+//! with shifted grids. The next generation is written into a second buffer which is then swapped
+//! with the grid. Steps on a [`GridArray`] can be distributed over the rows with rayon. Repeated
+//! generations are detected, and the [`Generations`] tell the grid of any later generation.
+
+use std::hash::Hash;
+use std::mem::swap;
+
+use fxhash::FxHashMap;
+use rayon::prelude::*;
+use utils::cycle::Cycle;
+
+use crate::bit_grid::BitGrid;
+use crate::grid_array::GridArray;
+use crate::grid_iteration::all_adjacent_directions;
+
+/// Storage of one generation of an automaton which computes the next generation under rule `R`
+pub trait Generation<R>: Clone {
+    /// Writes the generation following `self` into `next` (a grid of the same size)
+    fn next_generation(&self, rule: &R, next: &mut Self);
+}
+
+impl<T: Default + Clone, R: Fn(&T, &[&T]) -> T> Generation<R> for GridArray<T> {
+    fn next_generation(&self, rule: &R, next: &mut Self) {
+        next.data_mut()
+            .chunks_mut(self.width().max(1))
+            .enumerate()
+            .for_each(|(y, row)| step_row(self, rule, y, row));
+    }
+}
+
+/// Rule of a Life-like automaton on bits: a dead cell is born and a live cell survives for the
+/// given numbers of live neighbors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LifeRule {
+    /// bit `n` is set if a dead cell with `n` live neighbors is born
+    birth: u16,
+    /// bit `n` is set if a live cell with `n` live neighbors survives
+    survival: u16,
+}
+
+impl LifeRule {
+    /// Conway's Game of Life (B3/S23)
+    pub const CONWAY: Self = LifeRule {
+        birth: 1 << 3,
+        survival: 1 << 2 | 1 << 3,
+    };
+
+    /// Rule with the neighbor counts for birth and survival (from 0 to 8)
+    ///
+    /// # Panics
+    /// If a count is above 8
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let mask = |counts: &[usize]| {
+            counts.iter().fold(0, |mask, &count| {
+                assert!(count <= 8, "a cell has at most 8 neighbors, not {count}");
+                mask | 1 << count
+            })
+        };
+        LifeRule {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+}
+
+impl Generation<LifeRule> for BitGrid {
+    fn next_generation(&self, rule: &LifeRule, next: &mut Self) {
+        // the neighbor counts of all cells as binary numbers, one grid per bit
+        let mut count_bits: Vec<BitGrid> = Vec::with_capacity(4);
+        for direction in all_adjacent_directions(self.get_neighborhood()) {
+            let mut carry = self.shifted(direction);
+            for bit in &mut count_bits {
+                let next_carry = &*bit & &carry;
+                *bit ^= &carry;
+                carry = next_carry;
+            }
+            if carry.any() {
+                count_bits.push(carry);
+            }
+        }
+        let dead = !self;
+        next.fill(false);
+        // counts which need more bits than the largest count has do not occur
+        for count in (0..=8).filter(|count| count >> count_bits.len() == 0) {
+            let with_count = match ((rule.birth >> count) & 1, (rule.survival >> count) & 1) {
+                (0, 0) => continue,
+                (1, 0) => dead.clone(),
+                (0, _) => self.clone(),
+                _ => !&BitGrid::new(
+                    self.get_topology(),
+                    self.get_neighborhood(),
+                    self.width(),
+                    self.height(),
+                ),
+            };
+            let with_count = count_bits.iter().enumerate().fold(
+                with_count,
+                |mut with_count, (bit, count_bit)| {
+                    if (count >> bit) & 1 == 1 {
+                        with_count &= count_bit;
+                    } else {
+                        with_count &= &!count_bit;
+                    }
+                    with_count
+                },
+            );
+            *next |= &with_count;
+        }
+    }
+}
+
+/// A grid and a rule computing the next generation
+pub struct Automaton<G, R> {
+    grid: G,
+    buffer: G,
+    rule: R,
+    generation: usize,
+}
+
+/// Generations of an automaton from the search until the first repetition
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Generations<G> {
+    /// prefix and period of the generations, counted from the one where the search started
+    pub cycle: Cycle,
+    first_generation: usize,
+    states: Vec<G>,
+}
+
+impl<G> Generations<G> {
+    /// the generation where the search started
+    pub fn first_generation(&self) -> usize {
+        self.first_generation
+    }
+
+    /// the first generation which repeats
+    pub fn cycle_start(&self) -> usize {
+        self.first_generation + self.cycle.prefix
+    }
+
+    /// does the automaton stop changing
+    pub fn is_fixed_point(&self) -> bool {
+        self.cycle.period == 1
+    }
+
+    /// The grid of any generation from the one where the search started (`None` for earlier
+    /// generations)
+    pub fn state_at(&self, generation: usize) -> Option<&G> {
+        let steps = generation.checked_sub(self.first_generation)?;
+        self.states.get(self.cycle.equivalent_step(steps))
+    }
+}
+
+impl<G: Generation<R>, R> Automaton<G, R> {
+    /// Automaton starting with `grid` (generation 0)
+    pub fn new(grid: G, rule: R) -> Self {
+        Automaton {
+            buffer: grid.clone(),
+            grid,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// the current generation
+    pub fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    /// the current generation (consuming the automaton)
+    pub fn into_grid(self) -> G {
+        self.grid
+    }
+
+    /// number of steps so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation
+    pub fn step(&mut self) {
+        self.grid.next_generation(&self.rule, &mut self.buffer);
+        self.finish_step();
+    }
+
+    /// Computes `steps` generations
+    pub fn steps(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Steps until a generation equals the previous one (at most `max_steps` times) and returns
+    /// the generation from which on the grid does not change
+    pub fn run_until_stable(&mut self, max_steps: usize) -> Option<usize>
+    where
+        G: PartialEq,
+    {
+        for _ in 0..max_steps {
+            self.step();
+            if self.grid == self.buffer {
+                return Some(self.generation - 1);
+            }
+        }
+        None
+    }
+
+    /// Steps until a generation repeats an earlier one (at most `max_steps` times)
+    ///
+    /// All generations since the call are kept to look up later generations in the
+    /// [`Generations`].
+    pub fn find_cycle(&mut self, max_steps: usize) -> Option<Generations<G>>
+    where
+        G: Eq + Hash,
+    {
+        let first_generation = self.generation;
+        let mut seen = FxHashMap::default();
+        seen.insert(self.grid.clone(), first_generation);
+        for _ in 0..max_steps {
+            self.step();
+            if let Some(&start) = seen.get(&self.grid) {
+                let mut states: Vec<(usize, G)> = seen
+                    .into_iter()
+                    .map(|(grid, generation)| (generation, grid))
+                    .collect();
+                states.sort_unstable_by_key(|(generation, _grid)| *generation);
+                return Some(Generations {
+                    cycle: Cycle {
+                        prefix: start - first_generation,
+                        period: self.generation - start,
+                    },
+                    first_generation,
+                    states: states.into_iter().map(|(_generation, grid)| grid).collect(),
+                });
+            }
+            seen.insert(self.grid.clone(), self.generation);
+        }
+        None
+    }
+
+    fn finish_step(&mut self) {
+        swap(&mut self.grid, &mut self.buffer);
+        self.generation += 1;
+    }
+}
+
+impl<T: Default + Clone + Send + Sync, R: Fn(&T, &[&T]) -> T + Sync> Automaton<GridArray<T>, R> {
+    /// Computes the next generation, distributing the rows over the rayon thread pool
+    pub fn par_step(&mut self) {
+        let (grid, rule) = (&self.grid, &self.rule);
+        self.buffer
+            .data_mut()
+            .par_chunks_mut(grid.width().max(1))
+            .enumerate()
+            .for_each(|(y, row)| step_row(grid, rule, y, row));
+        self.finish_step();
+    }
+}
+
+fn step_row<T: Default + Clone>(
+    grid: &GridArray<T>,
+    rule: impl Fn(&T, &[&T]) -> T,
+    y: usize,
+    row: &mut [T],
+) {
+    let mut neighbors = Vec::with_capacity(8);
+    for (x, cell) in row.iter_mut().enumerate() {
+        neighbors.clear();
+        neighbors.extend(grid.neighborhood_cells(x, y).map(|(_coor, cell)| cell));
+        *cell = rule(grid.get_unchecked(x, y), &neighbors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::{Neighborhood, Topology};
+    use crate::test_util::grid;
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature of a rule
+    fn life(cell: &char, neighbors: &[&char]) -> char {
+        match (cell, neighbors.iter().filter(|&&&cell| cell == '#').count()) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let mut blinker = Automaton::new(
+            grid(
+                Topology::Bounded,
+                Neighborhood::Square,
+                ".....\n..#..\n..#..\n..#..\n.....\n",
+            ),
+            life,
+        );
+        blinker.step();
+        assert_eq!(
+            ".....\n.....\n.###.\n.....\n.....\n",
+            blinker.grid().to_string()
+        );
+        blinker.par_step();
+        assert_eq!(
+            ".....\n..#..\n..#..\n..#..\n.....\n",
+            blinker.grid().to_string()
+        );
+        assert_eq!(2, blinker.generation());
+    }
+
+    #[test]
+    fn test_parallel_step() {
+        let start = grid(
+            Topology::Torus,
+            Neighborhood::Square,
+            ".#....\n..#...\n###...\n......\n....#.\n...##.\n",
+        );
+        let mut sequential = Automaton::new(start.clone(), life);
+        let mut parallel = Automaton::new(start, life);
+        for _ in 0..10 {
+            sequential.step();
+            parallel.par_step();
+            assert_eq!(sequential.grid(), parallel.grid());
+        }
+    }
+
+    #[test]
+    fn test_bit_step() {
+        for topology in [Topology::Bounded, Topology::Torus] {
+            let start = grid(
+                topology,
+                Neighborhood::Square,
+                ".#.....\n..#..#.\n###..#.\n.....#.\n##.....\n##..#..\n",
+            );
+            let bits = BitGrid::from_grid_array(&start, |&cell| cell == '#');
+            let mut cells = Automaton::new(start, life);
+            let mut bits = Automaton::new(bits, LifeRule::CONWAY);
+            for _ in 0..12 {
+                cells.step();
+                bits.step();
+                assert_eq!(cells.grid().to_string(), bits.grid().to_string());
+            }
+        }
+        // cells with one to four live orthogonal neighbors live (B1234/S1234), others die
+        let seed = grid(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            "...\n.#.\n...\n",
+        );
+        let mut flood = Automaton::new(
+            BitGrid::from_grid_array(&seed, |&cell| cell == '#'),
+            LifeRule::new(&[1, 2, 3, 4], &[1, 2, 3, 4]),
+        );
+        flood.step();
+        assert_eq!(".#.\n#.#\n.#.\n", flood.grid().to_string());
+        flood.step();
+        assert_eq!("#.#\n.#.\n#.#\n", flood.grid().to_string());
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        // the lonely cell dies, the block stays
+        let mut automaton = Automaton::new(
+            grid(
+                Topology::Bounded,
+                Neighborhood::Square,
+                "##...\n##...\n.....\n.....\n....#\n",
+            ),
+            life,
+        );
+        assert_eq!(Some(1), automaton.run_until_stable(10));
+        assert_eq!(
+            "##...\n##...\n.....\n.....\n.....\n",
+            automaton.grid().to_string()
+        );
+        let mut blinker = Automaton::new(
+            grid(Topology::Bounded, Neighborhood::Square, ".#.\n.#.\n.#.\n"),
+            life,
+        );
+        assert_eq!(None, blinker.run_until_stable(10));
+    }
+
+    #[test]
+    fn test_cycle() {
+        // on a 4x4 torus a glider looks the same again after 8 generations
+        let glider = grid(
+            Topology::Torus,
+            Neighborhood::Square,
+            ".#..\n..#.\n###.\n....\n",
+        );
+        let mut automaton = Automaton::new(glider.clone(), life);
+        automaton.steps(3);
+        let generations = automaton.find_cycle(100).unwrap();
+        assert_eq!(
+            Cycle {
+                prefix: 0,
+                period: 8
+            },
+            generations.cycle
+        );
+        assert_eq!(3, generations.cycle_start());
+        assert!(!generations.is_fixed_point());
+        assert_eq!(None, generations.state_at(2));
+        let mut reference = Automaton::new(glider.clone(), life);
+        reference.steps(1003);
+        assert_eq!(Some(reference.grid()), generations.state_at(1003));
+        assert_eq!(generations.state_at(3), generations.state_at(1_000_000_003));
+
+        let bits = BitGrid::from_grid_array(&glider, |&cell| cell == '#');
+        let generations = Automaton::new(bits, LifeRule::CONWAY)
+            .find_cycle(100)
+            .unwrap();
+        assert_eq!(8, generations.cycle.period);
+        assert_eq!(
+            reference.grid().to_string(),
+            generations.state_at(1003).unwrap().to_string()
+        );
+    }
+}
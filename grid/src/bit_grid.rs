@@ -0,0 +1,408 @@
+//! Grid of booleans packed into machine words
+//!
+//! A [`BitGrid`] stores one bit per cell (row by row), e.g. walls, occupied or visited cells.
+//! Besides reading and writing single cells, whole grids are shifted by a direction and combined
+//! with `&`, `|`, `^` and `!`, which works on 64 cells at a time. Shifts follow the topology of
+//! the grid like [`adjacent_cell`]: on a bounded grid cells shifted beyond the edge are lost, on a
+//! torus they wrap around. Stitched grids are shifted cell by cell.
+
+use std::fmt::Display;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use bitvec::prelude::{BitVec, Lsb0};
+
+use crate::grid_array::GridArray;
+use crate::grid_iteration::{adjacent_cell, all_adjacent_directions};
+use crate::grid_types::{Direction, Neighborhood, Topology, UCoor2D, UCoor2DIndex};
+
+/// Grid of booleans, one bit per cell
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct BitGrid {
+    width: UCoor2DIndex,
+    height: UCoor2DIndex,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    bits: BitVec<u64, Lsb0>,
+}
+
+impl BitGrid {
+    /// Grid with all cells unset
+    pub fn new(
+        topology: Topology,
+        neighborhood: Neighborhood,
+        width: UCoor2DIndex,
+        height: UCoor2DIndex,
+    ) -> Self {
+        BitGrid {
+            width,
+            height,
+            topology,
+            neighborhood,
+            bits: BitVec::repeat(false, width * height),
+        }
+    }
+
+    /// Grid with the cells at `coors` set
+    ///
+    /// # Panics
+    /// If a coordinate is outside of the grid
+    pub fn from_coors(
+        topology: Topology,
+        neighborhood: Neighborhood,
+        width: UCoor2DIndex,
+        height: UCoor2DIndex,
+        coors: impl IntoIterator<Item = UCoor2D>,
+    ) -> Self {
+        let mut grid = BitGrid::new(topology, neighborhood, width, height);
+        for coor in coors {
+            grid.set(coor.x, coor.y, true);
+        }
+        grid
+    }
+
+    /// Grid of the same size, topology and neighborhood as `grid` with the cells set for which
+    /// `predicate` is true
+    pub fn from_grid_array<T: Default + Clone>(
+        grid: &GridArray<T>,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Self {
+        BitGrid {
+            width: grid.width(),
+            height: grid.height(),
+            topology: grid.get_topology(),
+            neighborhood: grid.get_neighborhood(),
+            bits: grid.iter().map(predicate).collect(),
+        }
+    }
+
+    /// `get_width`
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// `get_height`
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `get_topology`
+    pub fn get_topology(&self) -> Topology {
+        self.topology.clone()
+    }
+
+    /// `get_neighborhood`
+    pub fn get_neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// is the cell on x, y set (`None` if it is outside of the grid)
+    pub fn get(&self, x: UCoor2DIndex, y: UCoor2DIndex) -> Option<bool> {
+        (x < self.width && y < self.height).then(|| self.bits[self.index(x, y)])
+    }
+
+    /// set or unset the cell on x, y and return whether it was set before
+    ///
+    /// # Panics
+    /// If x, y is outside of the grid
+    pub fn set(&mut self, x: UCoor2DIndex, y: UCoor2DIndex, value: bool) -> bool {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the grid"
+        );
+        let index = self.index(x, y);
+        self.bits.replace(index, value)
+    }
+
+    /// set or unset all cells
+    pub fn fill(&mut self, value: bool) {
+        self.bits.fill(value);
+    }
+
+    /// number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    /// is any cell set
+    pub fn any(&self) -> bool {
+        self.bits.any()
+    }
+
+    /// coordinates of all set cells (row by row)
+    pub fn iter_ones(&self) -> impl Iterator<Item = UCoor2D> + '_ {
+        self.bits
+            .iter_ones()
+            .map(|index| UCoor2D::new(index % self.width, index / self.width))
+    }
+
+    /// Moves every set cell one step in `direction`
+    pub fn shift(&mut self, direction: Direction) {
+        if let Topology::Stitched(_) = self.topology {
+            self.shift_stitched(direction);
+            return;
+        }
+        let diff = direction.diff_coor();
+        match diff.x {
+            1 => self.shift_east(),
+            -1 => self.shift_west(),
+            _ => {}
+        }
+        match diff.y {
+            1 => self.shift_south(),
+            -1 => self.shift_north(),
+            _ => {}
+        }
+    }
+
+    /// Copy of the grid with every set cell moved one step in `direction`
+    #[must_use]
+    pub fn shifted(&self, direction: Direction) -> Self {
+        let mut shifted = self.clone();
+        shifted.shift(direction);
+        shifted
+    }
+
+    /// Set cells and all cells adjacent to a set cell (based on topology and neighborhood)
+    #[must_use]
+    pub fn dilate(&self) -> Self {
+        all_adjacent_directions(self.neighborhood).fold(self.clone(), |mut dilated, direction| {
+            dilated |= &self.shifted(direction);
+            dilated
+        })
+    }
+
+    /// Set cells whose adjacent cells are all set (based on topology and neighborhood)
+    ///
+    /// On a bounded grid the cells beyond the edge count as unset.
+    #[must_use]
+    pub fn erode(&self) -> Self {
+        all_adjacent_directions(self.neighborhood).fold(self.clone(), |mut eroded, direction| {
+            eroded &= &self.shifted(direction);
+            eroded
+        })
+    }
+
+    fn index(&self, x: UCoor2DIndex, y: UCoor2DIndex) -> usize {
+        y * self.width + x
+    }
+
+    fn shift_south(&mut self) {
+        let width = self.width.min(self.bits.len());
+        match self.topology {
+            Topology::Bounded => self.bits.shift_end(width),
+            Topology::Torus => self.bits.rotate_right(width),
+            Topology::Stitched(_) => unreachable!("stitched grids are shifted cell by cell"),
+        }
+    }
+
+    fn shift_north(&mut self) {
+        let width = self.width.min(self.bits.len());
+        match self.topology {
+            Topology::Bounded => self.bits.shift_start(width),
+            Topology::Torus => self.bits.rotate_left(width),
+            Topology::Stitched(_) => unreachable!("stitched grids are shifted cell by cell"),
+        }
+    }
+
+    /// Moves the set cells one by one, as the edges of a stitched grid do not map to a shift of
+    /// the whole bit vector
+    fn shift_stitched(&mut self, direction: Direction) {
+        let mut shifted = BitVec::repeat(false, self.bits.len());
+        for coor in self.iter_ones() {
+            if let Some(next) =
+                adjacent_cell(&self.topology, self.width, self.height, coor, direction)
+            {
+                shifted.set(self.index(next.x, next.y), true);
+            }
+        }
+        self.bits = shifted;
+    }
+
+    /// Shifts the bits towards the end and fixes the first column, which received the last
+    /// column of the previous row
+    fn shift_east(&mut self) {
+        if self.bits.is_empty() {
+            return;
+        }
+        let last_column: Vec<bool> = (0..self.height)
+            .map(|y| self.bits[self.index(self.width - 1, y)])
+            .collect();
+        self.bits.shift_end(1);
+        for (y, cell) in last_column.into_iter().enumerate() {
+            let index = self.index(0, y);
+            self.bits
+                .set(index, cell && self.topology == Topology::Torus);
+        }
+    }
+
+    /// Shifts the bits towards the start and fixes the last column, which received the first
+    /// column of the next row
+    fn shift_west(&mut self) {
+        if self.bits.is_empty() {
+            return;
+        }
+        let first_column: Vec<bool> = (0..self.height)
+            .map(|y| self.bits[self.index(0, y)])
+            .collect();
+        self.bits.shift_start(1);
+        for (y, cell) in first_column.into_iter().enumerate() {
+            let index = self.index(self.width - 1, y);
+            self.bits
+                .set(index, cell && self.topology == Topology::Torus);
+        }
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(
+                    f,
+                    "{}",
+                    if self.bits[self.index(x, y)] {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                )?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! bit_operation {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $assign_trait<&BitGrid> for BitGrid {
+            fn $assign_method(&mut self, other: &BitGrid) {
+                debug_assert_eq!((self.width, self.height), (other.width, other.height));
+                self.bits.$assign_method(&other.bits);
+            }
+        }
+
+        impl $trait<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                let mut result = self.clone();
+                result.$assign_method(other);
+                result
+            }
+        }
+    };
+}
+
+bit_operation!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bit_operation!(BitOr, bitor, BitOrAssign, bitor_assign);
+bit_operation!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        BitGrid {
+            bits: !self.bits.clone(),
+            topology: self.topology.clone(),
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edge_map::EdgeMap;
+    use crate::test_util::grid;
+
+    /// The `#` cells of the rows
+    fn bits(topology: Topology, input: &str) -> BitGrid {
+        let cells = grid(topology, Neighborhood::Orthogonal, input);
+        BitGrid::from_grid_array(&cells, |&cell| cell == '#')
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut bits = bits(Topology::Bounded, "#..\n..#\n");
+        assert_eq!(2, bits.count_ones());
+        assert_eq!(Some(true), bits.get(2, 1));
+        assert_eq!(None, bits.get(3, 1));
+        assert!(!bits.set(1, 1, true));
+        assert!(bits.set(0, 0, false));
+        assert_eq!(
+            vec![UCoor2D::new(1, 1), UCoor2D::new(2, 1)],
+            bits.iter_ones().collect::<Vec<_>>()
+        );
+        assert_eq!("...\n.##\n", bits.to_string());
+    }
+
+    #[test]
+    fn test_shift() {
+        let bounded = bits(Topology::Bounded, "#..#\n....\n.#.#\n");
+        assert_eq!(
+            ".#..\n....\n..#.\n",
+            bounded.shifted(Direction::East).to_string()
+        );
+        assert_eq!(
+            "..#.\n....\n#.#.\n",
+            bounded.shifted(Direction::West).to_string()
+        );
+        assert_eq!(
+            "....\n.#.#\n....\n",
+            bounded.shifted(Direction::North).to_string()
+        );
+        assert_eq!(
+            "....\n.#..\n....\n",
+            bounded.shifted(Direction::SouthEast).to_string()
+        );
+
+        let torus = bits(Topology::Torus, "#..#\n....\n.#.#\n");
+        assert_eq!(
+            "##..\n....\n#.#.\n",
+            torus.shifted(Direction::East).to_string()
+        );
+        assert_eq!(
+            "..##\n....\n#.#.\n",
+            torus.shifted(Direction::West).to_string()
+        );
+        assert_eq!(
+            "#.#.\n##..\n....\n",
+            torus.shifted(Direction::SouthEast).to_string()
+        );
+        let mut shifted = torus.clone();
+        for _ in 0..12 {
+            shifted.shift(Direction::NorthWest);
+        }
+        assert_eq!(torus, shifted);
+    }
+
+    #[test]
+    fn test_shift_stitched() {
+        let mut bits = bits(Topology::Bounded, "..#\n#..\n");
+        bits.topology = EdgeMap::mobius_strip(3, 2).into();
+        assert_eq!("...\n##.\n", bits.shifted(Direction::East).to_string());
+        assert_eq!("#..\n...\n", bits.shifted(Direction::North).to_string());
+    }
+
+    #[test]
+    fn test_operations() {
+        let walls = bits(Topology::Bounded, "##.\n#..\n");
+        let visited = bits(Topology::Bounded, ".##\n...\n");
+        assert_eq!(".#.\n...\n", (&walls & &visited).to_string());
+        assert_eq!("###\n#..\n", (&walls | &visited).to_string());
+        assert_eq!("#.#\n#..\n", (&walls ^ &visited).to_string());
+        assert_eq!("..#\n.##\n", (!&walls).to_string());
+        assert_eq!(3, (!&walls).count_ones());
+    }
+
+    #[test]
+    fn test_dilate_erode() {
+        let bits = bits(Topology::Bounded, ".....\n.###.\n.###.\n.###.\n");
+        assert_eq!(".###.\n#####\n#####\n#####\n", bits.dilate().to_string());
+        assert_eq!(".....\n.....\n..#..\n.....\n", bits.erode().to_string());
+        // the bottom row is at the edge of a bounded grid
+        assert_eq!(1, bits.erode().count_ones());
+        assert!(!bits.erode().erode().any());
+    }
+}
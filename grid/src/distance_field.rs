@@ -0,0 +1,331 @@
+//! Distances of all cells of a grid from (or to) a set of sources
+//!
+//! A distance field stores the shortest distance of every cell in a grid of the same size
+//! (`None` if the cell cannot be reached), so it can be indexed like the grid it was built from.
+//! Fields in both directions can be combined to find the cells on optimal paths.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::grid_array::GridArray;
+use crate::grid_iteration::adjacent_cell;
+use crate::grid_types::{Direction, UCoor2D};
+
+/// Shortest distance of every cell (`None` if it cannot be reached)
+pub type DistanceField = GridArray<Option<usize>>;
+
+/// Shortest distance of every cell for every facing direction (indexed by `Direction as usize`)
+pub type DirectionalDistanceField = GridArray<[Option<usize>; 8]>;
+
+/// Whether a field holds the distances from the sources or to the sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldDirection {
+    /// Distances of paths starting at a source
+    Forward,
+    /// Distances of paths ending at a source
+    Backward,
+}
+
+/// Distances stored in a cell of a distance field
+pub trait CellDistances: Default + Clone {
+    /// Smallest sum of the distances of the same state in two fields
+    fn min_sum(&self, other: &Self) -> Option<usize>;
+}
+
+impl CellDistances for Option<usize> {
+    fn min_sum(&self, other: &Self) -> Option<usize> {
+        Some((*self)? + (*other)?)
+    }
+}
+
+impl CellDistances for [Option<usize>; 8] {
+    fn min_sum(&self, other: &Self) -> Option<usize> {
+        self.iter()
+            .zip(other)
+            .filter_map(|(distance, other_distance)| distance.min_sum(other_distance))
+            .min()
+    }
+}
+
+/// Distances from the nearest of `sources` through passable cells, every step costs 1
+///
+/// The sources themselves need not be passable.
+pub fn distance_field<T: Default + Clone>(
+    grid: &GridArray<T>,
+    sources: impl IntoIterator<Item = UCoor2D>,
+    passable: impl Fn(&T) -> bool,
+) -> DistanceField {
+    weighted_distance_field(grid, sources, FieldDirection::Forward, |_from, to| {
+        passable(to).then_some(1)
+    })
+}
+
+/// Distances from (or to) the nearest of `sources`
+///
+/// `step_costs` returns the costs of a step from a cell to its neighbor (given their contents),
+/// or `None` if the step is not possible. E.g. weighted cells are `|_from, to| Some(weight(to))`.
+pub fn weighted_distance_field<T: Default + Clone>(
+    grid: &GridArray<T>,
+    sources: impl IntoIterator<Item = UCoor2D>,
+    field_direction: FieldDirection,
+    step_costs: impl Fn(&T, &T) -> Option<usize>,
+) -> DistanceField {
+    let distances = fill_distances::<_, 1>(
+        grid,
+        sources.into_iter().map(|coor| (coor, 0)),
+        |coor, _state, add_successor| {
+            for direction in grid.all_adjacent_directions() {
+                if let Some(neighbor) = adjacent(grid, coor, direction) {
+                    let costs = match field_direction {
+                        FieldDirection::Forward => {
+                            step_costs(cell(grid, coor), cell(grid, &neighbor))
+                        }
+                        FieldDirection::Backward => {
+                            step_costs(cell(grid, &neighbor), cell(grid, coor))
+                        }
+                    };
+                    if let Some(costs) = costs {
+                        add_successor(neighbor, 0, costs);
+                    }
+                }
+            }
+        },
+    );
+    GridArray::from_1d_vec(
+        grid.get_topology(),
+        grid.get_neighborhood(),
+        grid.width(),
+        distances
+            .into_iter()
+            .map(|distances| distances[0])
+            .collect(),
+    )
+}
+
+/// Distances from (or to) the nearest of `sources`, where a state is a cell and a facing direction
+///
+/// A state either steps forward to the adjacent cell in its direction (costs from `step_costs`
+/// as in [`weighted_distance_field`]) or turns to another direction of the neighborhood of the
+/// grid without moving (costs from `turn_costs`, `None` if the turn is not possible).
+pub fn directional_distance_field<T: Default + Clone>(
+    grid: &GridArray<T>,
+    sources: impl IntoIterator<Item = (UCoor2D, Direction)>,
+    field_direction: FieldDirection,
+    step_costs: impl Fn(&T, &T) -> Option<usize>,
+    turn_costs: impl Fn(Direction, Direction) -> Option<usize>,
+) -> DirectionalDistanceField {
+    let directions: Vec<Direction> = grid.all_adjacent_directions().collect();
+    let distances = fill_distances::<_, 8>(
+        grid,
+        sources
+            .into_iter()
+            .map(|(coor, direction)| (coor, direction as usize)),
+        |coor, state, add_successor| {
+            let facing = directions
+                .iter()
+                .copied()
+                .find(|&direction| direction as usize == state)
+                .expect("state is a direction of the neighborhood");
+            let step = match field_direction {
+                FieldDirection::Forward => adjacent(grid, coor, facing).and_then(|next| {
+                    Some((step_costs(cell(grid, coor), cell(grid, &next))?, next))
+                }),
+                FieldDirection::Backward => {
+                    adjacent(grid, coor, facing.opposite()).and_then(|previous| {
+                        Some((
+                            step_costs(cell(grid, &previous), cell(grid, coor))?,
+                            previous,
+                        ))
+                    })
+                }
+            };
+            if let Some((costs, neighbor)) = step {
+                add_successor(neighbor, state, costs);
+            }
+            for &direction in directions.iter().filter(|&&direction| direction != facing) {
+                let costs = match field_direction {
+                    FieldDirection::Forward => turn_costs(facing, direction),
+                    FieldDirection::Backward => turn_costs(direction, facing),
+                };
+                if let Some(costs) = costs {
+                    add_successor(*coor, direction as usize, costs);
+                }
+            }
+        },
+    );
+    GridArray::from_1d_vec(
+        grid.get_topology(),
+        grid.get_neighborhood(),
+        grid.width(),
+        distances,
+    )
+}
+
+/// Marks the cells on an optimal path between the sources of a forward and a backward field
+///
+/// A cell (or one of its states) is on an optimal path if its forward and backward distances
+/// add up to the shortest distance between the sources of both fields.
+pub fn on_optimal_paths<D: CellDistances>(
+    forward: &GridArray<D>,
+    backward: &GridArray<D>,
+) -> GridArray<bool> {
+    debug_assert_eq!(
+        (forward.width(), forward.height()),
+        (backward.width(), backward.height())
+    );
+    let sums: Vec<Option<usize>> = forward
+        .iter()
+        .zip(backward.iter())
+        .map(|(forward_distances, backward_distances)| {
+            forward_distances.min_sum(backward_distances)
+        })
+        .collect();
+    let optimum = sums.iter().flatten().min().copied();
+    GridArray::from_1d_vec(
+        forward.get_topology(),
+        forward.get_neighborhood(),
+        forward.width(),
+        sums.into_iter()
+            .map(|sum| sum.is_some() && sum == optimum)
+            .collect(),
+    )
+}
+
+fn cell<'a, T: Default + Clone>(grid: &'a GridArray<T>, coor: &UCoor2D) -> &'a T {
+    grid.get_unchecked(coor.x, coor.y)
+}
+
+fn adjacent<T: Default + Clone>(
+    grid: &GridArray<T>,
+    coor: &UCoor2D,
+    direction: Direction,
+) -> Option<UCoor2D> {
+    adjacent_cell(
+        &grid.get_topology(),
+        grid.width(),
+        grid.height(),
+        *coor,
+        direction,
+    )
+}
+
+/// Dijkstra over `N` states per cell, returns the distances of the states of every cell
+///
+/// `successors` is called with a cell, its state and a callback taking the cell, state and costs
+/// of every successor.
+fn fill_distances<T: Default + Clone, const N: usize>(
+    grid: &GridArray<T>,
+    sources: impl IntoIterator<Item = (UCoor2D, usize)>,
+    mut successors: impl FnMut(&UCoor2D, usize, &mut dyn FnMut(UCoor2D, usize, usize)),
+) -> Vec<[Option<usize>; N]> {
+    let index = |coor: &UCoor2D| coor.y * grid.width() + coor.x;
+    let mut distances = vec![[None; N]; grid.width() * grid.height()];
+    let mut queue = BinaryHeap::new();
+    for (coor, state) in sources {
+        distances[index(&coor)][state] = Some(0);
+        queue.push(Reverse((0, index(&coor), state)));
+    }
+    while let Some(Reverse((distance, cell_index, state))) = queue.pop() {
+        if distances[cell_index][state].is_some_and(|known_distance| known_distance < distance) {
+            continue;
+        }
+        let coor = UCoor2D::new(cell_index % grid.width(), cell_index / grid.width());
+        successors(&coor, state, &mut |successor, successor_state, costs| {
+            let successor_index = index(&successor);
+            let successor_distance = distance + costs;
+            let known = &mut distances[successor_index][successor_state];
+            if known.is_none_or(|known_distance| successor_distance < known_distance) {
+                *known = Some(successor_distance);
+                queue.push(Reverse((
+                    successor_distance,
+                    successor_index,
+                    successor_state,
+                )));
+            }
+        });
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::{Neighborhood, Topology};
+    use crate::test_util::maze;
+
+    #[test]
+    fn test_distance_field() {
+        let field = distance_field(&maze(), [UCoor2D::new(0, 0), UCoor2D::new(3, 3)], |&cell| {
+            cell == '.'
+        });
+        assert_eq!(Some(&Some(0)), field.get(3, 3));
+        assert_eq!(Some(&Some(2)), field.get(1, 3));
+        assert_eq!(Some(&Some(4)), field.get(3, 1));
+        assert_eq!(Some(&None), field.get(1, 1));
+        assert_eq!(Some(4), field.iter().flatten().max().copied());
+    }
+
+    #[test]
+    fn test_weighted_distance_field() {
+        let grid = GridArray::from_2d_vec(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            vec![vec![1, 9, 1], vec![1, 1, 1]],
+        );
+        let weights = |_from: &usize, &to: &usize| Some(to);
+        let forward = weighted_distance_field(
+            &grid,
+            [UCoor2D::new(0, 0)],
+            FieldDirection::Forward,
+            weights,
+        );
+        let backward = weighted_distance_field(
+            &grid,
+            [UCoor2D::new(2, 0)],
+            FieldDirection::Backward,
+            weights,
+        );
+        assert_eq!(Some(&Some(4)), forward.get(2, 0));
+        assert_eq!(Some(&Some(9)), forward.get(1, 0));
+        // a path ending at (2, 0) from (1, 0) costs 1, but entering (1, 0) is not part of it
+        assert_eq!(Some(&Some(1)), backward.get(1, 0));
+        assert_eq!(Some(&Some(4)), backward.get(0, 0));
+        let on_paths = on_optimal_paths(&forward, &backward);
+        assert_eq!(5, on_paths.iter().filter(|&&on_path| on_path).count());
+        assert_eq!(Some(&false), on_paths.get(1, 0));
+    }
+
+    #[test]
+    fn test_directional_distance_field() {
+        let grid = maze();
+        let step_costs = |_from: &char, &to: &char| (to == '.').then_some(1);
+        let turn_costs = |_from, _to| Some(10);
+        let forward = directional_distance_field(
+            &grid,
+            [(UCoor2D::new(0, 0), Direction::East)],
+            FieldDirection::Forward,
+            step_costs,
+            turn_costs,
+        );
+        let goals = grid
+            .all_adjacent_directions()
+            .map(|direction| (UCoor2D::new(3, 3), direction));
+        let backward = directional_distance_field(
+            &grid,
+            goals,
+            FieldDirection::Backward,
+            step_costs,
+            turn_costs,
+        );
+        // east, south at (2, 0), east at (2, 3)
+        let south = Direction::South as usize;
+        assert_eq!(
+            Some(26),
+            forward.get(3, 3).unwrap()[Direction::East as usize]
+        );
+        assert_eq!(Some(12), forward.get(2, 0).unwrap()[south]);
+        assert_eq!(Some(14), backward.get(2, 0).unwrap()[south]);
+        let on_paths = on_optimal_paths(&forward, &backward);
+        assert_eq!(7, on_paths.iter().filter(|&&on_path| on_path).count());
+        assert_eq!(Some(&false), on_paths.get(0, 1));
+    }
+}
@@ -0,0 +1,374 @@
+//! User-defined stitching of grid edges
+//!
+//! An [`EdgeMap`] (used as [`Topology::Stitched`]) decides where an orthogonal step leads to and
+//! which direction the walker faces afterwards. Steps without a link behave like in a bounded grid,
+//! so only the glued edges (e.g. of a cube net) or the teleport cells have to be linked.
+//! Diagonal steps are made of two orthogonal steps (vertical first). Each one turns the diagonal
+//! direction like the walker, and a mirroring edge (like the twisted edge of a Klein bottle)
+//! mirrors it as well.
+
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use fxhash::FxHashMap;
+
+use crate::grid_types::{Direction, Topology, UCoor2D, UCoor2DIndex};
+
+/// Links from a cell and a direction to the entered cell and the new facing direction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgeMap {
+    links: FxHashMap<(UCoor2D, Direction), (UCoor2D, Direction)>,
+}
+
+impl Hash for EdgeMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the links are not ordered, equal maps have the same links in the canonical order
+        let mut links: Vec<_> = self.links.iter().collect();
+        links.sort_unstable_by_key(|((from, direction), _to)| (*from, *direction as u8));
+        links.hash(state);
+    }
+}
+
+impl EdgeMap {
+    /// Map without links (a bounded grid)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stepping from `from` towards `direction` enters `to` facing `facing` (one way)
+    ///
+    /// # Panics
+    /// If `direction` is diagonal
+    pub fn link(
+        &mut self,
+        from: UCoor2D,
+        direction: Direction,
+        to: UCoor2D,
+        facing: Direction,
+    ) -> &mut Self {
+        assert!(
+            direction.is_orthogonal(),
+            "only orthogonal steps can be linked"
+        );
+        self.links.insert((from, direction), (to, facing));
+        self
+    }
+
+    /// Glues two edges cell by cell (both ways)
+    ///
+    /// Leaving the i-th cell of `edge` towards `leaving` enters the i-th cell of `other_edge`
+    /// facing `entering`, and leaving that cell backwards (opposite to `entering`) returns to
+    /// the cell of `edge` facing opposite to `leaving`.
+    ///
+    /// # Panics
+    /// If the edges differ in length or a direction is diagonal
+    pub fn stitch(
+        &mut self,
+        edge: impl IntoIterator<Item = UCoor2D>,
+        leaving: Direction,
+        other_edge: impl IntoIterator<Item = UCoor2D>,
+        entering: Direction,
+    ) -> &mut Self {
+        let edge: Vec<UCoor2D> = edge.into_iter().collect();
+        let other_edge: Vec<UCoor2D> = other_edge.into_iter().collect();
+        assert_eq!(edge.len(), other_edge.len(), "edges differ in length");
+        for (cell, other_cell) in edge.into_iter().zip(other_edge) {
+            self.link(cell, leaving, other_cell, entering);
+            self.link(other_cell, entering.opposite(), cell, leaving.opposite());
+        }
+        self
+    }
+
+    /// A strip whose east and west edges are glued with a half twist
+    pub fn mobius_strip(width: UCoor2DIndex, height: UCoor2DIndex) -> Self {
+        let mut map = Self::new();
+        map.stitch(
+            (0..height).map(|y| UCoor2D::new(width - 1, y)),
+            Direction::East,
+            (0..height).rev().map(|y| UCoor2D::new(0, y)),
+            Direction::East,
+        );
+        map
+    }
+
+    /// A torus whose north and south edges are glued with a half twist
+    pub fn klein_bottle(width: UCoor2DIndex, height: UCoor2DIndex) -> Self {
+        let mut map = Self::new();
+        map.stitch(
+            (0..height).map(|y| UCoor2D::new(width - 1, y)),
+            Direction::East,
+            (0..height).map(|y| UCoor2D::new(0, y)),
+            Direction::East,
+        )
+        .stitch(
+            (0..width).map(|x| UCoor2D::new(x, height - 1)),
+            Direction::South,
+            (0..width).rev().map(|x| UCoor2D::new(x, 0)),
+            Direction::South,
+        );
+        map
+    }
+
+    /// Does the link from `coor` towards `direction` flip the orientation
+    ///
+    /// The link is compared with the one of a neighbouring cell on the same edge: a mirroring
+    /// edge maps that neighbour to the opposite side of the entered cell. Single links (like
+    /// teleport cells) do not mirror.
+    pub fn mirrors(&self, coor: &UCoor2D, direction: Direction) -> bool {
+        let Some((to, facing)) = self.links.get(&(*coor, direction)) else {
+            return false;
+        };
+        let turn = *facing as isize - direction as isize;
+        [direction.turn_left(), direction.turn_right()]
+            .into_iter()
+            .any(|along| {
+                coor.step(along, 1)
+                    .and_then(|neighbor| self.links.get(&(neighbor, direction)))
+                    .is_some_and(|(neighbor_to, _)| {
+                        to.step(along.rotate_eighths(turn).opposite(), 1) == Some(*neighbor_to)
+                    })
+            })
+    }
+
+    /// The cell entered and the new facing when stepping from `coor` towards the orthogonal
+    /// `direction` (`None` if the step leads beyond an edge which is not linked)
+    pub fn step(
+        &self,
+        width: UCoor2DIndex,
+        height: UCoor2DIndex,
+        coor: &UCoor2D,
+        direction: Direction,
+    ) -> Option<(UCoor2D, Direction)> {
+        if let Some((to, facing)) = self.links.get(&(*coor, direction)) {
+            return Some((*to, *facing));
+        }
+        let (x, y) = (coor.x, coor.y);
+        let next = match direction {
+            Direction::North => UCoor2D::new(x, y.checked_sub(1)?),
+            Direction::South => UCoor2D::new(x, y + 1),
+            Direction::East => UCoor2D::new(x + 1, y),
+            Direction::West => UCoor2D::new(x.checked_sub(1)?, y),
+            _ => panic!("only orthogonal steps are looked up"),
+        };
+        (next.x < width && next.y < height).then_some((next, direction))
+    }
+}
+
+impl From<EdgeMap> for Topology {
+    fn from(map: EdgeMap) -> Self {
+        Topology::Stitched(Arc::new(map))
+    }
+}
+
+/// The cells from `from` to `to` (both included) along a row or a column
+///
+/// # Panics
+/// If the cells are neither in the same row nor in the same column
+pub fn edge_cells(from: &UCoor2D, to: &UCoor2D) -> Vec<UCoor2D> {
+    let range = |a: usize, b: usize| -> Vec<usize> {
+        if a <= b {
+            (a..=b).collect()
+        } else {
+            (b..=a).rev().collect()
+        }
+    };
+    if from.x == to.x {
+        range(from.y, to.y)
+            .into_iter()
+            .map(|y| UCoor2D::new(from.x, y))
+            .collect()
+    } else {
+        assert_eq!(from.y, to.y, "an edge is a row or a column");
+        range(from.x, to.x)
+            .into_iter()
+            .map(|x| UCoor2D::new(x, from.y))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_iteration::{adjacent_cell, step};
+    use crate::grid_types::Direction::*;
+
+    /// Walks `steps` times straight ahead (turning with the edges)
+    fn walk(
+        topology: &Topology,
+        width: usize,
+        height: usize,
+        mut coor: UCoor2D,
+        mut facing: Direction,
+        steps: usize,
+    ) -> Option<(UCoor2D, Direction)> {
+        for _ in 0..steps {
+            (coor, facing) = step(topology, width, height, coor, facing)?;
+        }
+        Some((coor, facing))
+    }
+
+    #[test]
+    fn test_mobius_strip() {
+        let topology = Topology::from(EdgeMap::mobius_strip(3, 2));
+        assert_eq!(
+            Some((UCoor2D::new(0, 1), East)),
+            step(&topology, 3, 2, UCoor2D::new(2, 0), East)
+        );
+        assert_eq!(
+            Some((UCoor2D::new(2, 0), West)),
+            step(&topology, 3, 2, UCoor2D::new(0, 1), West)
+        );
+        assert_eq!(
+            None,
+            adjacent_cell(&topology, 3, 2, UCoor2D::new(1, 0), North)
+        );
+        assert_eq!(
+            Some((UCoor2D::new(1, 1), East)),
+            walk(&topology, 3, 2, UCoor2D::new(1, 0), East, 3)
+        );
+        assert_eq!(
+            Some((UCoor2D::new(1, 0), East)),
+            walk(&topology, 3, 2, UCoor2D::new(1, 0), East, 6)
+        );
+        // the second part of a diagonal step crosses the twisted edge and mirrors it
+        assert_eq!(
+            Some((UCoor2D::new(0, 1), SouthEast)),
+            step(&topology, 3, 2, UCoor2D::new(2, 1), NorthEast)
+        );
+    }
+
+    #[test]
+    fn test_klein_bottle() {
+        let topology = Topology::from(EdgeMap::klein_bottle(3, 2));
+        assert_eq!(
+            Some(UCoor2D::new(0, 1)),
+            adjacent_cell(&topology, 3, 2, UCoor2D::new(2, 1), East)
+        );
+        assert_eq!(
+            Some((UCoor2D::new(2, 1), North)),
+            step(&topology, 3, 2, UCoor2D::new(0, 0), North)
+        );
+        // the twisted edge mirrors a diagonal step crossing it
+        assert_eq!(
+            Some((UCoor2D::new(1, 1), NorthWest)),
+            step(&topology, 3, 2, UCoor2D::new(0, 0), NorthEast)
+        );
+        assert_eq!(
+            Some((UCoor2D::new(1, 0), SouthEast)),
+            step(&topology, 3, 2, UCoor2D::new(2, 1), SouthWest)
+        );
+    }
+
+    #[test]
+    fn test_portal() {
+        let mut map = EdgeMap::new();
+        map.link(UCoor2D::new(1, 1), East, UCoor2D::new(0, 3), South);
+        let topology = Topology::from(map);
+        assert_eq!(
+            Some((UCoor2D::new(0, 3), South)),
+            step(&topology, 4, 4, UCoor2D::new(1, 1), East)
+        );
+        assert_eq!(
+            Some((UCoor2D::new(2, 2), East)),
+            step(&topology, 4, 4, UCoor2D::new(1, 2), East)
+        );
+        assert_eq!(None, step(&topology, 4, 4, UCoor2D::new(3, 1), East));
+    }
+
+    /// Cube net with faces of 2x2 cells:
+    /// ```text
+    ///  .A.
+    ///  BCD
+    ///  .E.
+    ///  .F.
+    /// ```
+    #[test]
+    fn test_cube_net() {
+        const N: usize = 2;
+        let cell = |(face_x, face_y): (usize, usize), x: usize, y: usize| {
+            UCoor2D::new(face_x * N + x, face_y * N + y)
+        };
+        let side = |face, from: (usize, usize), to: (usize, usize)| {
+            edge_cells(&cell(face, from.0, from.1), &cell(face, to.0, to.1))
+        };
+        let (a, b, d, e, f) = ((1, 0), (0, 1), (2, 1), (1, 2), (1, 3));
+        let (first, last) = (0, N - 1);
+        let mut map = EdgeMap::new();
+        map.stitch(
+            side(f, (first, last), (last, last)),
+            South,
+            side(a, (first, first), (last, first)),
+            South,
+        )
+        .stitch(
+            side(d, (last, first), (last, last)),
+            East,
+            side(f, (last, last), (last, first)),
+            West,
+        )
+        .stitch(
+            side(b, (first, first), (first, last)),
+            West,
+            side(f, (first, last), (first, first)),
+            East,
+        )
+        .stitch(
+            side(a, (last, first), (last, last)),
+            East,
+            side(d, (last, first), (first, first)),
+            South,
+        )
+        .stitch(
+            side(a, (first, first), (first, last)),
+            West,
+            side(b, (first, first), (last, first)),
+            South,
+        )
+        .stitch(
+            side(e, (last, first), (last, last)),
+            East,
+            side(d, (first, last), (last, last)),
+            North,
+        )
+        .stitch(
+            side(e, (first, first), (first, last)),
+            West,
+            side(b, (last, last), (first, last)),
+            North,
+        );
+        let topology = Topology::from(map);
+        let (width, height) = (3 * N, 4 * N);
+        let faces = [a, b, (1, 1), d, e, f];
+        for face in faces {
+            for (x, y) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+                for facing in [North, East, South, West] {
+                    let start = cell(face, x, y);
+                    // a straight walk around the cube returns to the start
+                    assert_eq!(
+                        Some((start, facing)),
+                        walk(&topology, width, height, start, facing, 4 * N)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        let hash = |map: &EdgeMap| fxhash::hash64(map);
+        let mut forward = EdgeMap::new();
+        forward
+            .link(UCoor2D::new(0, 0), North, UCoor2D::new(1, 1), South)
+            .link(UCoor2D::new(2, 0), West, UCoor2D::new(0, 1), East);
+        let mut backward = EdgeMap::new();
+        backward
+            .link(UCoor2D::new(2, 0), West, UCoor2D::new(0, 1), East)
+            .link(UCoor2D::new(0, 0), North, UCoor2D::new(1, 1), South);
+        assert_eq!(forward, backward);
+        assert_eq!(hash(&forward), hash(&backward));
+        assert_ne!(
+            hash(&EdgeMap::mobius_strip(3, 2)),
+            hash(&EdgeMap::klein_bottle(3, 2))
+        );
+    }
+}
@@ -10,7 +10,7 @@ use itertools::Itertools;
 
 use derive_builder::Builder;
 
-use crate::grid_iteration::{adjacent_cell, all_adjacent_directions, is_corner, is_edge};
+use crate::grid_iteration::{adjacent_cell, all_adjacent_directions, is_corner, is_edge, step};
 use crate::grid_types::Direction;
 
 use super::grid_iteration;
@@ -21,7 +21,7 @@ use gif::{Encoder, Frame, Repeat};
 /// `GridArray`
 #[allow(missing_docs, unused_mut)]
 #[derive(Builder, Clone, Debug, Hash, Eq, PartialEq)]
-pub struct GridArray<T: Default + Clone + std::fmt::Display> {
+pub struct GridArray<T: Default + Clone> {
     /// width of the grid
     width: UCoor2DIndex,
     height: UCoor2DIndex,
@@ -36,7 +36,7 @@ pub struct GridArray<T: Default + Clone + std::fmt::Display> {
     data: Vec<T>,
 }
 
-impl<T: Default + Clone + std::fmt::Display> GridArrayBuilder<T> {
+impl<T: Default + Clone> GridArrayBuilder<T> {
     fn create_data_vec(&self) -> Vec<T> {
         vec![T::default(); self.width.unwrap() * self.height.unwrap()]
     }
@@ -78,7 +78,7 @@ impl GridArray<char> {
     }
 }
 
-impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+impl<T: Default + Clone> GridArray<T> {
     /// from newline separated string
     /// #Panics panics if the string is not a rectangle
     pub fn from_newline_separated_string_into(
@@ -104,7 +104,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     }
 }
 
-impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+impl<T: Default + Clone> GridArray<T> {
     #[allow(dead_code)]
     fn create_data_vec(&self) -> Vec<T> {
         vec![T::default(); self.width * self.height]
@@ -184,7 +184,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
 
     /// `get_topology`
     pub fn get_topology(&self) -> Topology {
-        self.topology
+        self.topology.clone()
     }
 
     /// `get_neighborhood`
@@ -200,13 +200,13 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     /// `is_edge`
     pub fn is_edge(&self, x: UCoor2DIndex, y: UCoor2DIndex) -> bool {
         debug_assert!(self.check_index(x, y));
-        is_edge(self.topology, self.width, self.height, &UCoor2D::new(x, y))
+        is_edge(&self.topology, self.width, self.height, &UCoor2D::new(x, y))
     }
 
     /// `is_corner`
     pub fn is_corner(&self, x: UCoor2DIndex, y: UCoor2DIndex) -> bool {
         debug_assert!(self.check_index(x, y));
-        is_corner(self.topology, self.width, self.height, &UCoor2D::new(x, y))
+        is_corner(&self.topology, self.width, self.height, &UCoor2D::new(x, y))
     }
 
     /// get reference to element on x, y
@@ -267,7 +267,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         y: UCoor2DIndex,
     ) -> impl Iterator<Item = UCoor2D> {
         grid_iteration::neighborhood_cells(
-            self.topology,
+            &self.topology,
             self.width,
             self.height,
             UCoor2D::new(x, y),
@@ -282,7 +282,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         y: UCoor2DIndex,
     ) -> impl Iterator<Item = (UCoor2D, Direction)> {
         grid_iteration::neighborhood_cells_and_dirs(
-            self.topology,
+            &self.topology,
             self.width,
             self.height,
             UCoor2D::new(x, y),
@@ -294,7 +294,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         &self,
         it: impl Iterator<Item = UCoor2D>,
     ) -> impl Iterator<Item = (UCoor2D, &T)> {
-        it.map(|coor| (coor.clone(), self.get_unchecked(coor.x, coor.y)))
+        it.map(|coor| (coor, self.get_unchecked(coor.x, coor.y)))
     }
 
     // map_indexes_to_cells_mut not possible to implement (multiple borrows of self_data)
@@ -304,6 +304,11 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         self.data.iter()
     }
 
+    /// all data as a mutable slice (row by row)
+    pub(crate) fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
     /// return all elements
     pub fn all_cells(&self) -> impl Iterator<Item = (UCoor2D, &T)> {
         self.map_indexes_to_cells(self.all_indexes())
@@ -339,7 +344,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         direction: Direction,
     ) -> Option<UCoor2D> {
         adjacent_cell(
-            self.topology,
+            &self.topology,
             self.width,
             self.height,
             UCoor2D::new(x, y),
@@ -347,24 +352,21 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         )
     }
 
-    /// Print grid
-    pub fn print(&self, add_stars: bool) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                print!(
-                    "{}{}",
-                    self.get_unchecked(x, y),
-                    if add_stars { "*" } else { "" }
-                );
-            }
-            println!();
-        }
-    }
-
-    /// Print grid (with extra newline)
-    pub fn println(&self, add_stars: bool) {
-        self.print(add_stars);
-        println!();
+    /// return adjacent cell in direction and the direction faced after the step
+    /// (which only changes on stitched topologies)
+    pub fn step(
+        &self,
+        x: UCoor2DIndex,
+        y: UCoor2DIndex,
+        direction: Direction,
+    ) -> Option<(UCoor2D, Direction)> {
+        step(
+            &self.topology,
+            self.width,
+            self.height,
+            UCoor2D::new(x, y),
+            direction,
+        )
     }
 
     fn swap(&mut self, x1: UCoor2DIndex, y1: UCoor2DIndex, x2: UCoor2DIndex, y2: UCoor2DIndex) {
@@ -437,13 +439,37 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     }
 }
 
+impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+    /// Print grid
+    pub fn print(&self, add_stars: bool) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                print!(
+                    "{}{}",
+                    self.get_unchecked(x, y),
+                    if add_stars { "*" } else { "" }
+                );
+            }
+            println!();
+        }
+    }
+
+    /// Print grid (with extra newline)
+    pub fn println(&self, add_stars: bool) {
+        self.print(add_stars);
+        println!();
+    }
+}
+
 fn get_minmax_nonempty(grid: &GridArray<char>) -> (UCoor2D, UCoor2D) {
     grid.all_cells().filter(|(_, ch)| ch != &&'\0').fold(
         (
             UCoor2D::new(usize::MAX, usize::MAX),
             UCoor2D::new(usize::MIN, usize::MIN),
         ),
-        |(coor_min, coor_max), (coor, _)| (coor_min.min(&coor), coor_max.max(&coor)),
+        |(coor_min, coor_max), (coor, _)| {
+            (coor_min.component_min(&coor), coor_max.component_max(&coor))
+        },
     )
 }
 
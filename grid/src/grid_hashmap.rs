@@ -96,7 +96,9 @@ where
                 Coor2DMut::new(ICoor2DIndex::MAX, ICoor2DIndex::MAX),
                 Coor2DMut::new(ICoor2DIndex::MIN, ICoor2DIndex::MIN),
             ),
-            |(min_coor, max_coor), coor| (min_coor.min(coor), max_coor.max(coor)),
+            |(min_coor, max_coor), coor| {
+                (min_coor.component_min(coor), max_coor.component_max(coor))
+            },
         )
     }
 
@@ -127,7 +129,7 @@ where
 
     /// return all indexes
     pub fn all_indexes(&self) -> impl Iterator<Item = ICoor2D> + '_ {
-        self.data.keys().cloned()
+        self.data.keys().copied()
     }
 
     /// return all neighbor indexes (based on neighborhood)
@@ -136,14 +138,14 @@ where
         coor: &'a ICoor2D,
     ) -> impl Iterator<Item = ICoor2D> + 'a {
         grid_iteration::all_adjacent_directions(self.neighborhood)
-            .map(|direction| coor.clone() + direction.diff_coor())
+            .map(|direction| *coor + direction.diff_coor())
     }
 
     fn map_indexes_to_cells(
         &self,
         it: impl Iterator<Item = ICoor2D>,
     ) -> impl Iterator<Item = (ICoor2D, Option<&T>)> {
-        it.map(|coor| (coor.clone(), self.get(&coor)))
+        it.map(|coor| (coor, self.get(&coor)))
     }
 
     /// all data
@@ -7,76 +7,126 @@ use super::grid_types::{
 
 /// Get the adjacent point to a point in a given direction
 pub fn adjacent_cell(
-    t: Topology,
+    t: &Topology,
     width: UCoor2DIndex,
     height: UCoor2DIndex,
     index: UCoor2D,
     d: Direction,
 ) -> Option<UCoor2D> {
-    let (x, y) = (index.x, index.y);
-    match d {
-        NorthEast => adjacent_cell(t, width, height, index, North)
-            .and_then(|new_coor| adjacent_cell(t, width, height, new_coor, East)),
-        NorthWest => adjacent_cell(t, width, height, index, North)
-            .and_then(|new_coor| adjacent_cell(t, width, height, new_coor, West)),
-        SouthEast => adjacent_cell(t, width, height, index, South)
-            .and_then(|new_coor| adjacent_cell(t, width, height, new_coor, East)),
-        SouthWest => adjacent_cell(t, width, height, index, South)
-            .and_then(|new_coor| adjacent_cell(t, width, height, new_coor, West)),
+    step(t, width, height, index, d).map(|(coor, _facing)| coor)
+}
 
-        _ => match t {
-            Bounded => match d {
-                North => Some(UCoor2D::new(x, y.checked_sub(1)?)),
-                South => {
-                    if y + 1 < height {
-                        Some(UCoor2D::new(x, y + 1))
-                    } else {
-                        None
+/// Get the adjacent point to a point in a given direction and the direction faced after the step
+///
+/// The direction changes only on stitched topologies (e.g. when crossing the edge of a cube net).
+pub fn step(
+    t: &Topology,
+    width: UCoor2DIndex,
+    height: UCoor2DIndex,
+    index: UCoor2D,
+    d: Direction,
+) -> Option<(UCoor2D, Direction)> {
+    let (x, y) = (index.x, index.y);
+    let vertical = match d {
+        NorthEast | NorthWest => North,
+        SouthEast | SouthWest => South,
+        _ => {
+            let next = match t {
+                Bounded => match d {
+                    North => UCoor2D::new(x, y.checked_sub(1)?),
+                    South => {
+                        if y + 1 < height {
+                            UCoor2D::new(x, y + 1)
+                        } else {
+                            return None;
+                        }
                     }
-                }
-                East => {
-                    if x + 1 < width {
-                        Some(UCoor2D::new(x + 1, y))
-                    } else {
-                        None
+                    East => {
+                        if x + 1 < width {
+                            UCoor2D::new(x + 1, y)
+                        } else {
+                            return None;
+                        }
                     }
-                }
-                West => Some(UCoor2D::new(x.checked_sub(1)?, y)),
+                    West => UCoor2D::new(x.checked_sub(1)?, y),
 
-                _ => panic!(), // already handled above
-            },
-            Torus => match d {
-                North => Some(UCoor2D::new(x, y.checked_sub(1).unwrap_or(height - 1))),
-                South => Some(UCoor2D::new(x, (y + 1) % height)),
-                East => Some(UCoor2D::new((x + 1) % width, y)),
-                West => Some(UCoor2D::new(x.checked_sub(1).unwrap_or(width - 1), y)),
+                    _ => unreachable!(), // already handled above
+                },
+                Torus => match d {
+                    North => UCoor2D::new(x, y.checked_sub(1).unwrap_or(height - 1)),
+                    South => UCoor2D::new(x, (y + 1) % height),
+                    East => UCoor2D::new((x + 1) % width, y),
+                    West => UCoor2D::new(x.checked_sub(1).unwrap_or(width - 1), y),
 
-                _ => unreachable!(), // already handled above
-            },
-        },
-    }
+                    _ => unreachable!(), // already handled above
+                },
+                Stitched(edge_map) => return edge_map.step(width, height, &index, d),
+            };
+            return Some((next, d));
+        }
+    };
+    // each part turns (and mirrors) the rest of the diagonal step like the walker
+    let (new_coor, d, horizontal) = diagonal_part(t, width, height, index, d, vertical)?;
+    let (new_coor, d, _) = diagonal_part(t, width, height, new_coor, d, horizontal)?;
+    Some((new_coor, d))
+}
+
+/// Steps the orthogonal part `part` of the diagonal direction `d`, returns the entered cell, the
+/// new diagonal direction and its other orthogonal part
+fn diagonal_part(
+    t: &Topology,
+    width: UCoor2DIndex,
+    height: UCoor2DIndex,
+    index: UCoor2D,
+    d: Direction,
+    part: Direction,
+) -> Option<(UCoor2D, Direction, Direction)> {
+    let (new_coor, facing) = step(t, width, height, index, part)?;
+    // mirroring flips the other part, i.e. reflects the direction on `part`
+    let d = match t {
+        Stitched(edge_map) if edge_map.mirrors(&index, part) => {
+            part.rotate_eighths(part as isize - d as isize)
+        }
+        _ => d,
+    };
+    let d = d.rotate_eighths(facing as isize - part as isize);
+    let other = facing.rotate_eighths(2 * (d as isize - facing as isize));
+    Some((new_coor, d, other))
 }
 
 /// Is a given point on an edge of a grid
 pub(crate) fn is_edge(
-    t: Topology,
+    t: &Topology,
     width: UCoor2DIndex,
     height: UCoor2DIndex,
     index: &UCoor2D,
 ) -> bool {
     let (x, y) = (index.x, index.y);
-    t == Topology::Bounded && (x == 0 || x + 1 == width || y == 0 || y + 1 == height)
+    match t {
+        Bounded => x == 0 || x + 1 == width || y == 0 || y + 1 == height,
+        Torus => false,
+        Stitched(_) => [North, East, South, West]
+            .into_iter()
+            .any(|d| adjacent_cell(t, width, height, *index, d).is_none()),
+    }
 }
 
 /// Is a given point a corner of a grid
 pub(crate) fn is_corner(
-    t: Topology,
+    t: &Topology,
     width: UCoor2DIndex,
     height: UCoor2DIndex,
     index: &UCoor2D,
 ) -> bool {
     let (x, y) = (index.x, index.y);
-    t == Topology::Bounded && (x == 0 || x + 1 == width) && (y == 0 || y + 1 == height)
+    match t {
+        Bounded => (x == 0 || x + 1 == width) && (y == 0 || y + 1 == height),
+        Torus => false,
+        Stitched(_) => {
+            let missing = |d| adjacent_cell(t, width, height, *index, d).is_none();
+            (missing(North) || missing(South)) && (missing(East) || missing(West))
+        }
+    }
 }
 
 /// Returns an iterator over the points of a grid
@@ -101,26 +151,28 @@ pub fn all_adjacent_directions(n: Neighborhood) -> impl Iterator<Item = Directio
 
 /// Returns an iterator over the points in a neighborhood around a point
 pub(crate) fn neighborhood_cells(
-    t: Topology,
+    t: &Topology,
     width: UCoor2DIndex,
     height: UCoor2DIndex,
     index: UCoor2D,
     n: Neighborhood,
 ) -> impl Iterator<Item = UCoor2D> {
+    let t = t.clone();
     all_adjacent_directions(n)
-        .filter_map(move |direction| adjacent_cell(t, width, height, index.clone(), direction))
+        .filter_map(move |direction| adjacent_cell(&t, width, height, index, direction))
 }
 
 /// Returns an iterator over the points in a neighborhood around a point
 pub(crate) fn neighborhood_cells_and_dirs(
-    t: Topology,
+    t: &Topology,
     width: UCoor2DIndex,
     height: UCoor2DIndex,
     index: UCoor2D,
     n: Neighborhood,
 ) -> impl Iterator<Item = (UCoor2D, Direction)> {
+    let t = t.clone();
     all_adjacent_directions(n)
-        .filter_map(move |direction| adjacent_cell(t, width, height, index.clone(), direction).map(|coor| (coor, direction)))
+        .filter_map(move |direction| adjacent_cell(&t, width, height, index, direction).map(|coor| (coor, direction)))
 }
 
 #[cfg(test)]
@@ -132,29 +184,29 @@ mod tests {
     #[test]
     fn adjacent_bounded() {
         assert_eq!(
-            adjacent_cell(Bounded, 3, 3, UCoor2D::new(1, 0), North),
+            adjacent_cell(&Bounded, 3, 3, UCoor2D::new(1, 0), North),
             None
         );
         assert_eq!(
-            adjacent_cell(Bounded, 3, 3, UCoor2D::new(1, 1), North),
+            adjacent_cell(&Bounded, 3, 3, UCoor2D::new(1, 1), North),
             Some(UCoor2D::new(1, 0))
         );
         assert_eq!(
-            adjacent_cell(Bounded, 3, 3, UCoor2D::new(2, 2), South),
+            adjacent_cell(&Bounded, 3, 3, UCoor2D::new(2, 2), South),
             None
         );
         assert_eq!(
-            adjacent_cell(Bounded, 3, 3, UCoor2D::new(0, 0), South),
+            adjacent_cell(&Bounded, 3, 3, UCoor2D::new(0, 0), South),
             Some(UCoor2D::new(0, 1))
         );
-        assert_eq!(adjacent_cell(Bounded, 3, 3, UCoor2D::new(2, 2), East), None);
+        assert_eq!(adjacent_cell(&Bounded, 3, 3, UCoor2D::new(2, 2), East), None);
         assert_eq!(
-            adjacent_cell(Bounded, 3, 3, UCoor2D::new(1, 1), East),
+            adjacent_cell(&Bounded, 3, 3, UCoor2D::new(1, 1), East),
             Some(UCoor2D::new(2, 1))
         );
-        assert_eq!(adjacent_cell(Bounded, 3, 3, UCoor2D::new(0, 0), West), None);
+        assert_eq!(adjacent_cell(&Bounded, 3, 3, UCoor2D::new(0, 0), West), None);
         assert_eq!(
-            adjacent_cell(Bounded, 3, 3, UCoor2D::new(1, 1), West),
+            adjacent_cell(&Bounded, 3, 3, UCoor2D::new(1, 1), West),
             Some(UCoor2D::new(0, 1))
         );
     }
@@ -162,47 +214,47 @@ mod tests {
     #[test]
     fn adjacent_torus() {
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(1, 0), North),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(1, 0), North),
             Some(UCoor2D::new(1, 2))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(1, 1), North),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(1, 1), North),
             Some(UCoor2D::new(1, 0))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(2, 2), South),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(2, 2), South),
             Some(UCoor2D::new(2, 0))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(0, 0), South),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(0, 0), South),
             Some(UCoor2D::new(0, 1))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(2, 2), East),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(2, 2), East),
             Some(UCoor2D::new(0, 2))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(1, 1), East),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(1, 1), East),
             Some(UCoor2D::new(2, 1))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(0, 0), West),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(0, 0), West),
             Some(UCoor2D::new(2, 0))
         );
         assert_eq!(
-            adjacent_cell(Torus, 3, 3, UCoor2D::new(1, 1), West),
+            adjacent_cell(&Torus, 3, 3, UCoor2D::new(1, 1), West),
             Some(UCoor2D::new(0, 1))
         );
     }
 
     #[test]
     fn edge() {
-        assert!(is_edge(Bounded, 3, 3, &UCoor2D::new(1, 0)));
-        assert!(is_edge(Bounded, 3, 3, &UCoor2D::new(0, 1)));
-        assert!(is_edge(Bounded, 3, 3, &UCoor2D::new(1, 2)));
-        assert!(is_edge(Bounded, 3, 3, &UCoor2D::new(2, 1)));
-        assert!(!is_edge(Bounded, 3, 3, &UCoor2D::new(1, 1)));
-        assert!(!is_edge(Torus, 3, 3, &UCoor2D::new(2, 1)));
+        assert!(is_edge(&Bounded, 3, 3, &UCoor2D::new(1, 0)));
+        assert!(is_edge(&Bounded, 3, 3, &UCoor2D::new(0, 1)));
+        assert!(is_edge(&Bounded, 3, 3, &UCoor2D::new(1, 2)));
+        assert!(is_edge(&Bounded, 3, 3, &UCoor2D::new(2, 1)));
+        assert!(!is_edge(&Bounded, 3, 3, &UCoor2D::new(1, 1)));
+        assert!(!is_edge(&Torus, 3, 3, &UCoor2D::new(2, 1)));
     }
 
     #[test]
@@ -213,7 +265,7 @@ mod tests {
     #[test]
     fn neighborino() {
         assert_eq!(
-            neighborhood_cells(Torus, 5, 5, UCoor2D::new(0, 0), Square)
+            neighborhood_cells(&Torus, 5, 5, UCoor2D::new(0, 0), Square)
                 .collect::<HashSet<UCoor2D>>(),
             HashSet::from([
                 UCoor2D::new(0, 4),
@@ -227,7 +279,7 @@ mod tests {
             ]),
         );
         assert_eq!(
-            neighborhood_cells(Bounded, 5, 5, UCoor2D::new(0, 0), Square)
+            neighborhood_cells(&Bounded, 5, 5, UCoor2D::new(0, 0), Square)
                 .collect::<HashSet<UCoor2D>>(),
             HashSet::from([UCoor2D::new(0, 1), UCoor2D::new(1, 0), UCoor2D::new(1, 1)]),
         );
@@ -0,0 +1,431 @@
+//! Dense grid in any number of dimensions
+//!
+//! [`GridND`] stores the cells of a `D`-dimensional box in a vector (the first axis changes
+//! fastest) and addresses them with [`CoorND`]. Like the 2D grids it is either bounded or a
+//! torus, and its neighborhood is either the Von Neumann neighborhood (the `2 * D` cells one
+//! step along a single axis) or the Moore neighborhood (all `3^D - 1` surrounding cells).
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
+
+use itertools::Itertools;
+
+use crate::grid_types::Topology;
+
+/// Coordinate (or offset) in `D` dimensions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CoorND<const D: usize>(pub [isize; D]);
+
+/// Coordinate in three dimensions
+pub type Coor3D = CoorND<3>;
+
+/// Coordinate in four dimensions
+pub type Coor4D = CoorND<4>;
+
+impl<const D: usize> CoorND<D> {
+    /// The coordinate with all components 0
+    pub const ORIGIN: Self = CoorND([0; D]);
+
+    /// new coordinate from its components
+    pub fn new(components: [isize; D]) -> Self {
+        CoorND(components)
+    }
+
+    /// components of the coordinate
+    pub fn components(&self) -> [isize; D] {
+        self.0
+    }
+
+    /// Returns the sum of the absolute components
+    pub fn manhattan_length(&self) -> usize {
+        self.0
+            .iter()
+            .map(|component| component.unsigned_abs())
+            .sum()
+    }
+
+    /// Returns the largest absolute component
+    pub fn chebyshev_length(&self) -> usize {
+        self.0
+            .iter()
+            .map(|component| component.unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the manhattan distance (number of steps in the Von Neumann neighborhood)
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        (*self - *other).manhattan_length()
+    }
+
+    /// Returns the chebyshev distance (number of steps in the Moore neighborhood)
+    pub fn chebyshev_distance(&self, other: &Self) -> usize {
+        (*self - *other).chebyshev_length()
+    }
+}
+
+impl Coor3D {
+    /// x
+    pub fn x(&self) -> isize {
+        self.0[0]
+    }
+
+    /// y
+    pub fn y(&self) -> isize {
+        self.0[1]
+    }
+
+    /// z
+    pub fn z(&self) -> isize {
+        self.0[2]
+    }
+}
+
+impl<const D: usize> Default for CoorND<D> {
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl<const D: usize> From<[isize; D]> for CoorND<D> {
+    fn from(components: [isize; D]) -> Self {
+        CoorND(components)
+    }
+}
+
+impl<const D: usize> Index<usize> for CoorND<D> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const D: usize> Display for CoorND<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0.iter().join(","))
+    }
+}
+
+impl<const D: usize> Add for CoorND<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        CoorND(std::array::from_fn(|axis| self.0[axis] + other.0[axis]))
+    }
+}
+
+impl<const D: usize> Sub for CoorND<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        CoorND(std::array::from_fn(|axis| self.0[axis] - other.0[axis]))
+    }
+}
+
+impl<const D: usize> Neg for CoorND<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        CoorND(self.0.map(|component| -component))
+    }
+}
+
+impl<const D: usize> AddAssign for CoorND<D> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const D: usize> SubAssign for CoorND<D> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Neighborhoods in `D` dimensions. They do not contain the point itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NeighborhoodND {
+    /// Cells one step along a single axis (`2 * D` cells, manhattan distance 1)
+    VonNeumann,
+    /// All surrounding cells (`3^D - 1` cells, chebyshev distance 1)
+    Moore,
+}
+
+impl NeighborhoodND {
+    /// Offsets of all neighbors of a cell
+    pub fn offsets<const D: usize>(self) -> Vec<CoorND<D>> {
+        match self {
+            NeighborhoodND::VonNeumann => (0..D)
+                .flat_map(|axis| {
+                    [-1, 1].map(|step| {
+                        let mut offset = CoorND::ORIGIN;
+                        offset.0[axis] = step;
+                        offset
+                    })
+                })
+                .collect(),
+            NeighborhoodND::Moore => (0..D)
+                .map(|_| -1..=1)
+                .multi_cartesian_product()
+                .map(|components| CoorND(std::array::from_fn(|axis| components[axis])))
+                .filter(|&offset| offset != CoorND::ORIGIN)
+                .collect(),
+        }
+    }
+}
+
+/// Dense grid of `D` dimensions
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct GridND<T, const D: usize> {
+    size: [usize; D],
+    topology: Topology,
+    neighborhood: NeighborhoodND,
+    offsets: Vec<CoorND<D>>,
+    data: Vec<T>,
+}
+
+impl<T: Default + Clone, const D: usize> GridND<T, D> {
+    /// Grid of `size` with default cells
+    pub fn new(topology: Topology, neighborhood: NeighborhoodND, size: [usize; D]) -> Self {
+        let len = size.iter().product();
+        Self::from_vec(topology, neighborhood, size, vec![T::default(); len])
+    }
+}
+
+impl<T, const D: usize> GridND<T, D> {
+    /// Grid of `size` with the cells of `data` (the first axis changes fastest)
+    ///
+    /// # Panics
+    /// If the length of `data` does not fit `size` or the topology is stitched (edge maps are
+    /// two-dimensional)
+    pub fn from_vec(
+        topology: Topology,
+        neighborhood: NeighborhoodND,
+        size: [usize; D],
+        data: Vec<T>,
+    ) -> Self {
+        assert_eq!(
+            size.iter().product::<usize>(),
+            data.len(),
+            "data does not fit the size of the grid"
+        );
+        assert!(
+            !matches!(topology, Topology::Stitched(_)),
+            "GridND supports bounded and torus topologies"
+        );
+        GridND {
+            size,
+            topology,
+            neighborhood,
+            offsets: neighborhood.offsets(),
+            data,
+        }
+    }
+
+    /// extent of the grid along every axis
+    pub fn size(&self) -> [usize; D] {
+        self.size
+    }
+
+    /// `get_topology`
+    pub fn get_topology(&self) -> Topology {
+        self.topology.clone()
+    }
+
+    /// `get_neighborhood`
+    pub fn get_neighborhood(&self) -> NeighborhoodND {
+        self.neighborhood
+    }
+
+    /// is `coor` inside of the grid
+    pub fn contains(&self, coor: &CoorND<D>) -> bool {
+        self.vec_index(coor).is_some()
+    }
+
+    /// get reference to the cell at `coor`
+    pub fn get(&self, coor: &CoorND<D>) -> Option<&T> {
+        self.vec_index(coor).map(|index| &self.data[index])
+    }
+
+    /// get mutable reference to the cell at `coor`
+    pub fn get_mut(&mut self, coor: &CoorND<D>) -> Option<&mut T> {
+        self.vec_index(coor).map(|index| &mut self.data[index])
+    }
+
+    /// set new cell at `coor` and return the old cell
+    ///
+    /// # Panics
+    /// If `coor` is outside of the grid
+    pub fn set(&mut self, coor: &CoorND<D>, value: T) -> T {
+        let cell = self
+            .get_mut(coor)
+            .unwrap_or_else(|| panic!("{coor} is outside of the grid"));
+        std::mem::replace(cell, value)
+    }
+
+    /// iterate over all cells (the first axis changes fastest)
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// return all coordinates (the first axis changes fastest)
+    pub fn all_indexes(&self) -> impl Iterator<Item = CoorND<D>> + '_ {
+        (0..self.data.len()).map(|index| self.coor(index))
+    }
+
+    /// return all coordinates and cells
+    pub fn all_cells(&self) -> impl Iterator<Item = (CoorND<D>, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.coor(index), cell))
+    }
+
+    /// Coordinate of the cell `offset` away from `coor` (`None` if it is beyond the edge)
+    pub fn adjacent(&self, coor: &CoorND<D>, offset: &CoorND<D>) -> Option<CoorND<D>> {
+        let moved = *coor + *offset;
+        match self.topology {
+            Topology::Bounded => self.contains(&moved).then_some(moved),
+            Topology::Torus => Some(CoorND(std::array::from_fn(|axis| {
+                moved.0[axis].rem_euclid(self.size[axis].try_into().unwrap())
+            }))),
+            Topology::Stitched(_) => unreachable!("GridND is never stitched"),
+        }
+    }
+
+    /// Coordinates of the neighbors of `coor` (based on topology and neighborhood)
+    ///
+    /// On a torus with less than 3 cells along an axis a neighbor can be returned twice.
+    pub fn neighbors<'a>(&'a self, coor: &'a CoorND<D>) -> impl Iterator<Item = CoorND<D>> + 'a {
+        self.offsets
+            .iter()
+            .filter_map(|offset| self.adjacent(coor, offset))
+    }
+
+    /// Coordinates and cells of the neighbors of `coor`
+    pub fn neighbor_cells<'a>(
+        &'a self,
+        coor: &'a CoorND<D>,
+    ) -> impl Iterator<Item = (CoorND<D>, &'a T)> + 'a {
+        self.neighbors(coor)
+            .map(|neighbor| (neighbor, &self.data[self.vec_index(&neighbor).unwrap()]))
+    }
+
+    /// Grid of the same shape with every cell converted by `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> GridND<U, D> {
+        GridND::from_vec(
+            self.topology.clone(),
+            self.neighborhood,
+            self.size,
+            self.data.iter().map(f).collect(),
+        )
+    }
+
+    fn vec_index(&self, coor: &CoorND<D>) -> Option<usize> {
+        let mut index = 0;
+        for axis in (0..D).rev() {
+            let component = usize::try_from(coor.0[axis]).ok()?;
+            if component >= self.size[axis] {
+                return None;
+            }
+            index = index * self.size[axis] + component;
+        }
+        Some(index)
+    }
+
+    fn coor(&self, mut index: usize) -> CoorND<D> {
+        CoorND(std::array::from_fn(|axis| {
+            let component = index % self.size[axis];
+            index /= self.size[axis];
+            component.try_into().unwrap()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinates() {
+        let a = Coor3D::new([1, -2, 3]);
+        let b = Coor3D::new([-1, 2, 4]);
+        assert_eq!(Coor3D::new([0, 0, 7]), a + b);
+        assert_eq!(Coor3D::new([2, -4, -1]), a - b);
+        assert_eq!(7, a.manhattan_distance(&b));
+        assert_eq!(4, a.chebyshev_distance(&b));
+        assert_eq!((1, -2, 3), (a.x(), a.y(), a.z()));
+        assert_eq!("(1,-2,3)", a.to_string());
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        assert_eq!(6, NeighborhoodND::VonNeumann.offsets::<3>().len());
+        assert_eq!(26, NeighborhoodND::Moore.offsets::<3>().len());
+        assert_eq!(80, NeighborhoodND::Moore.offsets::<4>().len());
+        assert!(NeighborhoodND::Moore
+            .offsets::<4>()
+            .iter()
+            .all(|offset| offset.chebyshev_length() == 1));
+
+        let bounded = GridND::<u8, 3>::new(Topology::Bounded, NeighborhoodND::Moore, [3, 3, 3]);
+        assert_eq!(7, bounded.neighbors(&Coor3D::ORIGIN).count());
+        assert_eq!(26, bounded.neighbors(&Coor3D::new([1, 1, 1])).count());
+        let torus = GridND::<u8, 3>::new(Topology::Torus, NeighborhoodND::VonNeumann, [4, 4, 4]);
+        let mut neighbors: Vec<_> = torus.neighbors(&Coor3D::ORIGIN).collect();
+        neighbors.sort();
+        assert_eq!(Coor3D::new([0, 0, 1]), neighbors[0]);
+        assert_eq!(Coor3D::new([3, 0, 0]), neighbors[5]);
+    }
+
+    #[test]
+    fn test_indexes() {
+        let mut grid = GridND::<usize, 3>::new(Topology::Bounded, NeighborhoodND::Moore, [2, 3, 4]);
+        assert_eq!(24, grid.all_indexes().count());
+        assert_eq!(Some(Coor3D::new([1, 0, 0])), grid.all_indexes().nth(1));
+        assert_eq!(0, grid.set(&Coor3D::new([1, 2, 3]), 5));
+        assert_eq!(Some(&5), grid.get(&Coor3D::new([1, 2, 3])));
+        assert_eq!(Some(&5), grid.iter().last());
+        assert_eq!(None, grid.get(&Coor3D::new([2, 0, 0])));
+        assert_eq!(None, grid.get(&Coor3D::new([0, -1, 0])));
+    }
+
+    /// Conway cubes: a cell is active in the next cycle if 3 neighbors (or 2 neighbors and
+    /// itself) are active
+    fn simulate<const D: usize>(start: &str, cycles: usize) -> usize {
+        let margin = cycles + 1;
+        let lines: Vec<&str> = start.lines().collect();
+        let mut size = [2 * margin + 1; D];
+        size[0] = lines[0].len() + 2 * margin;
+        size[1] = lines.len() + 2 * margin;
+        let mut grid = GridND::<bool, D>::new(Topology::Bounded, NeighborhoodND::Moore, size);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                let mut coor = CoorND([margin.try_into().unwrap(); D]);
+                coor.0[0] += isize::try_from(x).unwrap();
+                coor.0[1] += isize::try_from(y).unwrap();
+                grid.set(&coor, cell == '#');
+            }
+        }
+        for _ in 0..cycles {
+            let active: Vec<bool> = grid
+                .all_cells()
+                .map(|(coor, &active)| {
+                    let neighbors = grid.neighbor_cells(&coor).filter(|(_, &n)| n).count();
+                    neighbors == 3 || (active && neighbors == 2)
+                })
+                .collect();
+            grid = GridND::from_vec(grid.get_topology(), grid.get_neighborhood(), size, active);
+        }
+        grid.iter().filter(|&&active| active).count()
+    }
+
+    #[test]
+    fn test_conway_cubes() {
+        let start = ".#.\n..#\n###\n";
+        assert_eq!(112, simulate::<3>(start, 6));
+        assert_eq!(29, simulate::<4>(start, 1));
+        assert_eq!(60, simulate::<4>(start, 2));
+    }
+}
@@ -0,0 +1,258 @@
+//! Common interface of dense and sparse grids
+//!
+//! Algorithms written against [`Grid`] work on [`GridArray`] (a fixed rectangle of cells
+//! with `UCoor2D` coordinates) as well as on [`GridHashMap`] (an unbounded set of cells
+//! with `ICoor2D` coordinates) and on [`HexGrid`](crate::hex::HexGrid) (hexagonal cells).
+
+use std::hash::Hash;
+
+use crate::grid_array::GridArray;
+use crate::grid_hashmap::GridHashMap;
+use crate::grid_iteration::{adjacent_cell, all_adjacent_directions};
+use crate::grid_types::{Direction, ICoor2D, Topology, UCoor2D};
+
+/// A 2D grid of cells
+///
+/// The method names differ from the inherent methods of the grids (e.g. [`Grid::cell`] instead of
+/// `get`), so both can be used side by side.
+pub trait Grid {
+    /// Content of a cell
+    type Cell;
+    /// Coordinate of a cell
+    type Coor: Clone + Eq + Hash;
+    /// Direction from a cell to an adjacent cell
+    type Direction: Copy + Eq + Hash;
+
+    /// Reference to the cell at `coor` (`None` if there is no cell)
+    fn cell(&self, coor: &Self::Coor) -> Option<&Self::Cell>;
+
+    /// Mutable reference to the cell at `coor` (`None` if there is no cell)
+    fn cell_mut(&mut self, coor: &Self::Coor) -> Option<&mut Self::Cell>;
+
+    /// Sets the cell at `coor` and returns the old cell
+    ///
+    /// # Panics
+    /// If `coor` is outside of a bounded grid
+    fn set_cell(&mut self, coor: Self::Coor, value: Self::Cell) -> Option<Self::Cell>;
+
+    /// Is there a cell at `coor`
+    fn contains(&self, coor: &Self::Coor) -> bool {
+        self.cell(coor).is_some()
+    }
+
+    /// Smallest and largest coordinate of all cells (`None` if the grid has no cells)
+    fn bounds(&self) -> Option<(Self::Coor, Self::Coor)>;
+
+    /// How the edges of the grid are connected (`None` if the grid has no edges)
+    fn topology(&self) -> Option<Topology>;
+
+    /// Directions of the adjacent cells (e.g. based on the neighborhood of a square grid)
+    fn directions(&self) -> impl Iterator<Item = Self::Direction>;
+
+    /// Coordinate of the adjacent cell in `direction` (`None` if it is beyond the edge)
+    fn adjacent(&self, coor: &Self::Coor, direction: Self::Direction) -> Option<Self::Coor>;
+
+    /// Coordinates of all cells
+    fn coors(&self) -> impl Iterator<Item = Self::Coor>;
+
+    /// Coordinates and contents of all cells
+    fn cells(&self) -> impl Iterator<Item = (Self::Coor, &Self::Cell)>;
+
+    /// Coordinates and directions of the neighbors of `coor` (based on topology and neighborhood),
+    /// including coordinates without a cell in sparse grids
+    fn neighbor_coors(
+        &self,
+        coor: &Self::Coor,
+    ) -> impl Iterator<Item = (Self::Coor, Self::Direction)> {
+        self.directions().filter_map(move |direction| {
+            self.adjacent(coor, direction)
+                .map(|neighbor| (neighbor, direction))
+        })
+    }
+
+    /// Coordinates, directions and contents of the existing neighbor cells of `coor`
+    fn neighbor_cells(
+        &self,
+        coor: &Self::Coor,
+    ) -> impl Iterator<Item = (Self::Coor, Self::Direction, &Self::Cell)> {
+        self.neighbor_coors(coor)
+            .filter_map(|(neighbor, direction)| {
+                let cell = self.cell(&neighbor)?;
+                Some((neighbor, direction, cell))
+            })
+    }
+}
+
+impl<T: Default + Clone> Grid for GridArray<T> {
+    type Cell = T;
+    type Coor = UCoor2D;
+    type Direction = Direction;
+
+    fn cell(&self, coor: &UCoor2D) -> Option<&T> {
+        self.get(coor.x, coor.y)
+    }
+
+    fn cell_mut(&mut self, coor: &UCoor2D) -> Option<&mut T> {
+        self.get_mut(coor.x, coor.y)
+    }
+
+    fn set_cell(&mut self, coor: UCoor2D, value: T) -> Option<T> {
+        assert!(
+            coor.x < self.width() && coor.y < self.height(),
+            "{coor} is outside of the grid"
+        );
+        Some(self.set(coor.x, coor.y, value))
+    }
+
+    fn contains(&self, coor: &UCoor2D) -> bool {
+        coor.x < self.width() && coor.y < self.height()
+    }
+
+    fn bounds(&self) -> Option<(UCoor2D, UCoor2D)> {
+        (self.width() > 0 && self.height() > 0).then(|| {
+            (
+                UCoor2D::new(0, 0),
+                UCoor2D::new(self.width() - 1, self.height() - 1),
+            )
+        })
+    }
+
+    fn topology(&self) -> Option<Topology> {
+        Some(self.get_topology())
+    }
+
+    fn directions(&self) -> impl Iterator<Item = Direction> {
+        all_adjacent_directions(self.get_neighborhood())
+    }
+
+    fn adjacent(&self, coor: &UCoor2D, direction: Direction) -> Option<UCoor2D> {
+        adjacent_cell(
+            &self.get_topology(),
+            self.width(),
+            self.height(),
+            *coor,
+            direction,
+        )
+    }
+
+    fn coors(&self) -> impl Iterator<Item = UCoor2D> {
+        self.all_indexes()
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (UCoor2D, &T)> {
+        self.all_cells()
+    }
+}
+
+impl<T: Default + Clone + std::fmt::Display> Grid for GridHashMap<T> {
+    type Cell = T;
+    type Coor = ICoor2D;
+    type Direction = Direction;
+
+    fn cell(&self, coor: &ICoor2D) -> Option<&T> {
+        self.get(coor)
+    }
+
+    fn cell_mut(&mut self, coor: &ICoor2D) -> Option<&mut T> {
+        self.get_mut(coor)
+    }
+
+    fn set_cell(&mut self, coor: ICoor2D, value: T) -> Option<T> {
+        self.set(coor, value)
+    }
+
+    fn bounds(&self) -> Option<(ICoor2D, ICoor2D)> {
+        (self.iter().next().is_some()).then(|| self.get_min_max())
+    }
+
+    fn topology(&self) -> Option<Topology> {
+        None
+    }
+
+    fn directions(&self) -> impl Iterator<Item = Direction> {
+        all_adjacent_directions(self.get_neighborhood())
+    }
+
+    fn adjacent(&self, coor: &ICoor2D, direction: Direction) -> Option<ICoor2D> {
+        Some(coor + &direction.diff_coor())
+    }
+
+    fn coors(&self) -> impl Iterator<Item = ICoor2D> {
+        self.all_indexes()
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (ICoor2D, &T)> {
+        self.all_cells().map(|(coor, cell)| (*coor, cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fxhash::FxHashSet;
+
+    use super::*;
+    use crate::grid_types::Neighborhood;
+
+    const GARDEN: &str = "..#.\n.##.\n#...\n";
+
+    /// Number of `.` cells reachable from `start` (written once for every kind of grid)
+    fn reachable<G: Grid<Cell = char>>(grid: &G, start: &G::Coor) -> usize {
+        let mut visited = FxHashSet::default();
+        let mut todo = vec![start.clone()];
+        while let Some(coor) = todo.pop() {
+            if visited.insert(coor.clone()) {
+                todo.extend(
+                    grid.neighbor_cells(&coor)
+                        .filter(|(_, _, &cell)| cell == '.')
+                        .map(|(neighbor, _, _)| neighbor),
+                );
+            }
+        }
+        visited.len()
+    }
+
+    #[test]
+    fn test_generic_algorithm() {
+        let array = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            GARDEN,
+        );
+        let hashmap = GridHashMap::from_newline_separated_string(Neighborhood::Orthogonal, GARDEN);
+        assert_eq!(3, reachable(&array, &UCoor2D::new(0, 0)));
+        assert_eq!(3, reachable(&hashmap, &ICoor2D::new(0, 0)));
+        assert_eq!(5, reachable(&array, &UCoor2D::new(3, 0)));
+        assert_eq!(5, reachable(&hashmap, &ICoor2D::new(3, 0)));
+        assert_eq!(array.cells().count(), hashmap.cells().count());
+    }
+
+    #[test]
+    fn test_bounds_and_topology() {
+        let mut array = GridArray::from_newline_separated_string(
+            Topology::Torus,
+            Neighborhood::Orthogonal,
+            GARDEN,
+        );
+        assert_eq!(
+            Some((UCoor2D::new(0, 0), UCoor2D::new(3, 2))),
+            array.bounds()
+        );
+        assert_eq!(Some(Topology::Torus), array.topology());
+        assert!(array.contains(&UCoor2D::new(3, 2)));
+        assert!(!array.contains(&UCoor2D::new(4, 2)));
+        assert_eq!(4, array.neighbor_coors(&UCoor2D::new(0, 0)).count());
+        assert_eq!(Some('#'), array.set_cell(UCoor2D::new(0, 2), 'x'));
+        assert_eq!(Some(&'x'), array.cell(&UCoor2D::new(0, 2)));
+
+        let mut hashmap = GridHashMap::from_newline_separated_string(Neighborhood::Square, GARDEN);
+        assert_eq!(None, hashmap.topology());
+        assert_eq!(8, hashmap.neighbor_coors(&ICoor2D::new(0, 0)).count());
+        assert_eq!(3, hashmap.neighbor_cells(&ICoor2D::new(0, 0)).count());
+        assert_eq!(None, hashmap.set_cell(ICoor2D::new(-1, 5), 'x'));
+        assert_eq!(
+            Some((ICoor2D::new(-1, 0), ICoor2D::new(3, 5))),
+            hashmap.bounds()
+        );
+        assert_eq!(None, GridHashMap::<char>::default().bounds());
+    }
+}
@@ -3,9 +3,13 @@
 use std::{
     cmp::Ordering,
     fmt::Display,
-    ops::{Add, Sub},
+    num::TryFromIntError,
+    ops::{Add, Mul, Sub},
+    sync::Arc,
 };
 
+use crate::edge_map::EdgeMap;
+
 use derive_more::{
     Add as DeriveAdd, AddAssign, Constructor, Display as DeriveDisplay, Sub as DeriveSub, SubAssign,
 };
@@ -20,13 +24,15 @@ pub type UCoor2D = Coor2DMut<UCoor2DIndex>;
 pub type ICoor2D = Coor2DMut<ICoor2DIndex>;
 
 /// Coor
+///
+/// Coordinates of primitive indices are `Copy`. They are ordered row by row (by y, then x), like
+/// the cells of a grid are read.
 #[derive(
     Eq,
     PartialEq,
     Hash,
-    // Ord,
-    PartialOrd,
     Clone,
+    Copy,
     Debug,
     //    From,
     //    Into,
@@ -47,6 +53,18 @@ pub struct Coor2DMut<T: Clone + Ord + Eq + Display> {
     pub y: T,
 }
 
+impl<T: Clone + Ord + Eq + Display> Ord for Coor2DMut<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.y, &self.x).cmp(&(&other.y, &other.x))
+    }
+}
+
+impl<T: Clone + Ord + Eq + Display> PartialOrd for Coor2DMut<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<T: Clone + Ord + Eq + Display> From<(T, T)> for Coor2DMut<T> {
     fn from(t: (T, T)) -> Self {
         Coor2DMut { x: t.0, y: t.1 }
@@ -81,6 +99,17 @@ where
     }
 }
 
+impl<T: Clone + Ord + Eq + Display + Mul<Output = T>> Mul<T> for Coor2DMut<T> {
+    type Output = Coor2DMut<T>;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Coor2DMut {
+            x: self.x * factor.clone(),
+            y: self.y * factor,
+        }
+    }
+}
+
 impl<T: Clone + Ord + Eq + Display> Coor2DMut<T> {
     /// to tuples
     pub fn to_tuple(&self) -> (T, T) {
@@ -97,18 +126,18 @@ impl<T: Clone + Ord + Eq + Display> Coor2DMut<T> {
         [self.x.clone(), self.y.clone()]
     }
 
-    /// min
+    /// component-wise minimum (unlike `Ord::min`)
     #[must_use]
-    pub fn min(&self, other: &Self) -> Self {
+    pub fn component_min(&self, other: &Self) -> Self {
         Self::new(
             self.x.clone().min(other.x.clone()),
             self.y.clone().min(other.y.clone()),
         )
     }
 
-    /// max
+    /// component-wise maximum (unlike `Ord::max`)
     #[must_use]
-    pub fn max(&self, other: &Self) -> Self {
+    pub fn component_max(&self, other: &Self) -> Self {
         Self::new(
             self.x.clone().max(other.x.clone()),
             self.y.clone().max(other.y.clone()),
@@ -159,34 +188,87 @@ impl<T: Clone + Ord + Eq + Display> Coor2DMut<T> {
 impl UCoor2D {
     /// to icoor2d
     pub fn to_icoor2d(&self) -> Option<ICoor2D> {
-        let x_converted = usize::try_into(self.x);
-        let y_converted = usize::try_into(self.y);
-        if x_converted.is_err() || y_converted.is_err() {
-            return None;
-        }
-        Some(Coor2DMut::new(x_converted.unwrap(), y_converted.unwrap()))
+        ICoor2D::try_from(*self).ok()
+    }
+
+    /// Returns the coordinate `n` steps in `direction` (`None` if it is negative)
+    #[must_use]
+    pub fn step(self, direction: Direction, n: UCoor2DIndex) -> Option<Self> {
+        let diff = direction.diff_coor();
+        let along = |index: UCoor2DIndex, diff: ICoor2DIndex| match diff {
+            1 => index.checked_add(n),
+            -1 => index.checked_sub(n),
+            _ => Some(index),
+        };
+        Some(Self::new(along(self.x, diff.x)?, along(self.y, diff.y)?))
+    }
+
+    /// Returns the coordinate `n` steps in `direction` on a torus of `width` x `height`
+    ///
+    /// # Panics
+    /// If the torus is empty
+    #[must_use]
+    pub fn wrapping_step(
+        self,
+        direction: Direction,
+        n: UCoor2DIndex,
+        width: UCoor2DIndex,
+        height: UCoor2DIndex,
+    ) -> Self {
+        assert!(width > 0 && height > 0, "the torus is empty");
+        let diff = direction.diff_coor();
+        let along = |index: UCoor2DIndex, diff: ICoor2DIndex, size: UCoor2DIndex| match diff {
+            1 => (index % size + n % size) % size,
+            -1 => (index % size + size - n % size) % size,
+            _ => index,
+        };
+        Self::new(along(self.x, diff.x, width), along(self.y, diff.y, height))
     }
 }
 
 impl ICoor2D {
     /// to ucoor2d
     pub fn to_ucoor2d(&self) -> Option<UCoor2D> {
-        let x_converted = isize::try_into(self.x);
-        let y_converted = isize::try_into(self.y);
-        if x_converted.is_err() || y_converted.is_err() {
-            return None;
-        }
-        Some(Coor2DMut::new(x_converted.unwrap(), y_converted.unwrap()))
+        UCoor2D::try_from(*self).ok()
+    }
+
+    /// Returns the coordinate `n` steps in `direction` (`None` on overflow)
+    #[must_use]
+    pub fn step(self, direction: Direction, n: UCoor2DIndex) -> Option<Self> {
+        let n = ICoor2DIndex::try_from(n).ok()?;
+        let diff = direction.diff_coor();
+        Some(Self::new(
+            self.x.checked_add(diff.x.checked_mul(n)?)?,
+            self.y.checked_add(diff.y.checked_mul(n)?)?,
+        ))
+    }
+}
+
+impl TryFrom<ICoor2D> for UCoor2D {
+    type Error = TryFromIntError;
+
+    fn try_from(coor: ICoor2D) -> Result<Self, Self::Error> {
+        Ok(Self::new(coor.x.try_into()?, coor.y.try_into()?))
+    }
+}
+
+impl TryFrom<UCoor2D> for ICoor2D {
+    type Error = TryFromIntError;
+
+    fn try_from(coor: UCoor2D) -> Result<Self, Self::Error> {
+        Ok(Self::new(coor.x.try_into()?, coor.y.try_into()?))
     }
 }
 
 /// A type of topology
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Topology {
     /// A bounded grid, with no wrap-around
-    Bounded = 0,
+    Bounded,
     /// A grid that wraps around, preserving the axis not moved in. e.g. Pacman
-    Torus = 1,
+    Torus,
+    /// A grid whose edges (or cells) are linked by a user-defined map, e.g. a cube net
+    Stitched(Arc<EdgeMap>),
 }
 
 /// All eight directions (Orthogonal+Diagonal)
@@ -212,21 +294,84 @@ pub enum Direction {
 }
 
 impl Direction {
-    /// Returns the direction rotated by the given number of degrees
+    /// All directions, clockwise from North
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Iterates over all directions, clockwise from North
+    pub fn all() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    /// Returns the direction rotated clockwise by the given number of degrees (`None` if the
+    /// rotation is not a multiple of 45°, see [`Direction::rotate_eighths`])
+    pub fn rotate(&self, rotation: isize) -> Option<Self> {
+        (rotation % 45 == 0).then(|| self.rotate_eighths(rotation / 45))
+    }
+
+    /// Returns the direction rotated clockwise by the given number of eighth turns (45°)
     #[must_use]
-    pub fn rotate(&self, rotation: isize) -> Self {
-        let new_dir = (*self as isize + rotation * 8 / 360).rem_euclid(8);
-        match new_dir {
-            0 => Direction::North,
-            1 => Direction::NorthEast,
-            2 => Direction::East,
-            3 => Direction::SouthEast,
-            4 => Direction::South,
-            5 => Direction::SouthWest,
-            6 => Direction::West,
-            7 => Direction::NorthWest,
-            _ => unreachable!(),
-        }
+    pub fn rotate_eighths(&self, eighths: isize) -> Self {
+        let new_dir = (*self as isize + eighths).rem_euclid(8);
+        Direction::ALL[usize::try_from(new_dir).unwrap()]
+    }
+
+    /// Returns the direction rotated by 180°
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        self.rotate_eighths(4)
+    }
+
+    /// Returns the direction rotated counterclockwise by 90°
+    #[must_use]
+    pub fn turn_left(&self) -> Self {
+        self.rotate_eighths(-2)
+    }
+
+    /// Returns the direction rotated clockwise by 90°
+    #[must_use]
+    pub fn turn_right(&self) -> Self {
+        self.rotate_eighths(2)
+    }
+
+    /// Is it North, East, South or West
+    pub fn is_orthogonal(&self) -> bool {
+        (*self as u8).is_multiple_of(2)
+    }
+
+    /// Is it one of the directions between the orthogonal ones
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_orthogonal()
+    }
+
+    /// Is it East or West
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    /// Is it North or South
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// Direction of an arrow character (`'^'`, `'>'`, `'v'` or `'<'`)
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        CardinalDirection::from_arrow(arrow).map(Direction::from)
+    }
+
+    /// Arrow character of an orthogonal direction (`None` for diagonals)
+    pub fn to_arrow(&self) -> Option<char> {
+        CardinalDirection::try_from(*self)
+            .ok()
+            .map(|direction| direction.to_arrow())
     }
 
     #[must_use]
@@ -245,6 +390,121 @@ impl Direction {
     }
 }
 
+/// The four orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum CardinalDirection {
+    /// North
+    North = 0,
+    /// East
+    East = 1,
+    /// South
+    South = 2,
+    /// West
+    West = 3,
+}
+
+impl CardinalDirection {
+    /// All cardinal directions, clockwise from North
+    pub const ALL: [CardinalDirection; 4] = [
+        CardinalDirection::North,
+        CardinalDirection::East,
+        CardinalDirection::South,
+        CardinalDirection::West,
+    ];
+
+    /// Iterates over all cardinal directions, clockwise from North
+    pub fn all() -> impl Iterator<Item = CardinalDirection> {
+        CardinalDirection::ALL.into_iter()
+    }
+
+    /// Returns the direction rotated clockwise by the given number of quarter turns
+    #[must_use]
+    pub fn rotate_quarters(&self, quarters: isize) -> Self {
+        let new_dir = (*self as isize + quarters).rem_euclid(4);
+        CardinalDirection::ALL[usize::try_from(new_dir).unwrap()]
+    }
+
+    /// Returns the direction rotated by 180°
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        self.rotate_quarters(2)
+    }
+
+    /// Returns the direction rotated counterclockwise by 90°
+    #[must_use]
+    pub fn turn_left(&self) -> Self {
+        self.rotate_quarters(-1)
+    }
+
+    /// Returns the direction rotated clockwise by 90°
+    #[must_use]
+    pub fn turn_right(&self) -> Self {
+        self.rotate_quarters(1)
+    }
+
+    /// Is it East or West
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, CardinalDirection::East | CardinalDirection::West)
+    }
+
+    /// Is it North or South
+    pub fn is_vertical(&self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Direction of an arrow character (`'^'`, `'>'`, `'v'` or `'<'`)
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(CardinalDirection::North),
+            '>' => Some(CardinalDirection::East),
+            'v' => Some(CardinalDirection::South),
+            '<' => Some(CardinalDirection::West),
+            _ => None,
+        }
+    }
+
+    /// Arrow character pointing in the direction
+    pub fn to_arrow(&self) -> char {
+        match self {
+            CardinalDirection::North => '^',
+            CardinalDirection::East => '>',
+            CardinalDirection::South => 'v',
+            CardinalDirection::West => '<',
+        }
+    }
+
+    /// Returns the difference vector for direction
+    pub fn diff_coor(&self) -> ICoor2D {
+        Direction::from(*self).diff_coor()
+    }
+}
+
+impl From<CardinalDirection> for Direction {
+    fn from(direction: CardinalDirection) -> Self {
+        Direction::ALL[direction as usize * 2]
+    }
+}
+
+/// Error converting a diagonal [`Direction`] into a [`CardinalDirection`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, DeriveDisplay)]
+#[display("{_0:?} is not a cardinal direction")]
+pub struct NotCardinalError(pub Direction);
+
+impl std::error::Error for NotCardinalError {}
+
+impl TryFrom<Direction> for CardinalDirection {
+    type Error = NotCardinalError;
+
+    fn try_from(direction: Direction) -> Result<Self, Self::Error> {
+        if direction.is_orthogonal() {
+            Ok(CardinalDirection::ALL[direction as usize / 2])
+        } else {
+            Err(NotCardinalError(direction))
+        }
+    }
+}
+
 /// Neighborhoods around a point. They do not contain the point itself
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Neighborhood {
@@ -255,3 +515,106 @@ pub enum Neighborhood {
     /// The neighborhood consisting of the square directly around the point.
     Square,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let coor = UCoor2D::new(2, 1);
+        assert_eq!(Some(UCoor2D::new(5, 1)), coor.step(Direction::East, 3));
+        assert_eq!(Some(UCoor2D::new(0, 3)), coor.step(Direction::SouthWest, 2));
+        assert_eq!(None, coor.step(Direction::North, 2));
+        assert_eq!(
+            Some(ICoor2D::new(-1, 4)),
+            ICoor2D::new(2, 1).step(Direction::SouthWest, 3)
+        );
+        assert_eq!(None, ICoor2D::new(0, isize::MAX).step(Direction::South, 1));
+        assert_eq!(
+            ICoor2D::new(-3, 6),
+            Direction::SouthWest.diff_coor() * 3 + ICoor2D::new(0, 3)
+        );
+    }
+
+    #[test]
+    fn test_wrapping_step() {
+        let coor = UCoor2D::new(2, 1);
+        assert_eq!(
+            UCoor2D::new(1, 1),
+            coor.wrapping_step(Direction::East, 3, 4, 3)
+        );
+        assert_eq!(
+            UCoor2D::new(3, 2),
+            coor.wrapping_step(Direction::NorthEast, 5, 4, 3)
+        );
+        assert_eq!(coor, coor.wrapping_step(Direction::West, 12, 4, 3));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Some(Direction::SouthEast), Direction::North.rotate(-225));
+        assert_eq!(Direction::West, Direction::North.rotate_eighths(-10));
+        assert_eq!(None, Direction::North.rotate(30));
+        assert_eq!(Direction::SouthWest, Direction::NorthEast.opposite());
+        assert_eq!(Direction::NorthWest, Direction::NorthEast.turn_left());
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(4, Direction::all().filter(Direction::is_diagonal).count());
+        assert!(Direction::West.is_horizontal() && !Direction::NorthWest.is_horizontal());
+        assert_eq!(Some(Direction::South), Direction::from_arrow('v'));
+        assert_eq!(None, Direction::from_arrow('x'));
+        assert_eq!(None, Direction::SouthEast.to_arrow());
+        let arrows: String = Direction::all().filter_map(|d| d.to_arrow()).collect();
+        assert_eq!("^>v<", arrows);
+    }
+
+    #[test]
+    fn test_cardinal_direction() {
+        for direction in CardinalDirection::all() {
+            assert_eq!(Ok(direction), Direction::from(direction).try_into());
+            assert_eq!(
+                Direction::from(direction.turn_left()),
+                Direction::from(direction).turn_left()
+            );
+            assert_eq!(
+                Some(direction),
+                CardinalDirection::from_arrow(direction.to_arrow())
+            );
+            assert_eq!(direction, direction.opposite().opposite());
+            assert_ne!(
+                direction.is_vertical(),
+                direction.turn_right().is_vertical()
+            );
+        }
+        assert_eq!(
+            Err(NotCardinalError(Direction::NorthWest)),
+            CardinalDirection::try_from(Direction::NorthWest)
+        );
+        assert_eq!(
+            "NorthWest is not a cardinal direction",
+            NotCardinalError(Direction::NorthWest).to_string()
+        );
+        assert_eq!(ICoor2D::new(-1, 0), CardinalDirection::West.diff_coor());
+    }
+
+    #[test]
+    fn test_conversions_and_order() {
+        assert_eq!(
+            Ok(UCoor2D::new(1, 2)),
+            UCoor2D::try_from(ICoor2D::new(1, 2))
+        );
+        assert!(UCoor2D::try_from(ICoor2D::new(-1, 2)).is_err());
+        assert_eq!(Some(ICoor2D::new(3, 0)), UCoor2D::new(3, 0).to_icoor2d());
+        let sorted: BTreeSet<UCoor2D> = [(1, 0), (0, 2), (0, 1)].map(UCoor2D::from).into();
+        assert_eq!(
+            vec![UCoor2D::new(1, 0), UCoor2D::new(0, 1), UCoor2D::new(0, 2)],
+            sorted.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            UCoor2D::new(0, 1),
+            UCoor2D::new(2, 1).component_min(&UCoor2D::new(0, 3))
+        );
+    }
+}
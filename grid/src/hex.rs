@@ -0,0 +1,504 @@
+//! Hexagonal grids
+//!
+//! Cells are addressed with axial coordinates [`HexCoor`] (`q` and `r`, the third cube
+//! coordinate `s` is `-q - r`). The six [`HexDirection`]s are named for pointy-top hexagons,
+//! where `q` grows to the east and `r` to the south-east. For flat-top hexagons the same axial
+//! coordinates and directions are used, the drawing is just rotated by 30°.
+//!
+//! Puzzles usually draw hex maps as text, where every other row (pointy-top) or column (flat-top)
+//! is shifted by half a cell. [`OffsetLayout`] converts between these offset positions and axial
+//! coordinates. [`HexGrid`] implements [`Grid`], so the searches and [`flood_fill`] work on hex
+//! maps as well.
+//!
+//! [`flood_fill`]: crate::region::flood_fill
+
+use std::fmt::{Display, Write};
+use std::ops::{Add, Sub};
+
+use fxhash::FxHashMap;
+use itertools::Itertools;
+
+use crate::grid_trait::Grid;
+use crate::grid_types::Topology;
+
+/// Axial coordinate of a hexagonal cell
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexCoor {
+    /// column axis (east)
+    pub q: isize,
+    /// row axis (south-east)
+    pub r: isize,
+}
+
+impl HexCoor {
+    /// The center
+    pub const ORIGIN: HexCoor = HexCoor { q: 0, r: 0 };
+
+    /// new axial coordinate
+    pub fn new(q: isize, r: isize) -> Self {
+        HexCoor { q, r }
+    }
+
+    /// from cube coordinates, which must add up to 0
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Self {
+        debug_assert_eq!(0, q + r + s, "cube coordinates must add up to 0");
+        HexCoor { q, r }
+    }
+
+    /// third cube coordinate
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    /// to cube coordinates
+    pub fn to_cube(&self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Returns the number of steps from the center
+    pub fn length(&self) -> usize {
+        (self.q.unsigned_abs() + self.r.unsigned_abs() + self.s().unsigned_abs()) / 2
+    }
+
+    /// Returns the number of steps between two cells
+    pub fn distance(&self, other: &HexCoor) -> usize {
+        (*self - *other).length()
+    }
+
+    /// The adjacent cell in `direction`
+    #[must_use]
+    pub fn neighbor(&self, direction: HexDirection) -> Self {
+        *self + direction.diff_coor()
+    }
+
+    /// The cells at exactly `radius` steps, clockwise starting east of the center
+    pub fn ring(&self, radius: usize) -> Vec<HexCoor> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let mut coor = HexCoor::ORIGIN;
+        for _ in 0..radius {
+            coor = coor.neighbor(HexDirection::East);
+        }
+        let mut ring = Vec::with_capacity(6 * radius);
+        // walking the sides clockwise, the first side goes south-west from the east corner
+        for side in HexDirection::ALL.map(|direction| direction.rotate(120)) {
+            for _ in 0..radius {
+                ring.push(*self + coor);
+                coor = coor.neighbor(side);
+            }
+        }
+        ring
+    }
+
+    /// The cells at most `radius` steps away, ring by ring starting at the center
+    pub fn spiral(&self, radius: usize) -> Vec<HexCoor> {
+        (0..=radius).flat_map(|ring| self.ring(ring)).collect()
+    }
+}
+
+impl Add for HexCoor {
+    type Output = HexCoor;
+
+    fn add(self, other: HexCoor) -> HexCoor {
+        HexCoor::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for HexCoor {
+    type Output = HexCoor;
+
+    fn sub(self, other: HexCoor) -> HexCoor {
+        HexCoor::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Display for HexCoor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.q, self.r)
+    }
+}
+
+/// The six directions of a hexagonal cell, clockwise (named for pointy-top hexagons)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum HexDirection {
+    /// East
+    East = 0,
+    /// SouthEast
+    SouthEast = 1,
+    /// SouthWest
+    SouthWest = 2,
+    /// West
+    West = 3,
+    /// NorthWest
+    NorthWest = 4,
+    /// NorthEast
+    NorthEast = 5,
+}
+
+impl HexDirection {
+    /// All directions, clockwise starting east
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// Returns the direction rotated clockwise by the given number of degrees (a multiple of 60)
+    #[must_use]
+    pub fn rotate(&self, rotation: isize) -> Self {
+        debug_assert_eq!(
+            0,
+            rotation % 60,
+            "hex directions rotate by multiples of 60°"
+        );
+        let new_dir = (*self as isize + rotation / 60).rem_euclid(6);
+        HexDirection::ALL[usize::try_from(new_dir).unwrap()]
+    }
+
+    /// Returns the offset to the adjacent cell
+    pub fn diff_coor(&self) -> HexCoor {
+        match self {
+            HexDirection::East => HexCoor::new(1, 0),
+            HexDirection::SouthEast => HexCoor::new(0, 1),
+            HexDirection::SouthWest => HexCoor::new(-1, 1),
+            HexDirection::West => HexCoor::new(-1, 0),
+            HexDirection::NorthWest => HexCoor::new(0, -1),
+            HexDirection::NorthEast => HexCoor::new(1, -1),
+        }
+    }
+}
+
+/// Orientation of the hexagons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// A corner points up, the cells form rows
+    Pointy,
+    /// A side is on top, the cells form columns
+    Flat,
+}
+
+/// How a hex map is stored in rows and columns of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetLayout {
+    /// Pointy-top rows, the odd rows are shifted right by half a cell
+    OddRows,
+    /// Pointy-top rows, the even rows are shifted right by half a cell
+    EvenRows,
+    /// Flat-top columns, the odd columns are shifted down by half a cell
+    OddColumns,
+    /// Flat-top columns, the even columns are shifted down by half a cell
+    EvenColumns,
+}
+
+impl OffsetLayout {
+    /// Orientation of the hexagons
+    pub fn orientation(self) -> HexOrientation {
+        match self {
+            OffsetLayout::OddRows | OffsetLayout::EvenRows => HexOrientation::Pointy,
+            OffsetLayout::OddColumns | OffsetLayout::EvenColumns => HexOrientation::Flat,
+        }
+    }
+
+    /// Axial coordinate of the cell in `column` and `row`
+    pub fn to_axial(self, column: isize, row: isize) -> HexCoor {
+        match self {
+            OffsetLayout::OddRows => HexCoor::new(column - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenRows => HexCoor::new(column - row.midpoint(row & 1), row),
+            OffsetLayout::OddColumns => HexCoor::new(column, row - (column - (column & 1)) / 2),
+            OffsetLayout::EvenColumns => HexCoor::new(column, row - column.midpoint(column & 1)),
+        }
+    }
+
+    /// Column and row of the cell at `coor`
+    pub fn to_offset(self, coor: &HexCoor) -> (isize, isize) {
+        let (q, r) = (coor.q, coor.r);
+        match self {
+            OffsetLayout::OddRows => (q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenRows => (q + r.midpoint(r & 1), r),
+            OffsetLayout::OddColumns => (q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenColumns => (q, r + q.midpoint(q & 1)),
+        }
+    }
+}
+
+/// Sparse grid of hexagonal cells
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexGrid<T> {
+    cells: FxHashMap<HexCoor, T>,
+}
+
+impl<T> Default for HexGrid<T> {
+    fn default() -> Self {
+        HexGrid {
+            cells: FxHashMap::default(),
+        }
+    }
+}
+
+impl<T> FromIterator<(HexCoor, T)> for HexGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (HexCoor, T)>>(iter: I) -> Self {
+        HexGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl HexGrid<char> {
+    /// Parses a hex map drawn as text in `layout`
+    ///
+    /// Every line is a row and the non-whitespace characters are its cells, so the shifted rows
+    /// may be indented and the cells separated by spaces:
+    /// ```text
+    /// a b c
+    ///  d e f
+    /// ```
+    pub fn from_offset_string(layout: OffsetLayout, input: &str) -> Self {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .filter(|cell| !cell.is_whitespace())
+                    .enumerate()
+                    .map(move |(column, cell)| {
+                        let coor =
+                            layout.to_axial(column.try_into().unwrap(), row.try_into().unwrap());
+                        (coor, cell)
+                    })
+            })
+            .collect()
+    }
+
+    /// Draws the map in `layout` (cells separated by spaces, shifted rows or columns indented),
+    /// missing cells are drawn as `blank`
+    pub fn to_offset_string(&self, layout: OffsetLayout, blank: char) -> String {
+        let offsets: FxHashMap<(isize, isize), char> = self
+            .cells
+            .iter()
+            .map(|(coor, &cell)| (layout.to_offset(coor), cell))
+            .collect();
+        let Some(((min_column, max_column), (min_row, max_row))) = offsets
+            .keys()
+            .map(|&(column, _)| column)
+            .minmax()
+            .into_option()
+            .zip(offsets.keys().map(|&(_, row)| row).minmax().into_option())
+        else {
+            return String::new();
+        };
+        let cell = |column, row| offsets.get(&(column, row)).copied().unwrap_or(blank);
+        let shifted = |index: isize| match layout {
+            OffsetLayout::OddRows | OffsetLayout::OddColumns => index & 1 == 1,
+            OffsetLayout::EvenRows | OffsetLayout::EvenColumns => index & 1 == 0,
+        };
+        let mut text = String::new();
+        match layout.orientation() {
+            HexOrientation::Pointy => {
+                for row in min_row..=max_row {
+                    let indent = if shifted(row) { " " } else { "" };
+                    let cells = (min_column..=max_column).map(|column| cell(column, row));
+                    writeln!(text, "{indent}{}", cells.format(" ")).unwrap();
+                }
+            }
+            // every row of text holds the upper halves of the unshifted columns and then the
+            // lower halves of the shifted columns
+            HexOrientation::Flat => {
+                for (row, lower_half) in (min_row..=max_row).cartesian_product([false, true]) {
+                    let line: String = (min_column..=max_column)
+                        .map(|column| {
+                            if shifted(column) == lower_half {
+                                cell(column, row)
+                            } else {
+                                ' '
+                            }
+                        })
+                        .collect();
+                    writeln!(text, "{}", line.trim_end()).unwrap();
+                }
+            }
+        }
+        text
+    }
+}
+
+impl<T> HexGrid<T> {
+    /// get reference to the cell at `coor`
+    pub fn get(&self, coor: &HexCoor) -> Option<&T> {
+        self.cells.get(coor)
+    }
+
+    /// get mutable reference to the cell at `coor`
+    pub fn get_mut(&mut self, coor: &HexCoor) -> Option<&mut T> {
+        self.cells.get_mut(coor)
+    }
+
+    /// set new cell at `coor` and return the old cell
+    pub fn set(&mut self, coor: HexCoor, value: T) -> Option<T> {
+        self.cells.insert(coor, value)
+    }
+
+    /// number of cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// has the grid no cells
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// return all coordinates and cells (in no particular order)
+    pub fn all_cells(&self) -> impl Iterator<Item = (&HexCoor, &T)> {
+        self.cells.iter()
+    }
+}
+
+impl<T> Grid for HexGrid<T> {
+    type Cell = T;
+    type Coor = HexCoor;
+    type Direction = HexDirection;
+
+    fn cell(&self, coor: &HexCoor) -> Option<&T> {
+        self.get(coor)
+    }
+
+    fn cell_mut(&mut self, coor: &HexCoor) -> Option<&mut T> {
+        self.get_mut(coor)
+    }
+
+    fn set_cell(&mut self, coor: HexCoor, value: T) -> Option<T> {
+        self.set(coor, value)
+    }
+
+    /// Smallest and largest `q` and `r` of all cells
+    fn bounds(&self) -> Option<(HexCoor, HexCoor)> {
+        let (min_q, max_q) = self
+            .cells
+            .keys()
+            .map(|coor| coor.q)
+            .minmax()
+            .into_option()?;
+        let (min_r, max_r) = self
+            .cells
+            .keys()
+            .map(|coor| coor.r)
+            .minmax()
+            .into_option()?;
+        Some((HexCoor::new(min_q, min_r), HexCoor::new(max_q, max_r)))
+    }
+
+    fn topology(&self) -> Option<Topology> {
+        None
+    }
+
+    fn directions(&self) -> impl Iterator<Item = HexDirection> {
+        HexDirection::ALL.into_iter()
+    }
+
+    fn adjacent(&self, coor: &HexCoor, direction: HexDirection) -> Option<HexCoor> {
+        Some(coor.neighbor(direction))
+    }
+
+    fn coors(&self) -> impl Iterator<Item = HexCoor> {
+        self.cells.keys().copied()
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (HexCoor, &T)> {
+        self.cells.iter().map(|(&coor, cell)| (coor, cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::flood_fill;
+    use crate::search::bfs;
+
+    #[test]
+    fn test_coordinates() {
+        let coor = HexCoor::from_cube(2, -3, 1);
+        assert_eq!(3, coor.length());
+        assert_eq!(4, coor.distance(&HexCoor::new(-2, 0)));
+        assert_eq!((2, -3, 1), coor.to_cube());
+        assert_eq!(HexDirection::West, HexDirection::NorthEast.rotate(-120));
+        assert_eq!(HexDirection::East, HexDirection::NorthEast.rotate(420));
+        for direction in HexDirection::ALL {
+            let back = coor.neighbor(direction).neighbor(direction.rotate(180));
+            assert_eq!(coor, back);
+        }
+    }
+
+    #[test]
+    fn test_rings_and_spirals() {
+        let center = HexCoor::new(3, -1);
+        assert_eq!(vec![center], center.ring(0));
+        for radius in 1..5 {
+            let ring = center.ring(radius);
+            assert_eq!(6 * radius, ring.len());
+            assert!(ring.iter().all(|coor| coor.distance(&center) == radius));
+            assert!(ring
+                .iter()
+                .zip(ring.iter().cycle().skip(1))
+                .all(|(a, b)| a.distance(b) == 1));
+        }
+        let spiral = center.spiral(3);
+        assert_eq!(37, spiral.len());
+        assert_eq!(37, spiral.iter().unique().count());
+    }
+
+    #[test]
+    fn test_offset_layouts() {
+        for layout in [
+            OffsetLayout::OddRows,
+            OffsetLayout::EvenRows,
+            OffsetLayout::OddColumns,
+            OffsetLayout::EvenColumns,
+        ] {
+            for coor in HexCoor::ORIGIN.spiral(4) {
+                let (column, row) = layout.to_offset(&coor);
+                assert_eq!(coor, layout.to_axial(column, row));
+            }
+        }
+        let input = "a b c\n d e f\ng h i\n";
+        let grid = HexGrid::from_offset_string(OffsetLayout::OddRows, input);
+        let e = HexCoor::new(1, 1);
+        assert_eq!(Some(&'e'), grid.get(&e));
+        let mut neighbors: Vec<char> = grid.neighbor_cells(&e).map(|(_, _, &cell)| cell).collect();
+        neighbors.sort_unstable();
+        assert_eq!(vec!['b', 'c', 'd', 'f', 'h', 'i'], neighbors);
+        assert_eq!(input, grid.to_offset_string(OffsetLayout::OddRows, '.'));
+
+        let columns = "a c\n b\nd f\n e\n";
+        let grid = HexGrid::from_offset_string(OffsetLayout::OddColumns, "a b c\nd e f\n");
+        assert_eq!(
+            columns,
+            grid.to_offset_string(OffsetLayout::OddColumns, '.')
+        );
+    }
+
+    #[test]
+    fn test_search_and_flood_fill() {
+        let map = "\
+. . # . .
+ . # . # .
+. # . . .
+ . . # # .
+";
+        let grid = HexGrid::from_offset_string(OffsetLayout::OddRows, map);
+        let start = OffsetLayout::OddRows.to_axial(0, 0);
+        let goal = OffsetLayout::OddRows.to_axial(3, 0);
+        let result = bfs(&grid, &start, Some(&goal), |&cell| cell == '.');
+        assert_eq!(Some(7), result.goal_distance());
+        let region = flood_fill(&grid, &start, |&cell| cell == '.');
+        assert_eq!(14, region.area());
+        let walls = flood_fill(&grid, &OffsetLayout::OddRows.to_axial(2, 0), |&cell| {
+            cell == '#'
+        });
+        assert_eq!(3, walls.area());
+    }
+}
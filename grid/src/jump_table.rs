@@ -0,0 +1,254 @@
+//! Precomputed jumps to the next blocking cell
+//!
+//! "Move until the next obstacle" simulations (e.g. a guard walking straight and turning at
+//! walls) spend most of their time on straight segments. A [`JumpTable`] stores for every cell and
+//! cardinal direction the first blocking cell ahead, so a walk takes one lookup per turn. One
+//! extra blocker can be passed to every query, which allows trying many candidate obstacles (also
+//! in parallel) without rebuilding the table.
+
+use fxhash::FxHashSet;
+
+use crate::grid_array::GridArray;
+use crate::grid_types::{CardinalDirection, Direction, Topology, UCoor2D, UCoor2DIndex};
+
+/// The first blocking cell ahead of every cell in every cardinal direction (on a bounded grid)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JumpTable {
+    width: UCoor2DIndex,
+    height: UCoor2DIndex,
+    /// indexed by direction, then by cell (row by row)
+    next_blockers: [Vec<Option<UCoor2D>>; 4],
+}
+
+impl JumpTable {
+    /// Table of the cells of `grid` for which `blocked` is true
+    ///
+    /// # Panics
+    /// If the grid is not bounded
+    pub fn new<T: Default + Clone>(grid: &GridArray<T>, blocked: impl Fn(&T) -> bool) -> Self {
+        assert_eq!(
+            Topology::Bounded,
+            grid.get_topology(),
+            "jump tables need a bounded grid"
+        );
+        let (width, height) = (grid.width(), grid.height());
+        let mut next_blockers: [Vec<Option<UCoor2D>>; 4] =
+            std::array::from_fn(|_| vec![None; width * height]);
+        for direction in CardinalDirection::all() {
+            // walk against the direction, remembering the last blocker seen
+            let table = &mut next_blockers[direction as usize];
+            let lines: Vec<Vec<UCoor2D>> = if direction.is_horizontal() {
+                (0..height)
+                    .map(|y| (0..width).map(|x| UCoor2D::new(x, y)).collect())
+                    .collect()
+            } else {
+                (0..width)
+                    .map(|x| (0..height).map(|y| UCoor2D::new(x, y)).collect())
+                    .collect()
+            };
+            let ahead_first = matches!(
+                direction,
+                CardinalDirection::East | CardinalDirection::South
+            );
+            for mut line in lines {
+                if ahead_first {
+                    line.reverse();
+                }
+                let mut last_blocker = None;
+                for coor in line {
+                    table[coor.y * width + coor.x] = last_blocker;
+                    if blocked(grid.get_unchecked(coor.x, coor.y)) {
+                        last_blocker = Some(coor);
+                    }
+                }
+            }
+        }
+        JumpTable {
+            width,
+            height,
+            next_blockers,
+        }
+    }
+
+    /// `get_width`
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// `get_height`
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The first blocking cell ahead of `coor` in `direction` (`None` if the way to the edge is
+    /// free), with `extra_blocker` counting as blocked as well
+    pub fn next_blocker(
+        &self,
+        coor: UCoor2D,
+        direction: CardinalDirection,
+        extra_blocker: Option<UCoor2D>,
+    ) -> Option<UCoor2D> {
+        let blocker = self.next_blockers[direction as usize][coor.y * self.width + coor.x];
+        match extra_blocker.and_then(|extra| distance_ahead(coor, extra, direction)) {
+            Some(extra_distance)
+                if blocker.is_none_or(|blocker| {
+                    distance_ahead(coor, blocker, direction)
+                        .is_some_and(|distance| extra_distance < distance)
+                }) =>
+            {
+                extra_blocker
+            }
+            _ => blocker,
+        }
+    }
+
+    /// The cell in front of the next blocker, where a walker from `coor` towards `direction`
+    /// stops (`None` if it leaves the grid)
+    pub fn jump(
+        &self,
+        coor: UCoor2D,
+        direction: CardinalDirection,
+        extra_blocker: Option<UCoor2D>,
+    ) -> Option<UCoor2D> {
+        self.next_blocker(coor, direction, extra_blocker)
+            .map(|blocker| {
+                blocker
+                    .step(Direction::from(direction.opposite()), 1)
+                    .expect("the cell before a blocker is inside of the grid")
+            })
+    }
+
+    /// The cells where a walker starting at `start` towards `direction` turns right, and the
+    /// directions it faces afterwards (ends when it leaves the grid or repeats a turn)
+    pub fn turns(
+        &self,
+        start: UCoor2D,
+        direction: CardinalDirection,
+        extra_blocker: Option<UCoor2D>,
+    ) -> impl Iterator<Item = (UCoor2D, CardinalDirection)> + '_ {
+        let mut seen = FxHashSet::default();
+        let mut state = Some((start, direction));
+        std::iter::from_fn(move || {
+            let (coor, direction) = state?;
+            let turn = self
+                .jump(coor, direction, extra_blocker)
+                .map(|stop| (stop, direction.turn_right()))
+                .filter(|turn| seen.insert(*turn));
+            state = turn;
+            turn
+        })
+    }
+
+    /// Does a walker starting at `start` towards `direction`, turning right at every blocker,
+    /// walk in a loop instead of leaving the grid
+    pub fn has_loop(
+        &self,
+        start: UCoor2D,
+        direction: CardinalDirection,
+        extra_blocker: Option<UCoor2D>,
+    ) -> bool {
+        let mut seen = FxHashSet::default();
+        let (mut coor, mut direction) = (start, direction);
+        while let Some(stop) = self.jump(coor, direction, extra_blocker) {
+            direction = direction.turn_right();
+            if !seen.insert((stop, direction)) {
+                return true;
+            }
+            coor = stop;
+        }
+        false
+    }
+}
+
+/// How many steps `target` is straight ahead of `coor` in `direction` (`None` if it is not ahead)
+fn distance_ahead(coor: UCoor2D, target: UCoor2D, direction: CardinalDirection) -> Option<usize> {
+    let distance = match direction {
+        CardinalDirection::North => (coor.x == target.x).then(|| coor.y.checked_sub(target.y))?,
+        CardinalDirection::East => (coor.y == target.y).then(|| target.x.checked_sub(coor.x))?,
+        CardinalDirection::South => (coor.x == target.x).then(|| target.y.checked_sub(coor.y))?,
+        CardinalDirection::West => (coor.y == target.y).then(|| coor.x.checked_sub(target.x))?,
+    }?;
+    (distance > 0).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::CardinalDirection::*;
+    use crate::test_util::bounded;
+
+    const LAB: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#........
+........#.
+#.........
+......#...
+";
+
+    fn table(input: &str) -> JumpTable {
+        JumpTable::new(&bounded(input), |&cell| cell == '#')
+    }
+
+    #[test]
+    fn test_next_blocker() {
+        let jumps = table(LAB);
+        let start = UCoor2D::new(4, 6);
+        assert_eq!(
+            Some(UCoor2D::new(4, 0)),
+            jumps.next_blocker(start, North, None)
+        );
+        assert_eq!(Some(UCoor2D::new(4, 1)), jumps.jump(start, North, None));
+        assert_eq!(None, jumps.next_blocker(start, East, None));
+        assert_eq!(
+            Some(UCoor2D::new(1, 6)),
+            jumps.next_blocker(start, West, None)
+        );
+        // an extra blocker behind the walker is ignored
+        assert_eq!(
+            Some(UCoor2D::new(1, 6)),
+            jumps.next_blocker(start, West, Some(UCoor2D::new(6, 6)))
+        );
+        assert_eq!(
+            Some(UCoor2D::new(3, 6)),
+            jumps.next_blocker(start, West, Some(UCoor2D::new(3, 6)))
+        );
+        assert_eq!(
+            Some(UCoor2D::new(4, 9)),
+            jumps.next_blocker(start, South, Some(UCoor2D::new(4, 9)))
+        );
+    }
+
+    #[test]
+    fn test_turns_and_loops() {
+        let jumps = table(LAB);
+        let start = UCoor2D::new(4, 6);
+        let turns: Vec<_> = jumps.turns(start, North, None).collect();
+        assert_eq!(
+            vec![
+                (UCoor2D::new(4, 1), East),
+                (UCoor2D::new(8, 1), South),
+                (UCoor2D::new(8, 6), West),
+                (UCoor2D::new(2, 6), North),
+                (UCoor2D::new(2, 4), East),
+                (UCoor2D::new(6, 4), South),
+                (UCoor2D::new(6, 8), West),
+                (UCoor2D::new(1, 8), North),
+                (UCoor2D::new(1, 7), East),
+                (UCoor2D::new(7, 7), South),
+            ],
+            turns
+        );
+        assert!(!jumps.has_loop(start, North, None));
+        // the example has 6 places for an obstruction that traps the guard
+        let trapping: Vec<UCoor2D> = (0..10)
+            .flat_map(|y| (0..10).map(move |x| UCoor2D::new(x, y)))
+            .filter(|&coor| coor != start && jumps.has_loop(start, North, Some(coor)))
+            .collect();
+        assert_eq!(6, trapping.len());
+        assert!(trapping.contains(&UCoor2D::new(3, 6)));
+    }
+}
@@ -10,7 +10,21 @@
 #![allow(clippy::missing_panics_doc)]
 #![allow(clippy::doc_markdown)]
 
+pub mod automaton;
+pub mod bit_grid;
+pub mod distance_field;
+pub mod edge_map;
 pub mod grid_array;
 pub mod grid_hashmap;
 pub mod grid_iteration;
+pub mod grid_nd;
+pub mod grid_trait;
 pub mod grid_types;
+pub mod hex;
+pub mod jump_table;
+pub mod pattern;
+pub mod ray;
+pub mod region;
+pub mod search;
+#[cfg(test)]
+mod test_util;
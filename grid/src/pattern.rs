@@ -0,0 +1,307 @@
+//! Searching a grid for a smaller pattern grid
+//!
+//! Patterns may contain wildcard cells and can be searched in all their rotations and
+//! reflections. Candidates are found by rolling hashes over the grid rows (for the longest run of
+//! non-wildcard cells of the pattern) and then compared cell by cell.
+
+use std::hash::Hash;
+
+use crate::grid_array::GridArray;
+use crate::grid_types::UCoor2D;
+
+/// Base of the polynomial rolling hash
+const HASH_BASE: u64 = 0x0100_0000_01b3;
+
+/// One of the 8 symmetries of a square: a horizontal flip (optional) followed by rotations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symmetry {
+    /// Flip horizontally (before rotating)
+    pub flipped: bool,
+    /// Number of clockwise rotations by 90° (0-3)
+    pub rotations: u8,
+}
+
+impl Symmetry {
+    /// The pattern as given
+    pub const IDENTITY: Symmetry = Symmetry {
+        flipped: false,
+        rotations: 0,
+    };
+
+    /// All symmetries, starting with the identity and the rotations
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::IDENTITY,
+        Symmetry::rotated(1),
+        Symmetry::rotated(2),
+        Symmetry::rotated(3),
+        Symmetry::flipped_and_rotated(0),
+        Symmetry::flipped_and_rotated(1),
+        Symmetry::flipped_and_rotated(2),
+        Symmetry::flipped_and_rotated(3),
+    ];
+
+    const fn rotated(rotations: u8) -> Self {
+        Symmetry {
+            flipped: false,
+            rotations,
+        }
+    }
+
+    const fn flipped_and_rotated(rotations: u8) -> Self {
+        Symmetry {
+            flipped: true,
+            rotations,
+        }
+    }
+
+    /// Returns the transformed copy of `grid`
+    pub fn apply<T: Default + Clone>(self, grid: &GridArray<T>) -> GridArray<T> {
+        let mut transformed = grid.clone();
+        if self.flipped {
+            transformed.flip_horizontal();
+        }
+        for _ in 0..self.rotations {
+            transformed.rotate_cw();
+        }
+        transformed
+    }
+}
+
+/// Which orientations of a pattern are searched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetries {
+    /// Only the pattern as given
+    Identity,
+    /// The pattern rotated by 0°, 90°, 180° and 270°
+    Rotations,
+    /// All rotations of the pattern and of its mirror image
+    All,
+}
+
+impl Symmetries {
+    /// The symmetries to search
+    pub fn symmetries(self) -> &'static [Symmetry] {
+        match self {
+            Symmetries::Identity => &Symmetry::ALL[..1],
+            Symmetries::Rotations => &Symmetry::ALL[..4],
+            Symmetries::All => &Symmetry::ALL,
+        }
+    }
+}
+
+/// A place where the pattern was found
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    /// Upper left cell of the (transformed) pattern in the grid
+    pub position: UCoor2D,
+    /// Transformation of the pattern which matched
+    pub symmetry: Symmetry,
+}
+
+impl<T: Default + Clone + PartialEq + Hash> GridArray<T> {
+    /// Finds all places where `pattern` (in one of the searched `symmetries`) matches the grid
+    ///
+    /// Cells of the pattern equal to `wildcard` match every cell. Orientations which look the
+    /// same as an earlier one (e.g. the 180° rotation of a symmetric pattern) are skipped, so every
+    /// place is reported once per distinct orientation. The matches are ordered by position
+    /// (row by row), then by symmetry.
+    pub fn find_pattern(
+        &self,
+        pattern: &GridArray<T>,
+        wildcard: Option<&T>,
+        symmetries: Symmetries,
+    ) -> Vec<PatternMatch> {
+        let cell_hashes: Vec<u64> = self.iter().map(fxhash::hash64).collect();
+        let mut orientations: Vec<(Symmetry, GridArray<T>)> = Vec::new();
+        for &symmetry in symmetries.symmetries() {
+            let oriented = symmetry.apply(pattern);
+            if orientations.iter().all(|(_, other)| other != &oriented) {
+                orientations.push((symmetry, oriented));
+            }
+        }
+        let mut matches: Vec<PatternMatch> = orientations
+            .iter()
+            .flat_map(|(symmetry, oriented)| {
+                self.find_oriented_pattern(&cell_hashes, oriented, wildcard)
+                    .into_iter()
+                    .map(|position| PatternMatch {
+                        position,
+                        symmetry: *symmetry,
+                    })
+            })
+            .collect();
+        matches.sort_by_key(|found| found.position);
+        matches
+    }
+
+    fn find_oriented_pattern(
+        &self,
+        cell_hashes: &[u64],
+        pattern: &GridArray<T>,
+        wildcard: Option<&T>,
+    ) -> Vec<UCoor2D> {
+        let (width, height) = (self.width(), self.height());
+        let (pattern_width, pattern_height) = (pattern.width(), pattern.height());
+        if pattern_width == 0 || pattern_width > width || pattern_height > height {
+            return Vec::new();
+        }
+        let is_wildcard = |cell: &T| wildcard.is_some_and(|wildcard| wildcard == cell);
+        let matches_at = |x: usize, y: usize| {
+            pattern.all_cells().all(|(coor, cell)| {
+                is_wildcard(cell) || self.get_unchecked(x + coor.x, y + coor.y) == cell
+            })
+        };
+
+        // the longest horizontal run of cells without wildcards: (row, first column, length)
+        let mut longest_run = (0, 0, 0);
+        for row in 0..pattern_height {
+            let mut run_start = 0;
+            for column in 0..=pattern_width {
+                if column == pattern_width || is_wildcard(pattern.get_unchecked(column, row)) {
+                    if column - run_start > longest_run.2 {
+                        longest_run = (row, run_start, column - run_start);
+                    }
+                    run_start = column + 1;
+                }
+            }
+        }
+        let (run_row, run_column, run_length) = longest_run;
+        if run_length == 0 {
+            // the pattern consists of wildcards only
+            return (0..=height - pattern_height)
+                .flat_map(|y| (0..=width - pattern_width).map(move |x| UCoor2D::new(x, y)))
+                .collect();
+        }
+
+        let run_hash = rolling_hash(
+            (run_column..run_column + run_length)
+                .map(|column| fxhash::hash64(pattern.get_unchecked(column, run_row))),
+        );
+        let highest_power = HASH_BASE.wrapping_pow(u32::try_from(run_length - 1).unwrap());
+        let mut found = Vec::new();
+        for y in 0..=height - pattern_height {
+            let row_hashes = &cell_hashes[(y + run_row) * width..(y + run_row + 1) * width];
+            let mut window_hash = rolling_hash(
+                row_hashes[run_column..run_column + run_length]
+                    .iter()
+                    .copied(),
+            );
+            for x in 0..=width - pattern_width {
+                if x > 0 {
+                    // roll the window one cell to the right
+                    window_hash = window_hash
+                        .wrapping_sub(row_hashes[x - 1 + run_column].wrapping_mul(highest_power))
+                        .wrapping_mul(HASH_BASE)
+                        .wrapping_add(row_hashes[x - 1 + run_column + run_length]);
+                }
+                if window_hash == run_hash && matches_at(x, y) {
+                    found.push(UCoor2D::new(x, y));
+                }
+            }
+        }
+        found
+    }
+}
+
+fn rolling_hash(hashes: impl Iterator<Item = u64>) -> u64 {
+    hashes.fold(0, |hash, cell_hash| {
+        hash.wrapping_mul(HASH_BASE).wrapping_add(cell_hash)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::{Neighborhood, Topology};
+    use crate::test_util::bounded;
+
+    #[test]
+    fn test_symmetries() {
+        let pattern = bounded("ab\ncd\n");
+        let transformed: Vec<String> = Symmetry::ALL
+            .iter()
+            .map(|symmetry| symmetry.apply(&pattern).to_string().replace('\n', ""))
+            .collect();
+        assert_eq!(
+            vec!["abcd", "cadb", "dcba", "bdac", "badc", "dbca", "cdab", "acbd"],
+            transformed
+        );
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let haystack = bounded("xabx\nxcdx\nxxxx\nbaxx\ndcxx\n");
+        let pattern = bounded("ab\ncd\n");
+        assert_eq!(
+            vec![PatternMatch {
+                position: UCoor2D::new(1, 0),
+                symmetry: Symmetry::IDENTITY
+            }],
+            haystack.find_pattern(&pattern, None, Symmetries::Rotations)
+        );
+        let matches = haystack.find_pattern(&pattern, None, Symmetries::All);
+        assert_eq!(2, matches.len());
+        assert_eq!(UCoor2D::new(0, 3), matches[1].position);
+        assert_eq!(
+            Symmetry {
+                flipped: true,
+                rotations: 0
+            },
+            matches[1].symmetry
+        );
+    }
+
+    #[test]
+    fn test_wildcards_and_symmetric_patterns() {
+        let haystack = bounded("M.S.\n.A..\nM.S.\n....\n");
+        let pattern = bounded("M?S\n?A?\nM?S\n");
+        let matches = haystack.find_pattern(&pattern, Some(&'?'), Symmetries::All);
+        // the mirror images of the pattern equal some of its rotations
+        assert_eq!(1, matches.len());
+        assert_eq!(UCoor2D::new(0, 0), matches[0].position);
+        let wildcards_only = bounded("??\n??\n");
+        assert_eq!(
+            9,
+            haystack
+                .find_pattern(&wildcards_only, Some(&'?'), Symmetries::All)
+                .len()
+        );
+        assert!(haystack
+            .find_pattern(&bounded("MAS\n"), None, Symmetries::Identity)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_matches_naive_search() {
+        // pseudo random grid of few letters, so that there are many (partial) matches
+        let mut state = 7_u64;
+        let cells: Vec<char> = (0..40 * 30)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1);
+                ['a', 'b'][usize::from(state >> 63 == 1)]
+            })
+            .collect();
+        let haystack = GridArray::from_1d_vec(Topology::Bounded, Neighborhood::Square, 40, cells);
+        let pattern = bounded("ab.\n.bb\n");
+        let naive_count: usize = Symmetry::ALL
+            .iter()
+            .map(|symmetry| {
+                let oriented = symmetry.apply(&pattern);
+                haystack
+                    .all_indexes()
+                    .filter(|coor| {
+                        oriented.all_cells().all(|(offset, cell)| {
+                            *cell == '.'
+                                || haystack.get(coor.x + offset.x, coor.y + offset.y) == Some(cell)
+                        })
+                    })
+                    .count()
+            })
+            .sum();
+        let matches = haystack.find_pattern(&pattern, Some(&'.'), Symmetries::All);
+        assert!(naive_count > 0);
+        assert_eq!(naive_count, matches.len());
+    }
+}
@@ -0,0 +1,254 @@
+//! Walking a grid along straight lines
+//!
+//! A [`Ray`] visits the cells `from + step`, `from + 2 * step`, ... until it leaves the grid or
+//! reaches a blocking cell. It follows the topology of the grid: on a torus it wraps around (and
+//! stops before returning to its start), on a stitched grid unit steps follow the edge map like
+//! [`step`]. [`line`] rasterizes the segment between two coordinates (Bresenham).
+
+use crate::grid_array::GridArray;
+use crate::grid_iteration::step;
+use crate::grid_types::{Direction, ICoor2D, ICoor2DIndex, Topology, UCoor2D};
+
+/// Predicate for the cells which stop a ray
+type Blocked<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
+/// Cells along a ray (the start is not included)
+pub struct Ray<'a, T: Default + Clone> {
+    grid: &'a GridArray<T>,
+    topology: Topology,
+    from: UCoor2D,
+    current: Option<UCoor2D>,
+    step: ICoor2D,
+    blocked: Option<Blocked<'a, T>>,
+    obstacle: Option<UCoor2D>,
+}
+
+impl<'a, T: Default + Clone> Ray<'a, T> {
+    /// Divides the step by the gcd of its components, so that no cell on the line is skipped
+    #[must_use]
+    pub fn reduced(mut self) -> Self {
+        let divisor = gcd(self.step.x.unsigned_abs(), self.step.y.unsigned_abs());
+        let divisor = ICoor2DIndex::try_from(divisor).unwrap();
+        self.step = ICoor2D::new(self.step.x / divisor, self.step.y / divisor);
+        self
+    }
+
+    /// Stops the ray before the first cell for which `blocked` is true
+    #[must_use]
+    pub fn blocked_by(mut self, blocked: impl Fn(&T) -> bool + 'a) -> Self {
+        self.blocked = Some(Box::new(blocked));
+        self
+    }
+
+    /// The blocking cell which stopped the ray (`None` while the ray goes on or if it left the
+    /// grid)
+    pub fn obstacle(&self) -> Option<UCoor2D> {
+        self.obstacle
+    }
+
+    fn advance(&mut self, coor: UCoor2D) -> Option<UCoor2D> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let unit_direction = Direction::all().find(|direction| direction.diff_coor() == self.step);
+        match (&self.topology, unit_direction) {
+            (Topology::Stitched(_), Some(direction)) => {
+                let (next, facing) = step(&self.topology, width, height, coor, direction)?;
+                self.step = facing.diff_coor();
+                Some(next)
+            }
+            (Topology::Torus, _) => {
+                let moved = ICoor2D::try_from(coor).ok()? + self.step;
+                let wrap = |index: ICoor2DIndex, size: usize| {
+                    usize::try_from(index.rem_euclid(ICoor2DIndex::try_from(size).ok()?)).ok()
+                };
+                let next = UCoor2D::new(wrap(moved.x, width)?, wrap(moved.y, height)?);
+                (next != self.from).then_some(next)
+            }
+            _ => {
+                let next = UCoor2D::try_from(ICoor2D::try_from(coor).ok()? + self.step).ok()?;
+                (next.x < width && next.y < height).then_some(next)
+            }
+        }
+    }
+}
+
+impl<'a, T: Default + Clone> Iterator for Ray<'a, T> {
+    type Item = (UCoor2D, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.current.and_then(|coor| self.advance(coor));
+        self.current = next;
+        let next = next?;
+        let cell = self.grid.get_unchecked(next.x, next.y);
+        if self.blocked.as_ref().is_some_and(|blocked| blocked(cell)) {
+            self.obstacle = Some(next);
+            self.current = None;
+            return None;
+        }
+        Some((next, cell))
+    }
+}
+
+impl<T: Default + Clone> GridArray<T> {
+    /// Cells `from + step`, `from + 2 * step`, ... up to the edge of the grid
+    ///
+    /// # Panics
+    /// If `step` is zero
+    pub fn ray(&self, from: UCoor2D, step: ICoor2D) -> Ray<'_, T> {
+        assert!(step != ICoor2D::new(0, 0), "a ray needs a non-zero step");
+        Ray {
+            grid: self,
+            topology: self.get_topology(),
+            from,
+            current: Some(from),
+            step,
+            blocked: None,
+            obstacle: None,
+        }
+    }
+
+    /// Is no cell strictly between `from` and `to` (on their Bresenham line) blocked
+    pub fn has_line_of_sight(
+        &self,
+        from: UCoor2D,
+        to: UCoor2D,
+        blocked: impl Fn(&T) -> bool,
+    ) -> bool {
+        let (Ok(from), Ok(to)) = (ICoor2D::try_from(from), ICoor2D::try_from(to)) else {
+            return false;
+        };
+        line(from, to)
+            .filter(|coor| *coor != from && *coor != to)
+            .filter_map(|coor| UCoor2D::try_from(coor).ok())
+            .all(|coor| self.get(coor.x, coor.y).is_none_or(|cell| !blocked(cell)))
+    }
+}
+
+/// Cells of the Bresenham line from `from` to `to` (both included)
+pub fn line(from: ICoor2D, to: ICoor2D) -> impl Iterator<Item = ICoor2D> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let mut error = dx + dy;
+    let mut current = Some(from);
+    std::iter::from_fn(move || {
+        let coor = current?;
+        current = (coor != to).then(|| {
+            let mut next = coor;
+            let doubled_error = 2 * error;
+            if doubled_error >= dy {
+                error += dy;
+                next.x += sx;
+            }
+            if doubled_error <= dx {
+                error += dx;
+                next.y += sy;
+            }
+            next
+        });
+        Some(coor)
+    })
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edge_map::EdgeMap;
+    use crate::grid_types::Neighborhood;
+    use crate::test_util::grid;
+
+    fn coors<'a>(ray: impl Iterator<Item = (UCoor2D, &'a char)>) -> Vec<(usize, usize)> {
+        ray.map(|(coor, _cell)| coor.to_tuple()).collect()
+    }
+
+    #[test]
+    fn test_ray() {
+        let cells = grid(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            "....\n..#.\n....\n....\n",
+        );
+        let from = UCoor2D::new(0, 0);
+        assert_eq!(
+            vec![(1, 1), (2, 2), (3, 3)],
+            coors(cells.ray(from, ICoor2D::new(1, 1)))
+        );
+        assert_eq!(vec![(2, 1)], coors(cells.ray(from, ICoor2D::new(2, 1))));
+        assert_eq!(
+            vec![(0, 2)],
+            coors(
+                cells
+                    .ray(from, ICoor2D::new(0, 4))
+                    .reduced()
+                    .skip(1)
+                    .take(1)
+            )
+        );
+
+        let mut ray = cells
+            .ray(UCoor2D::new(0, 1), Direction::East.diff_coor())
+            .blocked_by(|&cell| cell == '#');
+        assert_eq!(vec![(1, 1)], coors(ray.by_ref()));
+        assert_eq!(Some(UCoor2D::new(2, 1)), ray.obstacle());
+        let mut ray = cells.ray(UCoor2D::new(3, 1), Direction::South.diff_coor());
+        assert_eq!(2, ray.by_ref().count());
+        assert_eq!(None, ray.obstacle());
+    }
+
+    #[test]
+    fn test_ray_topologies() {
+        let torus = grid(Topology::Torus, Neighborhood::Orthogonal, "...\n...\n");
+        assert_eq!(
+            vec![(2, 0), (1, 0)],
+            coors(torus.ray(UCoor2D::new(0, 0), Direction::West.diff_coor()))
+        );
+        assert_eq!(
+            vec![(2, 1), (1, 0), (0, 1), (2, 0), (1, 1)],
+            coors(torus.ray(UCoor2D::new(0, 0), ICoor2D::new(2, 1)))
+        );
+
+        let strip = grid(
+            EdgeMap::mobius_strip(3, 2).into(),
+            Neighborhood::Orthogonal,
+            "...\n...\n",
+        );
+        assert_eq!(
+            vec![(2, 0), (0, 1), (1, 1), (2, 1), (0, 0)],
+            coors(
+                strip
+                    .ray(UCoor2D::new(1, 0), Direction::East.diff_coor())
+                    .take(5)
+            )
+        );
+    }
+
+    #[test]
+    fn test_line() {
+        let cells: Vec<(isize, isize)> = line(ICoor2D::new(0, 0), ICoor2D::new(4, -2))
+            .map(|coor| coor.to_tuple())
+            .collect();
+        assert_eq!(vec![(0, 0), (1, -1), (2, -1), (3, -2), (4, -2)], cells);
+        assert_eq!(
+            vec![ICoor2D::new(1, 1)],
+            line(ICoor2D::new(1, 1), ICoor2D::new(1, 1)).collect::<Vec<_>>()
+        );
+        // the line is symmetric for steep lines
+        assert_eq!(4, line(ICoor2D::new(3, 3), ICoor2D::new(2, 0)).count());
+
+        let cells = grid(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            "....\n.#..\n....\n",
+        );
+        let blocked = |&cell: &char| cell == '#';
+        assert!(!cells.has_line_of_sight(UCoor2D::new(0, 0), UCoor2D::new(2, 2), blocked));
+        assert!(cells.has_line_of_sight(UCoor2D::new(0, 0), UCoor2D::new(3, 1), blocked));
+        assert!(cells.has_line_of_sight(UCoor2D::new(1, 1), UCoor2D::new(3, 2), blocked));
+    }
+}
@@ -0,0 +1,462 @@
+//! Connected regions of cells and their shape
+//!
+//! [`flood_fill`] collects the region around a single cell of any [`Grid`], [`label_regions`] and
+//! [`label_components`] split a whole grid into regions (with a union-find over all cells).
+//! The shape of a [`Region`] (perimeter, sides, holes, outline) is measured on the square cells
+//! of the grid, as if the grid were bounded.
+
+use std::hash::Hash;
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::grid_array::GridArray;
+use crate::grid_trait::Grid;
+use crate::grid_types::UCoor2D;
+
+/// A set of connected cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<C: Eq + Hash = UCoor2D> {
+    cells: FxHashSet<C>,
+}
+
+impl<C: Eq + Hash> Default for Region<C> {
+    fn default() -> Self {
+        Self {
+            cells: FxHashSet::default(),
+        }
+    }
+}
+
+impl<C: Eq + Hash> FromIterator<C> for Region<C> {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Offsets of the orthogonal neighbors: north, east, south, west
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+impl<C: Eq + Hash> Region<C> {
+    /// Coordinates of the cells of the region (in no particular order)
+    pub fn cells(&self) -> impl Iterator<Item = &C> {
+        self.cells.iter()
+    }
+
+    /// Does the region contain the cell at `coor`
+    pub fn contains(&self, coor: &C) -> bool {
+        self.cells.contains(coor)
+    }
+
+    /// Number of cells
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl Region {
+    fn contains_offset(&self, coor: &UCoor2D, (dx, dy): (isize, isize)) -> bool {
+        match (coor.x.checked_add_signed(dx), coor.y.checked_add_signed(dy)) {
+            (Some(x), Some(y)) => self.cells.contains(&UCoor2D::new(x, y)),
+            _ => false,
+        }
+    }
+
+    /// Number of cell edges on the border of the region (including the borders of holes)
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|coor| {
+                ORTHOGONAL_OFFSETS
+                    .iter()
+                    .filter(|&&offset| !self.contains_offset(coor, offset))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight sides of the border of the region (including the borders of holes)
+    ///
+    /// Equals the number of corners, which are counted per cell: an outer corner has no region
+    /// cell on both adjacent sides, an inner corner has region cells on both sides but not
+    /// diagonally between them.
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|coor| {
+                ORTHOGONAL_OFFSETS
+                    .iter()
+                    .zip(ORTHOGONAL_OFFSETS.iter().cycle().skip(1))
+                    .filter(|&(&(dx1, dy1), &(dx2, dy2))| {
+                        let side1 = self.contains_offset(coor, (dx1, dy1));
+                        let side2 = self.contains_offset(coor, (dx2, dy2));
+                        let diagonal = self.contains_offset(coor, (dx1 + dx2, dy1 + dy2));
+                        (!side1 && !side2) || (side1 && side2 && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Smallest and largest coordinate of the cells (`None` for an empty region)
+    pub fn bounding_box(&self) -> Option<(UCoor2D, UCoor2D)> {
+        let mut cells = self.cells.iter();
+        let first = cells.next()?;
+        Some(cells.fold((*first, *first), |(min, max), coor| {
+            (min.component_min(coor), max.component_max(coor))
+        }))
+    }
+
+    /// Areas enclosed by the region
+    ///
+    /// A hole is an orthogonally connected area of cells outside of the region which is not
+    /// connected to the outside of the bounding box of the region.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some((min, max)) = self.bounding_box() else {
+            return Vec::new();
+        };
+        let mut visited: FxHashSet<UCoor2D> = FxHashSet::default();
+        let mut holes = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let coor = UCoor2D::new(x, y);
+                if self.contains(&coor) || visited.contains(&coor) {
+                    continue;
+                }
+                let mut area = vec![coor];
+                let mut is_hole = true;
+                visited.insert(coor);
+                let mut stack = vec![coor];
+                while let Some(coor) = stack.pop() {
+                    for (dx, dy) in ORTHOGONAL_OFFSETS {
+                        let neighbor = coor
+                            .x
+                            .checked_add_signed(dx)
+                            .zip(coor.y.checked_add_signed(dy))
+                            .map(UCoor2D::from_tuple)
+                            .filter(|neighbor| {
+                                (min.x..=max.x).contains(&neighbor.x)
+                                    && (min.y..=max.y).contains(&neighbor.y)
+                            });
+                        match neighbor {
+                            None => is_hole = false,
+                            Some(neighbor) => {
+                                if !self.contains(&neighbor) && visited.insert(neighbor) {
+                                    area.push(neighbor);
+                                    stack.push(neighbor);
+                                }
+                            }
+                        }
+                    }
+                }
+                if is_hole {
+                    holes.push(area.into_iter().collect());
+                }
+            }
+        }
+        holes
+    }
+
+    /// The borders of the region as closed polygons of cell corners (`(x, y)` is the upper left
+    /// corner of the cell `(x, y)`)
+    ///
+    /// Each polygon lists the corners where the border turns, walking with the region on the
+    /// right hand side (clockwise on screen for the outer border). The outer border comes first,
+    /// starting at its upper left corner. Where two cells of the region touch diagonally, the
+    /// border turns right, so the number of all corners equals [`Region::sides`].
+    pub fn outline(&self) -> Vec<Vec<UCoor2D>> {
+        // directed edges of the border, clockwise around every cell: top, right, bottom, left
+        let mut edges: Vec<(UCoor2D, UCoor2D)> = Vec::new();
+        for coor in &self.cells {
+            let (x, y) = (coor.x, coor.y);
+            let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+            for (side, offset) in ORTHOGONAL_OFFSETS.into_iter().enumerate() {
+                if !self.contains_offset(coor, offset) {
+                    edges.push((
+                        UCoor2D::from_tuple(corners[side]),
+                        UCoor2D::from_tuple(corners[(side + 1) % 4]),
+                    ));
+                }
+            }
+        }
+        // sorted by start corner (upper left first), so every walk starts at a corner
+        edges.sort_unstable();
+        let mut outgoing: FxHashMap<UCoor2D, Vec<usize>> = FxHashMap::default();
+        for (index, (start, _end)) in edges.iter().enumerate() {
+            outgoing.entry(*start).or_default().push(index);
+        }
+        let step = |index: usize| {
+            let (start, end) = &edges[index];
+            (
+                end.x.cast_signed() - start.x.cast_signed(),
+                end.y.cast_signed() - start.y.cast_signed(),
+            )
+        };
+
+        let mut used = vec![false; edges.len()];
+        let mut polygons = Vec::new();
+        for first in 0..edges.len() {
+            if used[first] {
+                continue;
+            }
+            let mut corners = Vec::new();
+            let mut current = first;
+            loop {
+                used[current] = true;
+                let end = &edges[current].1;
+                let (dx, dy) = step(current);
+                // where cells of the region touch diagonally, two edges continue: turn right
+                let next = outgoing[end]
+                    .iter()
+                    .copied()
+                    .filter(|&next| !used[next] || next == first)
+                    .max_by_key(|&next| step(next) == (-dy, dx))
+                    .expect("the border is closed");
+                if step(next) != (dx, dy) {
+                    corners.push(*end);
+                }
+                if next == first {
+                    break;
+                }
+                current = next;
+            }
+            // the walk started at a corner, which was added last
+            corners.rotate_right(1);
+            polygons.push(corners);
+        }
+        polygons
+    }
+}
+
+/// The cells around `start` which `belong` to its region (connected by the neighborhood of the grid)
+///
+/// The region is empty if `start` itself does not belong to it.
+pub fn flood_fill<G: Grid>(
+    grid: &G,
+    start: &G::Coor,
+    belongs: impl Fn(&G::Cell) -> bool,
+) -> Region<G::Coor> {
+    let mut region = Region::default();
+    if !grid.cell(start).is_some_and(&belongs) {
+        return region;
+    }
+    region.cells.insert(start.clone());
+    let mut stack = vec![start.clone()];
+    while let Some(coor) = stack.pop() {
+        for (neighbor, _direction, cell) in grid.neighbor_cells(&coor) {
+            if belongs(cell) && region.cells.insert(neighbor.clone()) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    region
+}
+
+/// A grid split into regions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionLabels {
+    labels: GridArray<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl RegionLabels {
+    /// Label (index into [`RegionLabels::regions`]) of every cell, `None` if it is in no region
+    pub fn labels(&self) -> &GridArray<Option<usize>> {
+        &self.labels
+    }
+
+    /// Label of the cell at `coor`
+    pub fn label(&self, coor: &UCoor2D) -> Option<usize> {
+        self.labels.get(coor.x, coor.y).copied().flatten()
+    }
+
+    /// All regions, ordered by their first cell (row by row)
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// The region of the cell at `coor`
+    pub fn region(&self, coor: &UCoor2D) -> Option<&Region> {
+        self.label(coor).map(|label| &self.regions[label])
+    }
+
+    /// All regions, ordered by their first cell (row by row)
+    pub fn into_regions(self) -> Vec<Region> {
+        self.regions
+    }
+}
+
+/// Splits the whole grid into regions, where neighbors (by the neighborhood of the grid) are in
+/// the same region if they are `connected`, e.g. `|a, b| a == b` for regions of equal cells
+pub fn label_regions<T: Default + Clone>(
+    grid: &GridArray<T>,
+    connected: impl Fn(&T, &T) -> bool,
+) -> RegionLabels {
+    label(grid, |_cell| true, connected)
+}
+
+/// Splits the cells which `belong` to a region into connected components (by the neighborhood of
+/// the grid), the other cells are in no region
+pub fn label_components<T: Default + Clone>(
+    grid: &GridArray<T>,
+    belongs: impl Fn(&T) -> bool,
+) -> RegionLabels {
+    label(grid, &belongs, |_cell, _neighbor| true)
+}
+
+fn label<T: Default + Clone>(
+    grid: &GridArray<T>,
+    belongs: impl Fn(&T) -> bool,
+    connected: impl Fn(&T, &T) -> bool,
+) -> RegionLabels {
+    let width = grid.width();
+    let index = |coor: &UCoor2D| coor.y * width + coor.x;
+    let mut union_find = UnionFind::new(width * grid.height());
+    for (coor, cell) in grid.all_cells().filter(|(_coor, cell)| belongs(cell)) {
+        for (neighbor, neighbor_cell) in grid.neighborhood_cells(coor.x, coor.y) {
+            if belongs(neighbor_cell) && connected(cell, neighbor_cell) {
+                union_find.union(index(&coor), index(&neighbor));
+            }
+        }
+    }
+
+    let mut labels = vec![None; width * grid.height()];
+    let mut root_labels = vec![None; width * grid.height()];
+    let mut regions: Vec<Region> = Vec::new();
+    for y in 0..grid.height() {
+        for x in 0..width {
+            let coor = UCoor2D::new(x, y);
+            if !belongs(grid.get_unchecked(x, y)) {
+                continue;
+            }
+            let root = union_find.find(index(&coor));
+            let label = *root_labels[root].get_or_insert_with(|| {
+                regions.push(Region::default());
+                regions.len() - 1
+            });
+            labels[index(&coor)] = Some(label);
+            regions[label].cells.insert(coor);
+        }
+    }
+    RegionLabels {
+        labels: GridArray::from_1d_vec(grid.get_topology(), grid.get_neighborhood(), width, labels),
+        regions,
+    }
+}
+
+/// Disjoint sets of indexes with path compression and union by size
+struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut index = index;
+        while self.parents[index] != root {
+            index = std::mem::replace(&mut self.parents[index], root);
+        }
+        root
+    }
+
+    fn union(&mut self, index1: usize, index2: usize) {
+        let (root1, root2) = (self.find(index1), self.find(index2));
+        if root1 == root2 {
+            return;
+        }
+        let (larger, smaller) = if self.sizes[root1] >= self.sizes[root2] {
+            (root1, root2)
+        } else {
+            (root2, root1)
+        };
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::bounded;
+
+    #[test]
+    fn test_label_regions() {
+        let garden = bounded("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let labels = label_regions(&garden, |plant, other_plant| plant == other_plant);
+        let shapes: Vec<_> = labels
+            .regions()
+            .iter()
+            .map(|region| (region.area(), region.perimeter(), region.sides()))
+            .collect();
+        assert_eq!(
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)],
+            shapes
+        );
+        assert_eq!(Some(2), labels.label(&UCoor2D::new(3, 3)));
+        assert_eq!(
+            Some((UCoor2D::new(2, 1), UCoor2D::new(3, 3))),
+            labels.regions()[2].bounding_box()
+        );
+    }
+
+    #[test]
+    fn test_holes_and_outline() {
+        let garden = bounded("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n");
+        let region = flood_fill(&garden, &UCoor2D::new(0, 0), |&plant| plant == 'O');
+        assert_eq!(
+            (21, 36, 20),
+            (region.area(), region.perimeter(), region.sides())
+        );
+        assert_eq!(4, region.holes().len());
+        let outline = region.outline();
+        assert_eq!(5, outline.len());
+        assert_eq!(
+            vec![
+                UCoor2D::new(0, 0),
+                UCoor2D::new(5, 0),
+                UCoor2D::new(5, 5),
+                UCoor2D::new(0, 5)
+            ],
+            outline[0]
+        );
+        // holes are walked the other way round
+        assert_eq!(
+            vec![
+                UCoor2D::new(1, 1),
+                UCoor2D::new(1, 2),
+                UCoor2D::new(2, 2),
+                UCoor2D::new(2, 1)
+            ],
+            outline[1]
+        );
+
+        let components = label_components(&garden, |&plant| plant == 'X');
+        assert_eq!(4, components.regions().len());
+        assert_eq!(None, components.label(&UCoor2D::new(0, 0)));
+        assert_eq!(region.holes().len(), components.regions().len());
+    }
+
+    #[test]
+    fn test_diagonal_touch() {
+        // the region touches itself diagonally at the corner (2, 2)
+        let garden = bounded("AAA.\nA.A.\nAA.A\n.AAA\n");
+        let region = flood_fill(&garden, &UCoor2D::new(0, 0), |&plant| plant == 'A');
+        assert_eq!(11, region.area());
+        let outline = region.outline();
+        assert_eq!(region.sides(), outline.iter().map(Vec::len).sum::<usize>());
+        let hole_areas: Vec<_> = region.holes().iter().map(Region::area).collect();
+        assert_eq!(vec![1, 1], hole_areas);
+    }
+}
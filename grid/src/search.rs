@@ -0,0 +1,443 @@
+//! Shortest paths through grids
+//!
+//! [`bfs`], [`dijkstra`] and [`astar`] search the cells of a [`Grid`] which are passable.
+//! Searches over other nodes (e.g. a cell together with a facing direction) use the generic
+//! [`breadth_first_search`] and [`cheapest_paths`] directly.
+//!
+//! Every search returns a [`SearchResult`] with the distances of all visited nodes and the
+//! predecessor DAG of all shortest paths, so single paths, all shortest paths and the nodes on
+//! them can be taken from the same result.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::grid_trait::Grid;
+
+/// Distances from the start and shortest-path predecessors of the visited nodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<N: Clone + Eq + Hash> {
+    start: N,
+    distances: FxHashMap<N, usize>,
+    predecessors: FxHashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    fn new(start: N) -> Self {
+        Self {
+            distances: FxHashMap::from_iter([(start.clone(), 0)]),
+            predecessors: FxHashMap::default(),
+            goals: Vec::new(),
+            start,
+        }
+    }
+
+    /// The node the search started at
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Distance of the shortest path from the start to `node` (`None` if it was not reached)
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distances of all visited nodes
+    ///
+    /// Without a goal, these are the shortest distances of all reachable nodes. A search which
+    /// stopped at a goal only guarantees them for nodes which are not farther away than the goal.
+    pub fn distances(&self) -> &FxHashMap<N, usize> {
+        &self.distances
+    }
+
+    /// Predecessors of `node` on all shortest paths to it (the edges of the predecessor DAG)
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goals reached with the shortest distance (empty if no goal was reached)
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Distance of the nearest goal
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(self.goals.first()?)
+    }
+
+    /// A shortest path from the start to `node` (both included)
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(predecessor) = self.predecessors(path.last().unwrap()).first() {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// A shortest path from the start to the nearest goal (both included)
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goals.first()?)
+    }
+
+    /// All shortest paths from the start to `node` (their number can grow exponentially)
+    pub fn all_paths(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return Vec::new();
+        }
+        let predecessors = self.predecessors(node);
+        if predecessors.is_empty() {
+            return vec![vec![node.clone()]];
+        }
+        predecessors
+            .iter()
+            .flat_map(|predecessor| self.all_paths(predecessor))
+            .map(|mut path| {
+                path.push(node.clone());
+                path
+            })
+            .collect()
+    }
+
+    /// All nodes on any shortest path from the start to one of `nodes`
+    pub fn nodes_on_paths<'a>(&'a self, nodes: impl IntoIterator<Item = &'a N>) -> FxHashSet<N> {
+        let mut on_paths = FxHashSet::default();
+        let mut todo: Vec<&N> = nodes
+            .into_iter()
+            .filter(|node| self.distances.contains_key(node))
+            .collect();
+        while let Some(node) = todo.pop() {
+            if on_paths.insert(node.clone()) {
+                todo.extend(self.predecessors(node));
+            }
+        }
+        on_paths
+    }
+
+    /// All nodes on any shortest path from the start to the nearest goals
+    pub fn nodes_on_goal_paths(&self) -> FxHashSet<N> {
+        self.nodes_on_paths(&self.goals)
+    }
+}
+
+/// Breadth first search from `start` over edges of length 1
+///
+/// The search stops as soon as all goals at the shortest distance are found
+/// (use `|_| false` as `is_goal` to visit all reachable nodes).
+pub fn breadth_first_search<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    is_goal: impl Fn(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        if result
+            .goal_distance()
+            .is_some_and(|goal_distance| distance > goal_distance)
+        {
+            break;
+        }
+        if is_goal(&node) {
+            result.goals.push(node);
+            continue;
+        }
+        for successor in successors(&node) {
+            match result.distances.get(&successor) {
+                None => {
+                    result.distances.insert(successor.clone(), distance + 1);
+                    result
+                        .predecessors
+                        .insert(successor.clone(), vec![node.clone()]);
+                    queue.push_back((successor, distance + 1));
+                }
+                Some(&successor_distance) if successor_distance == distance + 1 => {
+                    result
+                        .predecessors
+                        .entry(successor)
+                        .or_default()
+                        .push(node.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    result
+}
+
+/// Node in the priority queue, ordered by the smallest estimated total distance
+struct QueueEntry<N> {
+    estimate: usize,
+    distance: usize,
+    node: N,
+}
+
+impl<N> PartialEq for QueueEntry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for QueueEntry<N> {}
+
+impl<N> PartialOrd for QueueEntry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for QueueEntry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max heap; prefer the longer distance of equal estimates (deeper nodes)
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.distance.cmp(&other.distance))
+    }
+}
+
+/// Dijkstra (`heuristic` is `|_| 0`) or A* search from `start`
+///
+/// `successors` returns the neighbors of a node with the costs to reach them. `heuristic`
+/// must never overestimate the distance to the nearest goal. The search stops as soon as all
+/// goals at the shortest distance are found (use `|_| false` as `is_goal` to visit all
+/// reachable nodes).
+pub fn cheapest_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> usize,
+    is_goal: impl Fn(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut queue = BinaryHeap::from([QueueEntry {
+        estimate: heuristic(&start),
+        distance: 0,
+        node: start,
+    }]);
+    while let Some(QueueEntry {
+        estimate,
+        distance,
+        node,
+    }) = queue.pop()
+    {
+        if result
+            .goal_distance()
+            .is_some_and(|goal_distance| estimate > goal_distance)
+        {
+            break;
+        }
+        if result.distances[&node] < distance {
+            // a shorter path to the node was found after it was queued
+            continue;
+        }
+        if is_goal(&node) {
+            result.goals.push(node);
+            continue;
+        }
+        for (successor, costs) in successors(&node) {
+            let successor_distance = distance + costs;
+            match result.distances.get(&successor) {
+                Some(&known_distance) if known_distance < successor_distance => {}
+                Some(&known_distance) if known_distance == successor_distance => {
+                    result
+                        .predecessors
+                        .entry(successor)
+                        .or_default()
+                        .push(node.clone());
+                }
+                _ => {
+                    result
+                        .distances
+                        .insert(successor.clone(), successor_distance);
+                    result
+                        .predecessors
+                        .insert(successor.clone(), vec![node.clone()]);
+                    queue.push(QueueEntry {
+                        estimate: successor_distance + heuristic(&successor),
+                        distance: successor_distance,
+                        node: successor,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Neighbors of `coor` whose cells are passable, with the directions to reach them
+fn passable_neighbors<G: Grid>(
+    grid: &G,
+    coor: &G::Coor,
+    passable: impl Fn(&G::Cell) -> bool,
+) -> Vec<(G::Coor, G::Direction)> {
+    grid.neighbor_cells(coor)
+        .filter(|(_neighbor, _direction, cell)| passable(cell))
+        .map(|(neighbor, direction, _cell)| (neighbor, direction))
+        .collect()
+}
+
+/// Shortest paths from `start` through the passable cells, every step costs 1
+///
+/// Without a `goal`, all reachable cells are visited.
+pub fn bfs<G: Grid>(
+    grid: &G,
+    start: &G::Coor,
+    goal: Option<&G::Coor>,
+    passable: impl Fn(&G::Cell) -> bool,
+) -> SearchResult<G::Coor> {
+    breadth_first_search(
+        start.clone(),
+        |coor| {
+            passable_neighbors(grid, coor, &passable)
+                .into_iter()
+                .map(|(neighbor, _direction)| neighbor)
+        },
+        |coor| Some(coor) == goal,
+    )
+}
+
+/// Cheapest paths from `start` through the passable cells
+///
+/// `costs` returns the costs of a step from a cell to its neighbor in the given direction.
+/// Without a `goal`, all reachable cells are visited.
+pub fn dijkstra<G: Grid>(
+    grid: &G,
+    start: &G::Coor,
+    goal: Option<&G::Coor>,
+    passable: impl Fn(&G::Cell) -> bool,
+    costs: impl Fn(&G::Coor, &G::Coor, G::Direction) -> usize,
+) -> SearchResult<G::Coor> {
+    astar(grid, start, goal, passable, costs, |_coor| 0)
+}
+
+/// Cheapest paths from `start` through the passable cells, directed by `heuristic`
+///
+/// `costs` returns the costs of a step from a cell to its neighbor in the given direction.
+/// `heuristic` (e.g. the manhattan distance) must never overestimate the costs to the `goal`.
+pub fn astar<G: Grid>(
+    grid: &G,
+    start: &G::Coor,
+    goal: Option<&G::Coor>,
+    passable: impl Fn(&G::Cell) -> bool,
+    costs: impl Fn(&G::Coor, &G::Coor, G::Direction) -> usize,
+    heuristic: impl Fn(&G::Coor) -> usize,
+) -> SearchResult<G::Coor> {
+    cheapest_paths(
+        start.clone(),
+        |coor| {
+            passable_neighbors(grid, coor, &passable)
+                .into_iter()
+                .map(|(neighbor, direction)| {
+                    let step_costs = costs(coor, &neighbor, direction);
+                    (neighbor, step_costs)
+                })
+                .collect::<Vec<_>>()
+        },
+        heuristic,
+        |coor| Some(coor) == goal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::grid_hashmap::GridHashMap;
+    use crate::grid_types::{Direction, ICoor2D, Neighborhood, UCoor2D};
+    use crate::test_util::{maze, MAZE};
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let goal = UCoor2D::new(3, 3);
+        let result = bfs(&grid, &UCoor2D::new(0, 0), Some(&goal), |&cell| cell == '.');
+        assert_eq!(Some(6), result.goal_distance());
+        assert_eq!(std::slice::from_ref(&goal), result.goals());
+        assert_eq!(7, result.goal_path().unwrap().len());
+        assert_eq!(3, result.all_paths(&goal).len());
+        assert_eq!(11, result.nodes_on_goal_paths().len());
+        assert!(result.all_paths(&goal).iter().all_unique());
+
+        let everything = bfs(&grid, &UCoor2D::new(0, 0), None, |&cell| cell == '.');
+        assert_eq!(12, everything.distances().len());
+        assert_eq!(Some(4), everything.distance(&UCoor2D::new(3, 1)));
+        assert_eq!(None, everything.distance(&UCoor2D::new(3, 0)));
+        assert_eq!(None, everything.path(&UCoor2D::new(1, 1)));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let grid = maze();
+        let start = UCoor2D::new(0, 0);
+        let goal = UCoor2D::new(3, 3);
+        // moving down is expensive
+        let costs = |_from: &UCoor2D, _to: &UCoor2D, direction| {
+            if direction == Direction::South {
+                10
+            } else {
+                1
+            }
+        };
+        let result = dijkstra(&grid, &start, Some(&goal), |&cell| cell == '.', costs);
+        assert_eq!(Some(33), result.goal_distance());
+        assert_eq!(3, result.all_paths(&goal).len());
+        let heuristic = |coor: &UCoor2D| coor.manhattan_distance(&goal);
+        let astar_result = astar(
+            &grid,
+            &start,
+            Some(&goal),
+            |&cell| cell == '.',
+            costs,
+            heuristic,
+        );
+        assert_eq!(result.goal_distance(), astar_result.goal_distance());
+        assert_eq!(
+            result.nodes_on_goal_paths(),
+            astar_result.nodes_on_goal_paths()
+        );
+        assert!(astar_result.distances().len() <= result.distances().len());
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let grid = GridHashMap::from_newline_separated_string(Neighborhood::Orthogonal, MAZE);
+        let result = bfs(
+            &grid,
+            &ICoor2D::new(0, 0),
+            Some(&ICoor2D::new(3, 3)),
+            |&cell| cell == '.',
+        );
+        assert_eq!(Some(6), result.goal_distance());
+    }
+
+    #[test]
+    fn test_cheapest_paths_with_several_goals() {
+        // nodes are numbers, steps go to n + 1 (costs 2) or 2 * n (costs 3)
+        let result = cheapest_paths(
+            1_usize,
+            |&n| [(n + 1, 2), (2 * n, 3)],
+            |_| 0,
+            |&n| n == 4 || n == 5,
+        );
+        assert_eq!(Some(5), result.goal_distance());
+        assert_eq!(vec![vec![1, 2, 4]], result.all_paths(&4));
+        assert_eq!([4], result.goals());
+        assert_eq!(&1, result.start());
+        assert!(result.predecessors(&1).is_empty());
+    }
+}
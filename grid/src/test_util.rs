@@ -0,0 +1,22 @@
+//! Fixtures shared by the unit tests
+
+use crate::grid_array::GridArray;
+use crate::grid_types::{Neighborhood, Topology};
+
+/// Small maze with walls (`#`), the shortest way between opposite corners takes 6 steps
+pub(crate) const MAZE: &str = "...#\n.#..\n...#\n#...\n";
+
+/// Grid of the characters of newline separated rows
+pub(crate) fn grid(topology: Topology, neighborhood: Neighborhood, input: &str) -> GridArray<char> {
+    GridArray::from_newline_separated_string(topology, neighborhood, input)
+}
+
+/// Bounded grid with orthogonal steps of the characters of newline separated rows
+pub(crate) fn bounded(input: &str) -> GridArray<char> {
+    grid(Topology::Bounded, Neighborhood::Orthogonal, input)
+}
+
+/// [`MAZE`] as bounded grid with orthogonal steps
+pub(crate) fn maze() -> GridArray<char> {
+    bounded(MAZE)
+}
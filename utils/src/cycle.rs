@@ -0,0 +1,276 @@
+//! Detecting cycles in sequences of states
+//!
+//! A sequence `x0, f(x0), f(f(x0)), ...` over a finite set of states eventually repeats: after a
+//! prefix of `prefix` states it runs through a cycle of `period` states forever. [`floyd`] and
+//! [`brent`] find both lengths keeping only a few states, [`find_cycle`] remembers every state
+//! in a hash set (fewest calls of `step`), and [`find_cycle_by_fingerprint`] remembers only
+//! 64-bit hashes, so states which are expensive to clone are never cloned. [`state_after`] skips
+//! the repeated cycles to get the state after any number of steps ([`state_after_by_fingerprint`]
+//! without cloning the states).
+//!
+//! ```
+//! use utils::cycle::{brent, state_after};
+//!
+//! // 7, then 3, 6, 2, 5, 1, 4, 0 in a cycle (adding 3 modulo 7)
+//! let step = |&x: &u32| (x + 3) % 7;
+//! let cycle = brent(&7, step);
+//! assert_eq!((1, 7), (cycle.prefix, cycle.period));
+//! assert_eq!(4, state_after(7, step, 1_000_000_000));
+//! ```
+//!
+//! All detectors compare states, except for the `_by_fingerprint` variants which compare
+//! fingerprints: two different states with the same 64-bit hash are taken as equal, so they are
+//! only an option if a (very unlikely) wrong result is acceptable.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of a sequence of states ending in a cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// number of states before the cycle starts (the index of the first repeated state)
+    pub prefix: usize,
+    /// number of states in the cycle (1 for a fixed point)
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The smallest step after which the state is the same as after `n` steps
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Floyd's tortoise and hare: finds the cycle keeping two states
+///
+/// The sequence has to repeat eventually, otherwise this does not return.
+pub fn floyd<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the hare runs twice as fast until both meet somewhere in the cycle
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let ahead = step(&hare);
+        hare = step(&ahead);
+    }
+    // the distance of the meeting point from the start is a multiple of the period
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Brent's algorithm: finds the cycle keeping two states, usually with fewer steps than Floyd
+///
+/// The sequence has to repeat eventually, otherwise this does not return.
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the tortoise waits at powers of two for the hare to come around
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+    // with the hare one period ahead, both meet at the start of the cycle
+    tortoise = start.clone();
+    hare = start.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Finds the cycle remembering every state (each state is computed once)
+///
+/// Returns `None` if no state repeats within `max_steps` steps.
+pub fn find_cycle<S: Clone + Hash + Eq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> S,
+    max_steps: usize,
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut state = start.clone();
+    for index in 0..=max_steps {
+        if let Some(prefix) = seen.insert(state.clone(), index) {
+            return Some(Cycle {
+                prefix,
+                period: index - prefix,
+            });
+        }
+        state = step(&state);
+    }
+    None
+}
+
+/// Finds the cycle remembering the fingerprints (64-bit hashes) of the states, which are never
+/// cloned
+///
+/// Returns `None` if no fingerprint repeats within `max_steps` steps.
+pub fn find_cycle_by_fingerprint<S: Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    max_steps: usize,
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for index in 0..=max_steps {
+        if let Some(prefix) = seen.insert(fxhash::hash64(&state), index) {
+            return Some(Cycle {
+                prefix,
+                period: index - prefix,
+            });
+        }
+        state = step(&state);
+    }
+    None
+}
+
+/// The state after `n` steps from `start`, skipping the repeated cycles
+///
+/// Every state is remembered (like [`find_cycle`]); at most `prefix + 2 * period` steps are
+/// computed.
+pub fn state_after<S: Clone + Hash + Eq>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for index in 0..n {
+        if let Some(prefix) = seen.insert(state.clone(), index) {
+            // the current state repeats the one after `prefix` steps
+            for _ in 0..(n - index) % (index - prefix) {
+                state = step(&state);
+            }
+            return state;
+        }
+        state = step(&state);
+    }
+    state
+}
+
+/// The state after `n` steps from `start` like [`state_after`], identifying the states by their
+/// fingerprints (see [`find_cycle_by_fingerprint`])
+///
+/// The states are never cloned, but two states with the same fingerprint are taken as equal.
+pub fn state_after_by_fingerprint<S: Hash>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for index in 0..n {
+        if let Some(prefix) = seen.insert(fxhash::hash64(&state), index) {
+            // the current state repeats the one after `prefix` steps
+            for _ in 0..(n - index) % (index - prefix) {
+                state = step(&state);
+            }
+            return state;
+        }
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2, 5, 1, 8, then 3, 7, 4, 6, 0 in a cycle (in a random order)
+    const NEXT: [usize; 9] = [3, 8, 5, 7, 6, 1, 0, 4, 3];
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature of a step
+    fn step(state: &usize) -> usize {
+        NEXT[*state]
+    }
+
+    #[test]
+    fn test_detectors_agree() {
+        let expected = Cycle {
+            prefix: 4,
+            period: 5,
+        };
+        assert_eq!(expected, floyd(&2, step));
+        assert_eq!(expected, brent(&2, step));
+        assert_eq!(Some(expected), find_cycle(&2, step, 100));
+        assert_eq!(Some(expected), find_cycle_by_fingerprint(2, step, 100));
+        assert_eq!(None, find_cycle(&2, step, 8));
+        assert_eq!(Some(expected), find_cycle(&2, step, 9));
+        assert_eq!(None, find_cycle_by_fingerprint(2, step, 8));
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let halve = |state: &u64| state / 2;
+        let expected = Cycle {
+            prefix: 7,
+            period: 1,
+        };
+        assert_eq!(expected, floyd(&100, halve));
+        assert_eq!(expected, brent(&100, halve));
+        assert_eq!(Some(expected), find_cycle(&100, halve, 100));
+        assert_eq!(0, state_after(100, halve, usize::MAX));
+    }
+
+    #[test]
+    fn test_state_after() {
+        let mut states = vec![2];
+        for _ in 0..100 {
+            states.push(step(states.last().unwrap()));
+        }
+        for (n, expected) in states.iter().enumerate() {
+            assert_eq!(*expected, state_after(2, step, n));
+            assert_eq!(*expected, state_after_by_fingerprint(2, step, n));
+        }
+        let cycle = floyd(&2, step);
+        assert_eq!(3, cycle.equivalent_step(3));
+        assert_eq!(4, cycle.equivalent_step(9));
+        assert_eq!(
+            states[cycle.equivalent_step(1_000_000_007)],
+            state_after(2, step, 1_000_000_007)
+        );
+    }
+
+    #[test]
+    fn test_expensive_states() {
+        // a large state that is never cloned: a buffer rotated by a step of 3
+        #[derive(Hash)]
+        struct Buffer(Vec<u8>);
+        let rotate = |buffer: &Buffer| {
+            let mut rotated = buffer.0.clone();
+            rotated.rotate_left(3);
+            Buffer(rotated)
+        };
+        let start = Buffer((0..10).collect());
+        assert_eq!(
+            Some(Cycle {
+                prefix: 0,
+                period: 10
+            }),
+            find_cycle_by_fingerprint(Buffer((0..10).collect()), rotate, 100)
+        );
+        assert_eq!(
+            vec![9, 0, 1, 2, 3, 4, 5, 6, 7, 8],
+            state_after_by_fingerprint(start, rotate, 1_003).0
+        );
+    }
+}
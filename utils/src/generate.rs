@@ -0,0 +1,133 @@
+//! Random puzzle inputs for stress tests and benchmarks
+//!
+//! Every [`Day`](crate::solution::Day) generates structurally valid inputs of a configurable size.
+//! The generators are driven by a seeded [`StdRng`], so every generated input (and every failure
+//! it provokes) can be reproduced from its seed.
+
+use itertools::Itertools;
+pub use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::params::Params;
+
+/// Size of the generated inputs if none is given
+pub const DEFAULT_SIZE: usize = 50;
+
+/// Seed of the generated inputs if none is given
+pub const DEFAULT_SEED: u64 = 0;
+
+/// A generated puzzle input with the parameters it needs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedInput {
+    /// The puzzle input (lines separated by `\n`, without trailing newline)
+    pub input: String,
+    /// Parameters which replace the default parameters of the parts knowing them
+    pub params: Params,
+}
+
+impl GeneratedInput {
+    /// A generated input which fits the default parameters
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            params: Params::default(),
+        }
+    }
+
+    /// Sets a parameter which the generated input needs, e.g. the width of a generated area
+    #[must_use]
+    pub fn with_param(mut self, name: &str, value: &(impl ToString + ?Sized)) -> Self {
+        self.params.set(name, value);
+        self
+    }
+}
+
+/// The random number generator for the given seed
+pub fn seeded_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Joins the rows of a grid into newline separated text
+pub fn grid_to_string(rows: &[Vec<char>]) -> String {
+    rows.iter()
+        .map(|row| row.iter().collect::<String>())
+        .join("\n")
+}
+
+/// A grid whose cells are chosen randomly from `chars` (duplicates make a char more likely)
+pub fn random_grid<R: Rng + ?Sized>(
+    rng: &mut R,
+    width: usize,
+    height: usize,
+    chars: &[char],
+) -> Vec<Vec<char>> {
+    (0..height)
+        .map(|_y| {
+            (0..width)
+                .map(|_x| *chars.choose(rng).expect("chars must not be empty"))
+                .collect()
+        })
+        .collect()
+}
+
+/// A perfect maze (exactly one path between two open cells) of walls `#` and open cells `.`
+///
+/// The maze is surrounded by walls and its open cells have odd coordinates, so `width` and
+/// `height` are rounded up to odd numbers (at least 3). Every open cell is reachable from the
+/// upper left cell `(1, 1)`.
+pub fn maze<R: Rng + ?Sized>(rng: &mut R, width: usize, height: usize) -> Vec<Vec<char>> {
+    let width = width.max(3) | 1;
+    let height = height.max(3) | 1;
+    let mut rows = vec![vec!['#'; width]; height];
+    // randomized depth first search over the cells with odd coordinates
+    rows[1][1] = '.';
+    let mut stack: Vec<(usize, usize)> = vec![(1, 1)];
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&(next_x, next_y)| {
+                next_x < width - 1 && next_y < height - 1 && rows[next_y][next_x] == '#'
+            })
+            .collect_vec();
+        match unvisited.choose(rng) {
+            Some(&(next_x, next_y)) => {
+                rows[y.midpoint(next_y)][x.midpoint(next_x)] = '.';
+                rows[next_y][next_x] = '.';
+                stack.push((next_x, next_y));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng() {
+        let numbers = |seed| {
+            let mut rng = seeded_rng(seed);
+            (0..10).map(|_| rng.gen::<u32>()).collect_vec()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn test_maze() {
+        let rows = maze(&mut seeded_rng(1), 10, 7);
+        assert_eq!((11, 7), (rows[0].len(), rows.len()));
+        assert!(rows[0].iter().chain(&rows[6]).all(|&ch| ch == '#'));
+        // a perfect maze is a tree: edges = open cells - 1
+        let open = rows.iter().flatten().filter(|&&ch| ch == '.').count();
+        let cell_count = 5 * 3;
+        assert_eq!(cell_count + cell_count - 1, open);
+        assert!(grid_to_string(&rows).starts_with("###########\n#."));
+    }
+}
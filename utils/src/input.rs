@@ -0,0 +1,109 @@
+//! Loading of the puzzle input at runtime (from a file, stdin or an environment variable)
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::correct_folder;
+
+/// Environment variable which can hold the path of the input file (`-` reads stdin)
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+/// Argument value which reads the input from stdin
+pub const STDIN_ARG: &str = "-";
+
+/// Errors while loading the puzzle input
+#[derive(Error, Diagnostic, Debug)]
+pub enum InputError {
+    /// The input file could not be read
+    #[error("Could not read input file {}", path.display())]
+    #[diagnostic(
+        code(aoc::input::file),
+        help("pass the input file as argument, set {INPUT_ENV_VAR} or put the file into the day folder")
+    )]
+    File {
+        /// Path of the input file
+        path: PathBuf,
+        /// Underlying io error
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The input could not be read from stdin
+    #[error("Could not read input from stdin")]
+    #[diagnostic(code(aoc::input::stdin))]
+    Stdin(#[source] std::io::Error),
+}
+
+/// Normalises Windows line endings (`\r\n`) to `\n`
+pub fn normalize_newlines(input: &str) -> String {
+    input.replace("\r\n", "\n")
+}
+
+/// Reads the input file (works in root folder and in dayXY, see [`correct_folder`])
+///
+/// # Errors
+/// If the file cannot be read, the error names the file
+pub fn read_input_file(file_name: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = correct_folder(&file_name.as_ref().to_string_lossy());
+    std::fs::read_to_string(&path)
+        .map(|input| normalize_newlines(&input))
+        .map_err(|source| InputError::File { path, source })
+}
+
+/// Reads the whole input from stdin
+///
+/// # Errors
+/// If stdin cannot be read
+pub fn read_input_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(normalize_newlines(&input))
+}
+
+/// Loads the input from `arg`, from [`INPUT_ENV_VAR`] or from `default_file_name` (in this order).
+///
+/// `arg` and the environment variable can be a file path or [`STDIN_ARG`] to read stdin.
+///
+/// # Errors
+/// If the chosen file or stdin cannot be read
+pub fn load_input(arg: Option<&str>, default_file_name: &str) -> Result<String, InputError> {
+    let source = arg
+        .map(ToString::to_string)
+        .or_else(|| std::env::var(INPUT_ENV_VAR).ok())
+        .unwrap_or_else(|| default_file_name.to_string());
+    if source == STDIN_ARG {
+        read_input_stdin()
+    } else {
+        read_input_file(source)
+    }
+}
+
+/// Loads the input like [`load_input`] using the first command line argument
+///
+/// # Errors
+/// If the chosen file or stdin cannot be read
+pub fn load_input_from_args(default_file_name: &str) -> Result<String, InputError> {
+    let arg = std::env::args().nth(1);
+    load_input(arg.as_deref(), default_file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_newlines() {
+        assert_eq!("a\nb\n\nc", normalize_newlines("a\r\nb\r\n\r\nc"));
+    }
+
+    #[test]
+    fn test_missing_file_is_named() {
+        let err = read_input_file("day99/missing_input.txt").unwrap_err();
+        assert!(err.to_string().contains("missing_input.txt"));
+    }
+}
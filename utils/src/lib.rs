@@ -15,6 +15,14 @@ use std::ops::RangeInclusive;
 use std::path::PathBuf;
 use std::time::Instant;
 
+pub mod cycle;
+pub mod generate;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod parse_error;
+pub mod solution;
+
 pub use num::integer::div_rem;
 pub use num::integer::gcd;
 pub use num::integer::lcm;
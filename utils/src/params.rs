@@ -0,0 +1,205 @@
+//! Named puzzle parameters (e.g. grid sizes or cycle counts which differ between example and real input)
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Errors while reading or overriding puzzle parameters
+#[derive(Error, Diagnostic, Debug, PartialEq, Eq)]
+pub enum ParamError {
+    /// The parameter is not known by the solution
+    #[error("Unknown parameter '{name}'")]
+    #[diagnostic(code(aoc::params::unknown), help("known parameters: {known}"))]
+    Unknown {
+        /// Name of the parameter
+        name: String,
+        /// Comma separated names of the known parameters
+        known: String,
+    },
+
+    /// The parameter was not given
+    #[error("Missing parameter '{name}'")]
+    #[diagnostic(code(aoc::params::missing))]
+    Missing {
+        /// Name of the parameter
+        name: String,
+    },
+
+    /// The value of the parameter cannot be converted
+    #[error("Invalid value '{value}' for parameter '{name}': {reason}")]
+    #[diagnostic(code(aoc::params::invalid))]
+    Invalid {
+        /// Name of the parameter
+        name: String,
+        /// Given value
+        value: String,
+        /// Why the conversion failed
+        reason: String,
+    },
+
+    /// A parameter assignment is not of the form `name=value`
+    #[error("Invalid parameter assignment '{0}'")]
+    #[diagnostic(code(aoc::params::syntax), help("use name=value, e.g. width=11"))]
+    Syntax(String),
+}
+
+/// Puzzle parameters by name; the values are kept as strings and converted on access
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl<K: Into<String>, V: ToString, const N: usize> From<[(K, V); N]> for Params {
+    fn from(params: [(K, V); N]) -> Self {
+        Self(
+            params
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl Params {
+    /// Returns the converted value of the parameter
+    ///
+    /// # Errors
+    /// If the parameter is missing or cannot be converted to `T`
+    pub fn get<T>(&self, name: &str) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.0.get(name).ok_or_else(|| ParamError::Missing {
+            name: name.to_string(),
+        })?;
+        value.parse().map_err(|err: T::Err| ParamError::Invalid {
+            name: name.to_string(),
+            value: value.clone(),
+            reason: err.to_string(),
+        })
+    }
+
+    /// Sets the value of the parameter
+    pub fn set(&mut self, name: impl Into<String>, value: &(impl ToString + ?Sized)) {
+        self.0.insert(name.into(), value.to_string());
+    }
+
+    /// Whether there are no parameters
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over all parameters (sorted by name)
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Returns these parameters with the values of `overrides` replacing them
+    ///
+    /// # Errors
+    /// If `overrides` contains a parameter which is not part of these parameters
+    pub fn with_overrides(&self, overrides: &Params) -> Result<Params, ParamError> {
+        let mut params = self.clone();
+        for (name, value) in overrides.iter() {
+            if !self.0.contains_key(name) {
+                return Err(ParamError::Unknown {
+                    name: name.to_string(),
+                    known: self.0.keys().join(", "),
+                });
+            }
+            params.set(name, value);
+        }
+        Ok(params)
+    }
+
+    /// Returns these parameters with the values of those `overrides` which are part of these
+    /// parameters (all other `overrides` are ignored)
+    #[must_use]
+    pub fn with_known_overrides(&self, overrides: &Params) -> Params {
+        let mut params = self.clone();
+        for (name, value) in overrides.iter() {
+            if self.0.contains_key(name) {
+                params.set(name, value);
+            }
+        }
+        params
+    }
+
+    /// Parses assignments of the form `name=value`
+    ///
+    /// # Errors
+    /// If an assignment has no `=` or an empty name
+    pub fn parse_assignments<'a>(
+        assignments: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Params, ParamError> {
+        let mut params = Params::default();
+        for assignment in assignments {
+            match assignment.split_once('=') {
+                Some((name, value)) if !name.trim().is_empty() => {
+                    params.set(name.trim(), value.trim());
+                }
+                _ => return Err(ParamError::Syntax(assignment.to_string())),
+            }
+        }
+        Ok(params)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            &self
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .join(","),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let params = Params::from([("width", 1001)]);
+        assert_eq!(Ok(1001_usize), params.get("width"));
+        assert!(matches!(
+            params.get::<u8>("width"),
+            Err(ParamError::Invalid { .. })
+        ));
+        assert!(matches!(
+            params.get::<usize>("height"),
+            Err(ParamError::Missing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_with_overrides() -> Result<(), ParamError> {
+        let defaults = Params::from([("width", 101), ("height", 103)]);
+        let overrides = Params::parse_assignments(["width=11", "height = 7"])?;
+        assert_eq!(
+            Params::from([("width", 11), ("height", 7)]),
+            defaults.with_overrides(&overrides)?
+        );
+        assert_eq!(
+            Err(ParamError::Unknown {
+                name: "cycles".to_string(),
+                known: "height, width".to_string()
+            }),
+            defaults.with_overrides(&Params::from([("cycles", 25)]))
+        );
+        assert_eq!(
+            Params::from([("width", 11), ("height", 103)]),
+            defaults.with_known_overrides(&Params::from([("width", 11), ("cycles", 25)]))
+        );
+        assert_eq!(
+            Err(ParamError::Syntax("width".to_string())),
+            Params::parse_assignments(["width"])
+        );
+        Ok(())
+    }
+}
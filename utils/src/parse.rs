@@ -0,0 +1,411 @@
+//! Reusable `nom` parsers for the recurring shapes of puzzle inputs
+//!
+//! All parsers work on slices of the puzzle input and fail with a [`ParseFailure`] which
+//! remembers where parsing stopped. [`parse_all`] and [`parse_part`] run a parser and turn
+//! such a failure into a [`ParseError`] pointing at the offending line and column.
+//!
+//! ```
+//! use utils::parse::{key_pair, lines_of, parse_all, separated};
+//!
+//! let robots: Vec<((i32, i32), (i32, i32))> = parse_all(
+//!     "p=0,4 v=3,-3\np=6,3 v=-1,-3\n",
+//!     lines_of(separated(key_pair("p"), " ", key_pair("v"))),
+//! )
+//! .unwrap();
+//! assert_eq!(vec![((0, 4), (3, -3)), ((6, 3), (-1, -3))], robots);
+//! ```
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use nom::character::complete::{alphanumeric1, char, digit1, one_of, space0};
+use nom::combinator::{map_res, opt, recognize};
+use nom::error::{
+    context, ContextError, ErrorKind, FromExternalError, ParseError as NomParseError,
+};
+use nom::sequence::{pair, preceded, separated_pair, tuple};
+use nom::{IResult, Parser};
+
+use crate::parse_error::{ParseContext, ParseError};
+
+/// Result of the parsers of this module
+pub type PResult<'a, O> = IResult<&'a str, O, ParseFailure<'a>>;
+
+/// Failure of a parser: the remaining input at the failing position and what was expected there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFailure<'a> {
+    /// The input from the failing position on
+    pub remaining: &'a str,
+    /// Description of what was expected, e.g. `a number` or `'='`
+    pub expected: String,
+    /// Whether `expected` was given explicitly (and not derived from a nom [`ErrorKind`])
+    labelled: bool,
+}
+
+impl<'a> ParseFailure<'a> {
+    /// Creates a failure at the start of `remaining`
+    pub fn new(remaining: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            remaining,
+            expected: expected.into(),
+            labelled: true,
+        }
+    }
+
+    /// Converts the failure into an error pointing at the failing token of the input of `context`
+    pub fn to_parse_error(&self, context: &ParseContext) -> ParseError {
+        context.error(next_token(self.remaining), self.expected.clone())
+    }
+}
+
+impl<'a> NomParseError<&'a str> for ParseFailure<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let expected = match kind {
+            ErrorKind::Digit => "a digit".to_string(),
+            ErrorKind::Alpha => "a letter".to_string(),
+            ErrorKind::AlphaNumeric => "a letter or digit".to_string(),
+            ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".to_string(),
+            ErrorKind::CrLf => "end of line".to_string(),
+            ErrorKind::Eof => "end of input".to_string(),
+            kind => kind.description().to_lowercase(),
+        };
+        Self {
+            remaining: input,
+            expected,
+            labelled: false,
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, ch: char) -> Self {
+        Self::new(input, format!("'{ch}'"))
+    }
+
+    /// Keeps the failure which got further; failures at the same position list both expectations
+    fn or(self, other: Self) -> Self {
+        match self.remaining.len().cmp(&other.remaining.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => Self {
+                expected: format!("{} or {}", self.expected, other.expected),
+                labelled: self.labelled || other.labelled,
+                ..self
+            },
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for ParseFailure<'a> {
+    /// The context replaces the expectation if nothing of it could be parsed
+    /// or the inner failure has no explicit expectation
+    fn add_context(input: &'a str, ctx: &'static str, other: Self) -> Self {
+        if other.labelled && other.remaining.len() != input.len() {
+            other
+        } else {
+            Self::new(other.remaining, ctx)
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for ParseFailure<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _err: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+/// The leading non-whitespace part of `remaining`, used as error span
+fn next_token(remaining: &str) -> &str {
+    let end = remaining
+        .find(char::is_whitespace)
+        .unwrap_or(remaining.len());
+    &remaining[..end]
+}
+
+fn fail<O>(remaining: &str, expected: impl Into<String>) -> PResult<'_, O> {
+    Err(nom::Err::Error(ParseFailure::new(remaining, expected)))
+}
+
+/// Runs `parser` on the whole `input`; only trailing whitespace may remain
+///
+/// # Errors
+/// If `parser` fails or does not consume the whole input
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, ParseFailure<'a>>,
+) -> Result<O, ParseError> {
+    parse_part(&ParseContext::new(input), input, parser)
+}
+
+/// Runs `parser` on `text` (a slice of the input of `context`); only trailing whitespace may remain
+///
+/// # Errors
+/// If `parser` fails or does not consume the whole `text`
+pub fn parse_part<'a, O>(
+    context: &ParseContext<'a>,
+    text: &'a str,
+    mut parser: impl Parser<&'a str, O, ParseFailure<'a>>,
+) -> Result<O, ParseError> {
+    match parser.parse(text) {
+        Ok((remaining, output)) if remaining.trim().is_empty() => Ok(output),
+        Ok((remaining, _output)) => {
+            Err(context.error(next_token(remaining.trim_start()), "end of input"))
+        }
+        Err(nom::Err::Error(failure) | nom::Err::Failure(failure)) => {
+            Err(failure.to_parse_error(context))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(context.error_after(text, "more input")),
+    }
+}
+
+/// The exact `text`, e.g. `Program: `
+pub fn literal<'a>(text: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(text) {
+        Some(remaining) => Ok((remaining, &input[..text.len()])),
+        None => fail(input, format!("'{text}'")),
+    }
+}
+
+/// An unsigned integer, e.g. `42`
+///
+/// # Errors
+/// If there are no digits or the number does not fit into `T`
+pub fn unsigned<T: FromStr>(input: &str) -> PResult<'_, T> {
+    context("a number", map_res(digit1, str::parse))(input)
+}
+
+/// An integer with an optional sign, e.g. `-3` or `+94`
+///
+/// # Errors
+/// If there are no digits or the number does not fit into `T`
+pub fn signed<T: FromStr>(input: &str) -> PResult<'_, T> {
+    context(
+        "a number",
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )(input)
+}
+
+/// A name made of letters and digits, e.g. the node `kh` of an edge list
+///
+/// # Errors
+/// If there is no letter or digit
+pub fn name(input: &str) -> PResult<'_, &str> {
+    context("a name", alphanumeric1)(input)
+}
+
+/// One or more `element`s separated by `separator`; after a separator another element must follow
+pub fn list_of<'a, O>(
+    separator: &'static str,
+    mut element: impl Parser<&'a str, O, ParseFailure<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    move |input: &'a str| {
+        let (mut remaining, first) = element.parse(input)?;
+        let mut elements = vec![first];
+        while let Some(next) = remaining.strip_prefix(separator) {
+            let (next_remaining, next_element) = element.parse(next)?;
+            elements.push(next_element);
+            remaining = next_remaining;
+        }
+        Ok((remaining, elements))
+    }
+}
+
+/// Integers separated by `separator`, e.g. `3,-1,4` or `7 6 4 2 1`
+pub fn signed_list<'a, T: FromStr>(
+    separator: &'static str,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    list_of(separator, signed)
+}
+
+/// `first` and `second` separated by the exact `separator`, e.g. `190: 10 19` with `": "`
+pub fn separated<'a, O1, O2>(
+    first: impl Parser<&'a str, O1, ParseFailure<'a>>,
+    separator: &'static str,
+    second: impl Parser<&'a str, O2, ParseFailure<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, (O1, O2)> {
+    separated_pair(first, literal(separator), second)
+}
+
+/// A pair of integers assigned to `key`, e.g. `p=0,4` or `v=3,-3`
+pub fn key_pair<'a, T: FromStr>(key: &'static str) -> impl FnMut(&'a str) -> PResult<'a, (T, T)> {
+    preceded(
+        pair(literal(key), char('=')),
+        separated_pair(signed, char(','), signed),
+    )
+}
+
+/// A `value` labelled with `label` and a colon, e.g. `Register A: 729` or `Program: 0,1,5`
+pub fn labelled<'a, O>(
+    label: &'static str,
+    value: impl Parser<&'a str, O, ParseFailure<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, O> {
+    preceded(tuple((literal(label), char(':'), space0)), value)
+}
+
+/// One or more lines parsed by `line`; stops in front of an empty line
+///
+/// Every line must be parsed completely.
+pub fn lines_of<'a, O>(
+    mut line: impl Parser<&'a str, O, ParseFailure<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    move |mut input: &'a str| {
+        let mut lines = Vec::new();
+        loop {
+            let (remaining, parsed_line) = line.parse(input)?;
+            lines.push(parsed_line);
+            match remaining.strip_prefix('\n') {
+                Some(next) if !next.is_empty() && !next.starts_with('\n') => input = next,
+                Some(_) => return Ok((remaining, lines)),
+                None if remaining.is_empty() => return Ok((remaining, lines)),
+                None => return fail(remaining, "end of line"),
+            }
+        }
+    }
+}
+
+/// One or more sections parsed by `section`, separated by empty lines
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, ParseFailure<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    list_of("\n\n", section)
+}
+
+/// Two different sections separated by an empty line, e.g. page ordering rules and updates
+pub fn section_pair<'a, O1, O2>(
+    first: impl Parser<&'a str, O1, ParseFailure<'a>>,
+    second: impl Parser<&'a str, O2, ParseFailure<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, (O1, O2)> {
+    separated_pair(first, context("an empty line", literal("\n\n")), second)
+}
+
+/// An edge between two named nodes, e.g. `kh-tc` with separator `'-'`
+pub fn edge<'a>(separator: char) -> impl FnMut(&'a str) -> PResult<'a, (&'a str, &'a str)> {
+    separated_pair(name, char(separator), name)
+}
+
+/// One edge per line, e.g. the connections of a network
+pub fn edge_list<'a>(
+    separator: char,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<(&'a str, &'a str)>> {
+    lines_of(edge(separator))
+}
+
+/// A rectangular grid of characters (all contained in `allowed`), returned as rows
+pub fn char_grid<'a>(allowed: &'static str) -> impl FnMut(&'a str) -> PResult<'a, Vec<Vec<char>>> {
+    move |input: &'a str| {
+        let mut width = None;
+        let mut rows = lines_of(|line: &'a str| grid_row(line, allowed, &mut width));
+        rows(input)
+    }
+}
+
+fn grid_row<'a>(line: &'a str, allowed: &str, width: &mut Option<usize>) -> PResult<'a, Vec<char>> {
+    let end = line
+        .find(|ch: char| ch == '\n' || !allowed.contains(ch))
+        .unwrap_or(line.len());
+    if !line[end..].is_empty() && !line[end..].starts_with('\n') {
+        return fail(&line[end..], format!("one of \"{allowed}\""));
+    }
+    let row: Vec<char> = line[..end].chars().collect();
+    match *width {
+        None if row.is_empty() => return fail(line, format!("one of \"{allowed}\"")),
+        None => *width = Some(row.len()),
+        Some(width) if row.len() < width => {
+            return fail(&line[end..], format!("{width} cells per line"));
+        }
+        Some(width) if row.len() > width => {
+            let (index, _ch) = line.char_indices().nth(width).unwrap();
+            return fail(&line[index..], "end of line");
+        }
+        Some(_) => {}
+    }
+    Ok((&line[end..], row))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_list() -> Result<(), ParseError> {
+        assert_eq!(
+            vec![vec![7, 6, 4], vec![1, -2]],
+            parse_all("7 6 4\n1 -2\n", lines_of(signed_list::<i32>(" ")))?
+        );
+        let err = parse_all("7 6 4\n1 x2\n", lines_of(signed_list::<i32>(" "))).unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 3: expected a number",
+            err.to_string()
+        );
+        let err = parse_all("300", unsigned::<u8>).unwrap_err();
+        assert_eq!(
+            (1, 1, "a number"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_pair_and_labelled() -> Result<(), ParseError> {
+        let robot = separated(key_pair::<i32>("p"), " ", key_pair::<i32>("v"));
+        assert_eq!(((0, 4), (3, -3)), parse_all("p=0,4 v=3,-3", robot)?);
+        let err = parse_all(
+            "p=0,4 w=3,-3",
+            separated(key_pair::<i32>("p"), " ", key_pair::<i32>("v")),
+        )
+        .unwrap_err();
+        assert_eq!((1, 7, "'v'"), (err.line, err.column, err.expected.as_str()));
+
+        let register = labelled("Register A", unsigned::<usize>);
+        assert_eq!(729, parse_all("Register A: 729\n", register)?);
+        let err = parse_all(
+            "Register A: 729 1",
+            labelled("Register A", unsigned::<usize>),
+        )
+        .unwrap_err();
+        assert_eq!(
+            (1, 17, "end of input"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sections_and_edges() -> Result<(), ParseError> {
+        let input = "kh-tc\nqp-kh\n\n1,2\n\n3\n";
+        let (edges, numbers) = parse_all(
+            input,
+            section_pair(edge_list('-'), sections(signed_list::<u8>(","))),
+        )?;
+        assert_eq!(vec![("kh", "tc"), ("qp", "kh")], edges);
+        assert_eq!(vec![vec![1, 2], vec![3]], numbers);
+
+        let err = parse_all("kh-tc\nqp+kh\n", edge_list('-')).unwrap_err();
+        assert_eq!((2, 3, "'-'"), (err.line, err.column, err.expected.as_str()));
+        let err =
+            parse_all("kh-tc\n1,2\n", section_pair(edge_list('-'), literal("1,2"))).unwrap_err();
+        assert_eq!((2, 2, "'-'"), (err.line, err.column, err.expected.as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_grid() -> Result<(), ParseError> {
+        assert_eq!(
+            vec![vec!['#', '.'], vec!['.', 'S']],
+            parse_all("#.\n.S\n", char_grid("#.S"))?
+        );
+        let err = parse_all("#.\n.x\n", char_grid("#.S")).unwrap_err();
+        assert_eq!(
+            "Parse error at line 2, column 2: expected one of \"#.S\"",
+            err.to_string()
+        );
+        let err = parse_all("#..\n.S\n", char_grid("#.S")).unwrap_err();
+        assert_eq!(
+            (2, 3, "3 cells per line"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+//! Parse errors which point at the offending line and column of the puzzle input
+
+use std::str::FromStr;
+
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+/// The puzzle input could not be parsed
+///
+/// Contains the whole input, so the diagnostic shows the offending line with the
+/// unexpected part labelled with the expected token.
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq, Eq)]
+#[error("Parse error at line {line}, column {column}: expected {expected}")]
+#[diagnostic(code(aoc::parse_error))]
+pub struct ParseError {
+    #[source_code]
+    input: String,
+    #[label("expected {expected}")]
+    span: SourceSpan,
+    /// Description of what was expected, e.g. `a number` or `'='`
+    pub expected: String,
+    /// Line of the error (starting at 1)
+    pub line: usize,
+    /// Column of the error in characters (starting at 1)
+    pub column: usize,
+}
+
+impl ParseError {
+    /// Creates an error for the `len` bytes at byte `offset` of `input`
+    pub fn new(input: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Self {
+            input: input.to_string(),
+            span: (offset, len.min(input.len() - offset)).into(),
+            expected: expected.into(),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Byte offset and length of the offending part of the input
+    pub fn span(&self) -> SourceSpan {
+        self.span
+    }
+}
+
+/// The whole puzzle input, used to locate the parts of it which cannot be parsed
+#[derive(Debug, Clone, Copy)]
+pub struct ParseContext<'a> {
+    input: &'a str,
+}
+
+impl<'a> ParseContext<'a> {
+    /// Creates the context for the whole puzzle input
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    /// The whole puzzle input
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// Byte offset of `token` (which must be a slice of the input) in the input
+    pub fn offset(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.input.as_ptr() as usize);
+        debug_assert!(offset <= self.input.len(), "token is not part of the input");
+        offset.min(self.input.len())
+    }
+
+    /// Creates an error pointing at `token` (a slice of the input)
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.input, self.offset(token), token.len(), expected)
+    }
+
+    /// Creates an error pointing right behind `token` (a slice of the input), e.g. at the end of a line
+    pub fn error_after(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.input, self.offset(token) + token.len(), 0, expected)
+    }
+
+    /// Parses `token` (a slice of the input) with [`FromStr`]
+    ///
+    /// # Errors
+    /// If `token` cannot be parsed, the error points at it
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Splits `text` (a slice of the input) at the first `delimiter`
+    ///
+    /// # Errors
+    /// If `text` does not contain `delimiter`, the error points at the end of `text`
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error_after(text, format!("'{delimiter}'")))
+    }
+
+    /// Removes `prefix` from the start of `text` (a slice of the input)
+    ///
+    /// # Errors
+    /// If `text` does not start with `prefix`, the error points at the start of `text`
+    pub fn strip_prefix(&self, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        text.strip_prefix(prefix).ok_or_else(|| {
+            let len = text
+                .char_indices()
+                .nth(prefix.chars().count())
+                .map_or(text.len(), |(index, _)| index);
+            self.error(&text[..len], format!("'{prefix}'"))
+        })
+    }
+
+    /// Creates an error for the character at byte `index` of `text` (a slice of the input)
+    pub fn char_error(&self, text: &str, index: usize, expected: impl Into<String>) -> ParseError {
+        let len = text[index..].chars().next().map_or(0, char::len_utf8);
+        self.error(&text[index..index + len], expected)
+    }
+
+    /// Creates an error for the character in column `x` of line `y` (both starting at 0),
+    /// e.g. for an invalid cell of a grid
+    pub fn cell_error(&self, x: usize, y: usize, expected: impl Into<String>) -> ParseError {
+        match self.input.lines().nth(y) {
+            Some(line) => {
+                let index = line
+                    .char_indices()
+                    .nth(x)
+                    .map_or(line.len(), |(index, _)| index);
+                self.char_error(line, index, expected)
+            }
+            None => self.error_after(self.input, expected),
+        }
+    }
+
+    /// Finds the only occurrence of `ch` in `text` (a slice of the input), e.g. the start of a maze
+    ///
+    /// # Errors
+    /// If `ch` is missing (the error points at the end of `text`) or occurs more than once
+    /// (the error points at the second occurrence)
+    pub fn find_unique_char(&self, text: &str, ch: char) -> Result<usize, ParseError> {
+        let mut indexes = text.match_indices(ch).map(|(index, _ch)| index);
+        let index = indexes
+            .next()
+            .ok_or_else(|| self.error_after(text, format!("'{ch}'")))?;
+        if let Some(second_index) = indexes.next() {
+            return Err(self.char_error(text, second_index, format!("only one '{ch}'")));
+        }
+        Ok(index)
+    }
+
+    /// Checks that `is_allowed` holds for every character of every line of `text` (a slice of the input),
+    /// e.g. for the cells of a grid
+    ///
+    /// # Errors
+    /// The error points at the first character which is not allowed
+    pub fn check_chars(
+        &self,
+        text: &str,
+        is_allowed: impl Fn(char) -> bool,
+        expected: &str,
+    ) -> Result<(), ParseError> {
+        for line in text.lines() {
+            if let Some((index, _ch)) = line.char_indices().find(|(_index, ch)| !is_allowed(*ch)) {
+                return Err(self.char_error(line, index, expected));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let input = "p=0,4 v=3,-3\np=6,3 v=-1,x3\n";
+        let context = ParseContext::new(input);
+        let line = input.lines().nth(1).unwrap();
+        let err = context.parse::<i32>(&line[11..], "a number").unwrap_err();
+        assert_eq!((2, 12), (err.line, err.column));
+        assert_eq!(SourceSpan::from((24, 2)), err.span());
+        assert_eq!(
+            "Parse error at line 2, column 12: expected a number",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_split_and_strip() -> Result<(), ParseError> {
+        let input = "p=0,4 v=3,-3";
+        let context = ParseContext::new(input);
+        let (position, velocity) = context.split_once(input, " ")?;
+        assert_eq!("0,4", context.strip_prefix(position, "p=")?);
+        let err = context.strip_prefix(velocity, "p=").unwrap_err();
+        assert_eq!(
+            (1, 7, "'p='"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        let err = context.split_once(position, ";").unwrap_err();
+        assert_eq!(SourceSpan::from((5, 0)), err.span());
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_unique_char() {
+        let context = ParseContext::new("#S.\n.SE\n");
+        assert_eq!(Ok(6), context.find_unique_char(context.input(), 'E'));
+        let err = context.find_unique_char(context.input(), 'S').unwrap_err();
+        assert_eq!(
+            (2, 2, "only one 'S'"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        let err = context.find_unique_char(context.input(), 'X').unwrap_err();
+        assert_eq!((3, 1, "'X'"), (err.line, err.column, err.expected.as_str()));
+    }
+
+    #[test]
+    fn test_cell_error() {
+        let context = ParseContext::new("#.#\n.x.\n");
+        let err = context.cell_error(1, 1, "'#' or '.'");
+        assert_eq!((2, 2), (err.line, err.column));
+        assert_eq!(SourceSpan::from((5, 1)), err.span());
+        assert_eq!(
+            Err(err),
+            context.check_chars(context.input(), |ch| ch == '#' || ch == '.', "'#' or '.'")
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Registry types for the daily puzzle solutions (used by the `aoc` runner)
+//!
+//! A day implements [`Day`]: its input is parsed once into a typed [`Day::Input`] which is then
+//! consumed by [`Day::part1`] and [`Day::part2`]. [`DayPart`] registers one part of a day as
+//! [`Solution`] for the runner. Every day also generates random inputs (see [`crate::generate`]).
+
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use miette::Result;
+
+use crate::generate::{seeded_rng, GeneratedInput, StdRng};
+use crate::params::Params;
+
+/// One part of one day's puzzle
+pub trait Solution: Sync {
+    /// Day of the puzzle (1 to 25)
+    fn day(&self) -> u8;
+
+    /// Part of the puzzle (1 or 2)
+    fn part(&self) -> u8;
+
+    /// Solves the puzzle for the given input and parameters (see [`Solution::default_params`])
+    ///
+    /// # Errors
+    /// If the input or a parameter is invalid or the puzzle cannot be solved
+    fn process(&self, input: &str, params: &Params) -> Result<String>;
+
+    /// Like [`Solution::process`], also measuring the time of parsing and of solving
+    ///
+    /// # Errors
+    /// If the input or a parameter is invalid or the puzzle cannot be solved
+    fn process_timed(&self, input: &str, params: &Params) -> Result<(String, PhaseTimes)>;
+
+    /// Parameters of the real puzzle input, all other parameters are rejected
+    fn default_params(&self) -> Params {
+        Params::default()
+    }
+
+    /// Generates a random puzzle input from the seed (see [`Day::generate`]),
+    /// its parameters are the complete parameters of this part
+    fn generate(&self, seed: u64, size: usize) -> GeneratedInput;
+
+    /// Name of the crate (and folder) of the day, e.g. `day07`
+    fn crate_name(&self) -> String {
+        format!("day{:02}", self.day())
+    }
+
+    /// Name of the input file inside the day folder, e.g. `input2.txt`
+    fn input_file_name(&self) -> String {
+        format!("input{}.txt", self.part())
+    }
+}
+
+/// Time spent in the phases of [`Solution::process`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseTimes {
+    /// Parsing the input
+    pub parse: Duration,
+    /// Solving the part for the parsed input
+    pub solve: Duration,
+}
+
+impl PhaseTimes {
+    /// Time of parsing and solving
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// A day's puzzle whose input is parsed once and shared by both parts
+pub trait Day {
+    /// Day of the puzzle (1 to 25)
+    const DAY: u8;
+
+    /// The parsed puzzle input, consumed by both parts
+    type Input;
+
+    /// Parses the puzzle input
+    ///
+    /// # Errors
+    /// If the input is invalid
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Solves part 1 for the parsed input and parameters (see [`Day::default_params`])
+    ///
+    /// # Errors
+    /// If a parameter is invalid or the puzzle cannot be solved
+    fn part1(input: &Self::Input, params: &Params) -> Result<String>;
+
+    /// Solves part 2 for the parsed input and parameters (see [`Day::default_params`])
+    ///
+    /// # Errors
+    /// If a parameter is invalid or the puzzle cannot be solved
+    fn part2(input: &Self::Input, params: &Params) -> Result<String>;
+
+    /// Parameters of the given part for the real puzzle input, all other parameters are rejected
+    fn default_params(_part: u8) -> Params {
+        Params::default()
+    }
+
+    /// Generates a random, structurally valid puzzle input which both parts can solve
+    ///
+    /// The input grows with `size`, its meaning depends on the day (e.g. the side length of a
+    /// grid or the number of lines). The parameters of the input replace the default parameters.
+    fn generate(rng: &mut StdRng, size: usize) -> GeneratedInput;
+}
+
+/// One part of a [`Day`], registered as [`Solution`] (parses the input and solves the part)
+pub struct DayPart<D> {
+    part: u8,
+    day: PhantomData<fn() -> D>,
+}
+
+impl<D: Day> DayPart<D> {
+    /// Part 1 of the day
+    pub const PART1: Self = Self::new(1);
+    /// Part 2 of the day
+    pub const PART2: Self = Self::new(2);
+
+    const fn new(part: u8) -> Self {
+        Self {
+            part,
+            day: PhantomData,
+        }
+    }
+
+    /// Solves the part for the already parsed input
+    ///
+    /// # Errors
+    /// If a parameter is invalid or the puzzle cannot be solved
+    pub fn solve(&self, input: &D::Input, params: &Params) -> Result<String> {
+        match self.part {
+            1 => D::part1(input, params),
+            _ => D::part2(input, params),
+        }
+    }
+}
+
+impl<D: Day> Solution for DayPart<D> {
+    fn day(&self) -> u8 {
+        D::DAY
+    }
+
+    fn part(&self) -> u8 {
+        self.part
+    }
+
+    fn process(&self, input: &str, params: &Params) -> Result<String> {
+        self.solve(&D::parse(input)?, params)
+    }
+
+    fn process_timed(&self, input: &str, params: &Params) -> Result<(String, PhaseTimes)> {
+        let start = Instant::now();
+        let parsed = D::parse(input)?;
+        let parse = start.elapsed();
+        let start = Instant::now();
+        let result = self.solve(&parsed, params)?;
+        let solve = start.elapsed();
+        Ok((result, PhaseTimes { parse, solve }))
+    }
+
+    fn default_params(&self) -> Params {
+        D::default_params(self.part)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> GeneratedInput {
+        let generated = D::generate(&mut seeded_rng(seed), size);
+        GeneratedInput {
+            params: self
+                .default_params()
+                .with_known_overrides(&generated.params),
+            input: generated.input,
+        }
+    }
+}